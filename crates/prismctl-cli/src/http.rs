@@ -0,0 +1,122 @@
+use prismctl_core::provider_api::{HttpClient, HttpRequest, HttpResponse, TransportError};
+use std::time::Duration;
+
+/// Blocking [`HttpClient`] backed by `ureq`; non-2xx responses are returned, not treated as errors.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut req = self.agent.request(request.method, &request.url);
+        for (k, v) in &request.headers {
+            req = req.set(k, v);
+        }
+        let result = match &request.body {
+            Some(body) => req.send_string(body),
+            None => req.call(),
+        };
+        let resp = match result {
+            Ok(resp) | Err(ureq::Error::Status(_, resp)) => resp,
+            Err(ureq::Error::Transport(t)) => return Err(classify_transport(&t)),
+        };
+        let status = resp.status();
+        let body = resp.into_string().unwrap_or_default();
+        Ok(HttpResponse { status, body })
+    }
+}
+
+fn classify_transport(t: &ureq::Transport) -> TransportError {
+    let message = t.to_string();
+    let lower = message.to_ascii_lowercase();
+    match t.kind() {
+        ureq::ErrorKind::Dns => TransportError::Dns,
+        _ if lower.contains("timed out") || lower.contains("timeout") => TransportError::Timeout,
+        _ if lower.contains("certificate") || lower.contains("tls") => TransportError::Tls,
+        ureq::ErrorKind::ConnectionFailed if lower.contains("refused") => {
+            TransportError::ConnectionRefused
+        }
+        _ => TransportError::Other(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prismctl_core::provider_api::{
+        probe_endpoint, ApiFlavor, AuthHeader, Endpoint, ProbeOutcome,
+    };
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve one canned response and return the request head the client sent.
+    fn stub_server(response: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}/v1", listener.local_addr().expect("addr"));
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let mut reader = BufReader::new(stream.try_clone().expect("clone"));
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read");
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push(line.trim().to_string());
+            }
+            stream.write_all(response.as_bytes()).expect("write");
+            head
+        });
+        (base_url, handle)
+    }
+
+    fn openai_endpoint(base_url: String) -> Endpoint {
+        Endpoint {
+            flavor: ApiFlavor::OpenAi,
+            base_url,
+            api_key: Some("sk-test".to_string()),
+            auth_header: AuthHeader::Bearer,
+            model: None,
+        }
+    }
+
+    #[test]
+    fn probe_against_stub_server_reports_status() {
+        let (base_url, server) = stub_server(
+            "HTTP/1.1 401 Unauthorized\r\nContent-Type: application/json\r\nContent-Length: 35\r\nConnection: close\r\n\r\n{\"error\":{\"message\":\"bad api key\"}}",
+        );
+        let client = UreqClient::new(Duration::from_secs(5));
+        let report = probe_endpoint(&client, &openai_endpoint(base_url));
+        let head = server.join().expect("server");
+
+        assert_eq!(head[0], "GET /v1/models HTTP/1.1");
+        assert!(head
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("authorization: Bearer sk-test")));
+        assert_eq!(report.status, Some(401));
+        assert_eq!(report.outcome, ProbeOutcome::Unauthorized);
+        assert_eq!(report.detail.as_deref(), Some("bad api key"));
+    }
+
+    #[test]
+    fn probe_reports_connection_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}/v1", listener.local_addr().expect("addr"));
+        drop(listener);
+
+        let client = UreqClient::new(Duration::from_secs(5));
+        let report = probe_endpoint(&client, &openai_endpoint(base_url));
+        assert_eq!(report.status, None);
+        assert_eq!(report.outcome, ProbeOutcome::ConnectionRefused);
+    }
+}
@@ -30,7 +30,9 @@ pub fn render_change(change: &Change) -> String {
             let pseudo = format!("{}/", path.display());
             Some(icon_for_file(pseudo.as_str(), &Some(Theme::Dark)))
         }
-        Change::WriteFile { path, .. } => Some(icon_for_file(path, &Some(Theme::Dark))),
+        Change::WriteFile { path, .. } | Change::RemoveFile { path } => {
+            Some(icon_for_file(path, &Some(Theme::Dark)))
+        }
         Change::RunCommand { .. } => None,
     };
 
@@ -553,21 +553,13 @@ fn gemini_mcp_menu() -> Result<(), String> {
 }
 
 fn gemini_env_configure() -> Result<(), String> {
-    use crate::interactive::utils::prompt_secret_with_env_default;
-
     println!("\n{}\n", t!(keys::WIZARD_GEMINI_TITLE));
 
-    let api_key_prompt = t!(keys::WIZARD_GEMINI_PROMPT_API_KEY);
-    let api_key =
-        prompt_secret_with_env_default(&api_key_prompt, &["GEMINI_API_KEY", "PRISMCTL_API_KEY"])?;
-    if let Some(k) = &api_key {
-        crate::interactive::utils::validate_api_key_format(k)?;
-    }
-
-    let Some(key) = api_key else {
+    let env_flags = crate::interactive::wizard_gemini::prompt_auth_env_flags()?;
+    if env_flags.is_empty() {
         println!("{}\n", t!(keys::WIZARD_NO_CHANGES_CANCELLED));
         return Ok(());
-    };
+    }
 
     let scope_title = t!(keys::MENU_GEMINI_ENV_SCOPE_TITLE);
     let scope_user = t!(keys::MENU_GEMINI_ENV_SCOPE_USER);
@@ -599,9 +591,8 @@ fn gemini_env_configure() -> Result<(), String> {
         "set".to_string(),
         "--scope".to_string(),
         scope_flag.to_string(),
-        "--api-key".to_string(),
-        key.clone(),
     ];
+    preview.extend(env_flags.iter().cloned());
     if let Some(p) = &project_path {
         preview.push("--project-path".to_string());
         preview.push(p.clone());
@@ -615,10 +606,9 @@ fn gemini_env_configure() -> Result<(), String> {
             "set".to_string(),
             "--scope".to_string(),
             scope_flag.to_string(),
-            "--api-key".to_string(),
-            key,
-            "--apply".to_string(),
         ];
+        apply.extend(env_flags);
+        apply.push("--apply".to_string());
         if let Some(p) = project_path {
             apply.push("--project-path".to_string());
             apply.push(p);
@@ -703,12 +693,14 @@ fn gemini_settings_configure() -> Result<(), String> {
 
 fn codex_provider_configure() -> Result<(), String> {
     use crate::interactive::utils::prompt_secret_with_env_default;
-    use prismctl_core::providers;
+    use prismctl_core::paths::PrismctlHome;
 
     // Reuse wizard prompts to keep vocabulary consistent.
     println!("\n{}\n", t!(keys::WIZARD_CODEX_TITLE));
 
-    let ids = providers::list_provider_ids();
+    let home = PrismctlHome::discover(None)?;
+    let catalog = crate::legacy::load_provider_catalog(&home)?;
+    let ids = catalog.list_provider_ids();
     let mut options = ids.iter().map(|id| (*id).to_string()).collect::<Vec<_>>();
     let custom = t!(keys::WIZARD_CODEX_PROVIDER_CUSTOM);
     options.push(custom.clone());
@@ -730,7 +722,7 @@ fn codex_provider_configure() -> Result<(), String> {
         ("custom".to_string(), base_url, wire_api, model)
     } else {
         // Validate early to give a friendly error message.
-        providers::parse_provider_id(&choice)?;
+        catalog.parse_provider_id(&choice)?;
         (choice, None, None, None)
     };
 
@@ -857,7 +849,7 @@ fn codex_agent_configure() -> Result<(), String> {
     }
     legacy::cmd_codex(preview)?;
 
-    let confirm = t!(keys::WIZARD_CODEX_CONFIRM_OVERWRITE_AGENTS);
+    let confirm = t!(keys::WIZARD_CODEX_CONFIRM_APPLY_PERSONA);
     if prompt_confirm(&confirm, false)? {
         let mut apply = vec![
             "agent".to_string(),
@@ -869,7 +861,6 @@ fn codex_agent_configure() -> Result<(), String> {
             "--scope".to_string(),
             scope_flag.to_string(),
             "--apply".to_string(),
-            "--yes".to_string(),
         ];
         if let Some(p) = project_path {
             apply.push("--project-path".to_string());
@@ -1128,6 +1119,8 @@ fn view_current_config() -> Result<(), String> {
     println!();
     legacy::cmd_doctor(Vec::new())?;
     println!();
+    legacy::cmd_codex(vec!["settings".to_string(), "show".to_string()])?;
+    println!();
     legacy::cmd_skill(vec!["list".to_string()])?;
     println!();
     let cont = t!(keys::ACTION_CONTINUE);
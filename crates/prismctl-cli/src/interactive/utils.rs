@@ -1,6 +1,7 @@
 use inquire::error::InquireError;
 use inquire::validator::Validation;
 use inquire::{Confirm, MultiSelect, Password, Select, Text};
+use prismctl_core::provider_api::Endpoint;
 use prismctl_i18n::{keys, t, tf};
 use std::env;
 use std::sync::Once;
@@ -74,6 +75,55 @@ pub fn prompt_optional(prompt: &str) -> Result<Option<String>, String> {
     }
 }
 
+/// Models offered by `endpoint`, or just `fallback` when the user declines the request or the
+/// endpoint cannot be listed. Nothing is sent unless the user opts in.
+pub fn fetch_model_choices(
+    endpoint: &Endpoint,
+    fallback: Option<String>,
+) -> Result<Vec<String>, String> {
+    let confirm = tf!(keys::WIZARD_MODELS_FETCH_CONFIRM, "url" => endpoint.base_url.clone());
+    if !prompt_confirm(&confirm, false)? {
+        return Ok(fallback.into_iter().collect());
+    }
+    println!(
+        "{}",
+        tf!(keys::WIZARD_MODELS_FETCHING, "url" => endpoint.base_url.clone())
+    );
+    Ok(match crate::legacy::fetch_models(endpoint) {
+        Ok(models) if !models.is_empty() => models,
+        Ok(_) => fallback.into_iter().collect(),
+        Err(report) => {
+            println!(
+                "{}",
+                tf!(keys::WIZARD_MODELS_UNAVAILABLE, "outcome" => report.outcome.id())
+            );
+            fallback.into_iter().collect()
+        }
+    })
+}
+
+/// Pick a model from `models` (plus manual input / skip); plain text input when the list is empty.
+pub fn prompt_model(prompt: &str, models: &[String]) -> Result<Option<String>, String> {
+    if models.is_empty() {
+        return prompt_optional(prompt);
+    }
+    let custom = t!(keys::WIZARD_MODEL_CUSTOM);
+    let skip = t!(keys::WIZARD_MODEL_SKIP);
+    let mut options = models.to_vec();
+    options.push(custom.clone());
+    options.push(skip.clone());
+
+    let choice = prompt_select(prompt, options, 0)?;
+    if choice == custom {
+        let p = t!(keys::WIZARD_MODEL_NAME);
+        return prompt_required(&p).map(Some);
+    }
+    if choice == skip {
+        return Ok(None);
+    }
+    Ok(Some(choice))
+}
+
 pub fn prompt_required(prompt: &str) -> Result<String, String> {
     Text::new(prompt)
         .with_validator(|input: &str| {
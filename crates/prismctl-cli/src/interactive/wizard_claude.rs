@@ -1,9 +1,13 @@
 use crate::interactive::utils::{
-    prompt_confirm, prompt_line, prompt_multi_select, prompt_optional, prompt_required,
-    prompt_secret_with_env_default, prompt_select, validate_api_key_format, validate_http_url,
+    fetch_model_choices, prompt_confirm, prompt_line, prompt_model, prompt_multi_select,
+    prompt_required, prompt_secret_with_env_default, prompt_select, validate_api_key_format,
+    validate_http_url,
 };
 use crate::legacy;
+use prismctl_core::claude_statusline::StatusLinePreset;
 use prismctl_core::mcp;
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_core::provider_api::{self, AuthHeader};
 use prismctl_core::skill;
 use prismctl_i18n::{keys, t, tf};
 
@@ -24,14 +28,32 @@ pub fn wizard_configure_claude() -> Result<(), String> {
         validate_api_key_format(k)?;
     }
 
+    // Offer to query the endpoint that will be in effect (current settings overridden by the answers above).
+    let home = PrismctlHome::discover(None)?;
+    let project_root =
+        std::env::current_dir().map_err(|e| tf!(keys::ERROR_CURRENT_DIR, "error" => e))?;
+    let mut endpoint =
+        provider_api::resolve_endpoint(&home, &project_root, Tool::ClaudeCode, &|p| {
+            std::fs::read_to_string(p).ok()
+        })?;
+    if let Some(url) = &base_url {
+        endpoint.base_url = url.clone();
+    }
+    if auth_token.is_some() {
+        endpoint.api_key = auth_token.clone();
+        endpoint.auth_header = AuthHeader::Bearer;
+    }
+    let fallback = endpoint.model.clone();
+    let models = fetch_model_choices(&endpoint, fallback)?;
+
     let model_prompt = t!(keys::WIZARD_CLAUDE_PROMPT_MODEL);
-    let model = prompt_optional(&model_prompt)?;
+    let model = prompt_model(&model_prompt, &models)?;
     let haiku_prompt = t!(keys::WIZARD_CLAUDE_PROMPT_HAIKU_MODEL);
-    let haiku_model = prompt_optional(&haiku_prompt)?;
+    let haiku_model = prompt_model(&haiku_prompt, &models)?;
     let sonnet_prompt = t!(keys::WIZARD_CLAUDE_PROMPT_SONNET_MODEL);
-    let sonnet_model = prompt_optional(&sonnet_prompt)?;
+    let sonnet_model = prompt_model(&sonnet_prompt, &models)?;
     let opus_prompt = t!(keys::WIZARD_CLAUDE_PROMPT_OPUS_MODEL);
-    let opus_model = prompt_optional(&opus_prompt)?;
+    let opus_model = prompt_model(&opus_prompt, &models)?;
 
     let has_env_changes = !(auth_token.is_none()
         && base_url.is_none()
@@ -75,7 +97,7 @@ pub fn wizard_configure_claude() -> Result<(), String> {
             legacy::cmd_claude(args)?;
         }
     } else {
-        // Still continue to optional steps (output style / skills / MCP).
+        // Still continue to optional steps (output style / status line / skills / MCP).
         println!("{}\n", t!(keys::WIZARD_CLAUDE_ENV_SKIP));
     }
 
@@ -102,6 +124,33 @@ pub fn wizard_configure_claude() -> Result<(), String> {
         }
     }
 
+    // Optional: status line preset.
+    println!("\n{}", t!(keys::WIZARD_CLAUDE_STATUSLINE_TITLE));
+    let p = t!(keys::WIZARD_CLAUDE_STATUSLINE_CONFIRM);
+    if prompt_confirm(&p, false)? {
+        let options = StatusLinePreset::ALL
+            .iter()
+            .map(|p| format!("{} ({})", p.as_str(), p.segments()))
+            .collect::<Vec<_>>();
+        let title = t!(keys::WIZARD_CLAUDE_STATUSLINE_SELECT);
+        let choice = prompt_select(&title, options, 2)?;
+        let preset = choice.split(' ').next().unwrap_or_default().to_string();
+        legacy::cmd_claude(vec![
+            "statusline".to_string(),
+            "preset".to_string(),
+            preset.clone(),
+        ])?;
+        let confirm = t!(keys::ACTION_CONFIRM_APPLY);
+        if prompt_confirm(&confirm, false)? {
+            legacy::cmd_claude(vec![
+                "statusline".to_string(),
+                "preset".to_string(),
+                preset,
+                "--apply".to_string(),
+            ])?;
+        }
+    }
+
     // Optional: install skills.
     println!("\n{}", t!(keys::WIZARD_CLAUDE_SKILLS_TITLE));
     let p = t!(keys::WIZARD_CLAUDE_SKILLS_CONFIRM);
@@ -1,36 +1,34 @@
 use crate::interactive::utils::{
-    prompt_confirm, prompt_lang_selection, prompt_optional, prompt_required,
-    prompt_secret_with_env_default, prompt_select, validate_api_key_format, validate_http_url,
+    fetch_model_choices, prompt_confirm, prompt_lang_selection, prompt_model, prompt_optional,
+    prompt_required, prompt_secret_with_env_default, prompt_select, validate_api_key_format,
+    validate_http_url,
 };
 use crate::legacy;
-use prismctl_core::providers;
+use prismctl_core::codex;
+use prismctl_core::codex_settings::{ApprovalPolicy, ReasoningEffort, SandboxMode, Verbosity};
+use prismctl_core::paths::PrismctlHome;
+use prismctl_core::provider_api::{ApiFlavor, AuthHeader, Endpoint};
+use prismctl_core::providers::ProviderCatalog;
 use prismctl_core::templates;
 use prismctl_i18n::{keys, t};
 
 pub fn wizard_configure_codex() -> Result<(), String> {
     println!("\n{}\n", t!(keys::WIZARD_CODEX_TITLE));
 
-    let provider_id = prompt_provider_id()?;
-    let (base_url, wire_api, model) = if provider_id == "custom" {
+    let home = PrismctlHome::discover(None)?;
+    let catalog = legacy::load_provider_catalog(&home)?;
+    let provider_id = prompt_provider_id(&catalog)?;
+    let (base_url, wire_api) = if provider_id == "custom" {
         let prompt = t!(keys::WIZARD_CODEX_PROMPT_BASE_URL);
         let base_url = prompt_optional(&prompt)?;
         if let Some(u) = &base_url {
             validate_http_url(u)?;
         }
-        (
-            base_url,
-            {
-                let prompt = t!(keys::WIZARD_CODEX_PROMPT_WIRE_API);
-                prompt_optional(&prompt)?
-            },
-            {
-                let prompt = t!(keys::WIZARD_CODEX_PROMPT_MODEL);
-                prompt_optional(&prompt)?
-            },
-        )
+        let prompt = t!(keys::WIZARD_CODEX_PROMPT_WIRE_API);
+        (base_url, prompt_optional(&prompt)?)
     } else {
         // Preset values are resolved inside `cmd_codex_provider_set` when passing `--provider`.
-        (None, None, None)
+        (None, None)
     };
 
     let api_key_prompt = t!(keys::WIZARD_CODEX_PROMPT_API_KEY);
@@ -42,13 +40,43 @@ pub fn wizard_configure_codex() -> Result<(), String> {
         validate_api_key_format(k)?;
     }
 
+    // Offer the models served by the chosen endpoint; the preset default is the fallback.
+    let preset = catalog.parse_provider_id(&provider_id).ok();
+    let models = match (preset, &base_url) {
+        (Some(p), _) => fetch_model_choices(
+            &Endpoint {
+                flavor: ApiFlavor::OpenAi,
+                base_url: p.base_url.clone(),
+                api_key: api_key.clone(),
+                auth_header: AuthHeader::Bearer,
+                model: None,
+            },
+            Some(p.default_model.clone()).filter(|m| !m.is_empty()),
+        )?,
+        (None, Some(url)) => fetch_model_choices(
+            &Endpoint {
+                flavor: ApiFlavor::OpenAi,
+                base_url: url.clone(),
+                api_key: api_key.clone(),
+                auth_header: AuthHeader::Bearer,
+                model: None,
+            },
+            None,
+        )?,
+        (None, None) => Vec::new(),
+    };
+    let model_prompt = t!(keys::WIZARD_CODEX_PROMPT_MODEL);
+    let model = prompt_model(&model_prompt, &models)?;
+
     let set_default_prompt = t!(keys::WIZARD_CODEX_SET_DEFAULT);
     let set_default = prompt_confirm(&set_default_prompt, true)?;
+    let profile_args = prompt_profile_args(model.as_deref())?;
+    let settings_args = prompt_settings_args()?;
 
     let mut args = vec!["provider".to_string(), "set".to_string()];
     if provider_id != "custom" {
         // Validate early to give a friendly error message.
-        providers::parse_provider_id(&provider_id)?;
+        catalog.parse_provider_id(&provider_id)?;
         args.push("--provider".to_string());
         args.push(provider_id);
     }
@@ -74,6 +102,13 @@ pub fn wizard_configure_codex() -> Result<(), String> {
 
     // Preview.
     legacy::cmd_codex(args.clone())?;
+    let extra_args: Vec<Vec<String>> = [profile_args, settings_args]
+        .into_iter()
+        .flatten()
+        .collect();
+    for extra in &extra_args {
+        legacy::cmd_codex(extra.clone())?;
+    }
 
     let confirm = t!(keys::ACTION_CONFIRM_APPLY);
     if !prompt_confirm(&confirm, false)? {
@@ -83,6 +118,10 @@ pub fn wizard_configure_codex() -> Result<(), String> {
 
     args.push("--apply".to_string());
     legacy::cmd_codex(args)?;
+    for mut extra in extra_args {
+        extra.push("--apply".to_string());
+        legacy::cmd_codex(extra)?;
+    }
 
     // Optional: pick and apply agent.
     println!("\n{}", t!(keys::WIZARD_CODEX_SWITCH_AGENT_TITLE));
@@ -114,7 +153,7 @@ pub fn wizard_configure_codex() -> Result<(), String> {
             lang.clone(),
         ])?;
 
-        let confirm = t!(keys::WIZARD_CODEX_CONFIRM_OVERWRITE_AGENTS);
+        let confirm = t!(keys::WIZARD_CODEX_CONFIRM_APPLY_PERSONA);
         if prompt_confirm(&confirm, false)? {
             legacy::cmd_codex(vec![
                 "agent".to_string(),
@@ -124,7 +163,6 @@ pub fn wizard_configure_codex() -> Result<(), String> {
                 "--lang".to_string(),
                 lang,
                 "--apply".to_string(),
-                "--yes".to_string(),
             ])?;
         }
     }
@@ -133,8 +171,8 @@ pub fn wizard_configure_codex() -> Result<(), String> {
     Ok(())
 }
 
-fn prompt_provider_id() -> Result<String, String> {
-    let ids = providers::list_provider_ids();
+fn prompt_provider_id(catalog: &ProviderCatalog) -> Result<String, String> {
+    let ids = catalog.list_provider_ids();
     let mut options = ids.iter().map(|id| (*id).to_string()).collect::<Vec<_>>();
     let custom = t!(keys::WIZARD_CODEX_PROVIDER_CUSTOM);
     options.push(custom.clone());
@@ -148,3 +186,89 @@ fn prompt_provider_id() -> Result<String, String> {
     }
     Ok(choice)
 }
+
+/// Optionally wrap the provider in a `[profiles.<name>]` table (`codex --profile <name>`).
+fn prompt_profile_args(model: Option<&str>) -> Result<Option<Vec<String>>, String> {
+    let prompt = t!(keys::WIZARD_CODEX_CREATE_PROFILE);
+    if !prompt_confirm(&prompt, false)? {
+        return Ok(None);
+    }
+    let prompt = t!(keys::WIZARD_CODEX_PROFILE_NAME);
+    let name = prompt_required(&prompt)?;
+    codex::validate_codex_profile_name(&name)?;
+
+    let mut args = vec![
+        "profile".to_string(),
+        "set".to_string(),
+        "--name".to_string(),
+        name,
+        "--model-provider".to_string(),
+        "prismctl".to_string(),
+    ];
+    if let Some(m) = model {
+        args.push("--model".to_string());
+        args.push(m.to_string());
+    }
+    let approval = ApprovalPolicy::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_APPROVAL_POLICY);
+    push_choice(&mut args, "--approval-policy", &prompt, approval)?;
+    let effort = ReasoningEffort::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_REASONING_EFFORT);
+    push_choice(&mut args, "--reasoning-effort", &prompt, effort)?;
+    let prompt = t!(keys::WIZARD_CODEX_PROFILE_DEFAULT);
+    if prompt_confirm(&prompt, false)? {
+        args.push("--default".to_string());
+    }
+    Ok(Some(args))
+}
+
+/// Optionally set root behavior settings (`codex settings set`).
+fn prompt_settings_args() -> Result<Option<Vec<String>>, String> {
+    let prompt = t!(keys::WIZARD_CODEX_CONFIGURE_SETTINGS);
+    if !prompt_confirm(&prompt, false)? {
+        return Ok(None);
+    }
+    let mut args = vec!["settings".to_string(), "set".to_string()];
+    let approval = ApprovalPolicy::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_APPROVAL_POLICY);
+    push_choice(&mut args, "--approval-policy", &prompt, approval)?;
+    let sandbox = SandboxMode::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_SANDBOX_MODE);
+    let sandbox = push_choice(&mut args, "--sandbox-mode", &prompt, sandbox)?;
+    if sandbox.as_deref() == Some(SandboxMode::WorkspaceWrite.as_str()) {
+        let prompt = t!(keys::WIZARD_CODEX_NETWORK_ACCESS);
+        let network = prompt_confirm(&prompt, false)?;
+        args.push("--network-access".to_string());
+        args.push(network.to_string());
+    }
+    let effort = ReasoningEffort::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_REASONING_EFFORT);
+    push_choice(&mut args, "--reasoning-effort", &prompt, effort)?;
+    let verbosity = Verbosity::ALL.iter().map(|v| v.as_str());
+    let prompt = t!(keys::WIZARD_CODEX_VERBOSITY);
+    push_choice(&mut args, "--verbosity", &prompt, verbosity)?;
+
+    if args.len() == 2 {
+        return Ok(None);
+    }
+    Ok(Some(args))
+}
+
+/// Select one of `values` (or skip); a chosen value is appended as `flag <value>`.
+fn push_choice<'a>(
+    args: &mut Vec<String>,
+    flag: &str,
+    title: &str,
+    values: impl Iterator<Item = &'a str>,
+) -> Result<Option<String>, String> {
+    let skip = t!(keys::WIZARD_MODEL_SKIP);
+    let mut options = vec![skip.clone()];
+    options.extend(values.map(str::to_string));
+    let choice = prompt_select(title, options, 0)?;
+    if choice == skip {
+        return Ok(None);
+    }
+    args.push(flag.to_string());
+    args.push(choice.clone());
+    Ok(Some(choice))
+}
@@ -1,6 +1,6 @@
 use crate::interactive::utils::{
-    prompt_confirm, prompt_line, prompt_multi_select, prompt_optional,
-    prompt_secret_with_env_default, prompt_select, validate_api_key_format,
+    prompt_confirm, prompt_line, prompt_multi_select, prompt_optional, prompt_required,
+    prompt_secret_with_env_default, prompt_select, validate_api_key_format, validate_http_url,
 };
 use crate::legacy;
 use prismctl_core::mcp;
@@ -9,22 +9,17 @@ use prismctl_i18n::{keys, t};
 pub fn wizard_configure_gemini() -> Result<(), String> {
     println!("\n{}\n", t!(keys::WIZARD_GEMINI_TITLE));
 
-    let api_key_prompt = t!(keys::WIZARD_GEMINI_PROMPT_API_KEY);
-    let api_key =
-        prompt_secret_with_env_default(&api_key_prompt, &["GEMINI_API_KEY", "PRISMCTL_API_KEY"])?;
-    if let Some(k) = &api_key {
-        validate_api_key_format(k)?;
-    }
+    let env_flags = prompt_auth_env_flags()?;
     let model_prompt = t!(keys::WIZARD_GEMINI_PROMPT_MODEL);
     let model = prompt_optional(&model_prompt)?;
 
-    if api_key.is_none() && model.is_none() {
+    if env_flags.is_empty() && model.is_none() {
         println!("{}\n", t!(keys::WIZARD_NO_CHANGES_CANCELLED));
         return Ok(());
     }
 
-    // API key scope (user vs project).
-    let (env_scope_flag, env_project_path) = if api_key.is_some() {
+    // .env scope (user vs project).
+    let (env_scope_flag, env_project_path) = if !env_flags.is_empty() {
         let title = t!(keys::MENU_GEMINI_ENV_SCOPE_TITLE);
         let user = t!(keys::MENU_GEMINI_ENV_SCOPE_USER);
         let project = t!(keys::MENU_GEMINI_ENV_SCOPE_PROJECT);
@@ -82,15 +77,14 @@ pub fn wizard_configure_gemini() -> Result<(), String> {
     };
 
     // Preview.
-    if let Some(v) = &api_key {
+    if !env_flags.is_empty() {
         let mut cmd = vec![
             "env".to_string(),
             "set".to_string(),
             "--scope".to_string(),
             env_scope_flag.to_string(),
-            "--api-key".to_string(),
-            v.clone(),
         ];
+        cmd.extend(env_flags.iter().cloned());
         if let Some(p) = &env_project_path {
             cmd.push("--project-path".to_string());
             cmd.push(p.clone());
@@ -119,16 +113,15 @@ pub fn wizard_configure_gemini() -> Result<(), String> {
         return Ok(());
     }
 
-    if let Some(v) = api_key {
+    if !env_flags.is_empty() {
         let mut cmd = vec![
             "env".to_string(),
             "set".to_string(),
             "--scope".to_string(),
             env_scope_flag.to_string(),
-            "--api-key".to_string(),
-            v,
-            "--apply".to_string(),
         ];
+        cmd.extend(env_flags);
+        cmd.push("--apply".to_string());
         if let Some(p) = env_project_path {
             cmd.push("--project-path".to_string());
             cmd.push(p);
@@ -237,3 +230,66 @@ pub fn wizard_configure_gemini() -> Result<(), String> {
     println!();
     Ok(())
 }
+
+/// Ask for the auth mode and its values; returns `gemini env set` flags (empty = skip `.env`).
+pub(crate) fn prompt_auth_env_flags() -> Result<Vec<String>, String> {
+    let title = t!(keys::WIZARD_GEMINI_AUTH_MODE_TITLE);
+    let api_key_mode = t!(keys::WIZARD_GEMINI_AUTH_MODE_API_KEY);
+    let vertex_mode = t!(keys::WIZARD_GEMINI_AUTH_MODE_VERTEX_AI);
+    let base_url_mode = t!(keys::WIZARD_GEMINI_AUTH_MODE_BASE_URL);
+    let skip = t!(keys::WIZARD_GEMINI_AUTH_MODE_SKIP);
+    let choice = prompt_select(
+        &title,
+        vec![
+            api_key_mode.clone(),
+            vertex_mode.clone(),
+            base_url_mode.clone(),
+            skip,
+        ],
+        0,
+    )?;
+
+    let mut flags = Vec::new();
+    let mut push = |flag: &str, value: Option<String>| {
+        if let Some(v) = value {
+            flags.push(flag.to_string());
+            flags.push(v);
+        }
+    };
+
+    if choice == vertex_mode {
+        push("--mode", Some("vertex-ai".to_string()));
+        let p = t!(keys::WIZARD_GEMINI_PROMPT_PROJECT);
+        push("--project", prompt_optional(&p)?);
+        let p = t!(keys::WIZARD_GEMINI_PROMPT_LOCATION);
+        push("--location", prompt_optional(&p)?);
+        let p = t!(keys::WIZARD_GEMINI_PROMPT_VERTEX_API_KEY);
+        push(
+            "--api-key",
+            prompt_secret_with_env_default(&p, &["GOOGLE_API_KEY"])?,
+        );
+    } else if choice == api_key_mode || choice == base_url_mode {
+        let base_url = if choice == base_url_mode {
+            let p = t!(keys::WIZARD_GEMINI_PROMPT_BASE_URL);
+            let url = prompt_required(&p)?;
+            validate_http_url(&url)?;
+            push("--mode", Some("base-url".to_string()));
+            Some(url)
+        } else {
+            push("--mode", Some("api-key".to_string()));
+            None
+        };
+        push("--base-url", base_url);
+
+        let p = t!(keys::WIZARD_GEMINI_PROMPT_API_KEY);
+        let api_key = prompt_secret_with_env_default(&p, &["GEMINI_API_KEY", "PRISMCTL_API_KEY"])?;
+        if let Some(k) = &api_key {
+            validate_api_key_format(k)?;
+        } else if choice == api_key_mode {
+            // Nothing to write for a skipped key.
+            return Ok(Vec::new());
+        }
+        push("--api-key", api_key);
+    }
+    Ok(flags)
+}
@@ -1,6 +1,7 @@
 use crate::interactive;
 use crate::interactive::utils::{prompt_confirm, prompt_lang_selection, prompt_multi_select};
 use crate::legacy;
+use prismctl_core::tools;
 use prismctl_i18n::{keys, t};
 
 pub fn wizard_quick_init() -> Result<(), String> {
@@ -9,14 +10,14 @@ pub fn wizard_quick_init() -> Result<(), String> {
     println!("  - {}", t!(keys::WIZARD_QUICK_INIT_DESC_INIT));
     println!("  - {}\n", t!(keys::WIZARD_QUICK_INIT_DESC_CONFIG));
 
-    let tools = prompt_tools_multi_select()?;
+    let selected = prompt_tools_multi_select()?;
     let lang = prompt_lang_selection()?;
 
     // Step 1: init templates (dry-run) for selected tools.
-    let init_tools: Vec<String> = if tools.len() == 3 {
+    let init_tools: Vec<String> = if selected.len() == tools::registry().len() {
         vec!["all".to_string()]
     } else {
-        tools.clone()
+        selected.clone()
     };
     for tool in &init_tools {
         legacy::cmd_init(vec![
@@ -48,7 +49,7 @@ pub fn wizard_quick_init() -> Result<(), String> {
         return Ok(());
     }
 
-    for tool in tools {
+    for tool in selected {
         match tool.as_str() {
             "claude" => {
                 let p = t!(keys::WIZARD_QUICK_INIT_CONFIGURE_CLAUDE);
@@ -77,19 +78,15 @@ pub fn wizard_quick_init() -> Result<(), String> {
 }
 
 fn prompt_tools_multi_select() -> Result<Vec<String>, String> {
-    let options = vec![
-        "claude".to_string(),
-        "codex".to_string(),
-        "gemini".to_string(),
-    ];
+    let options: Vec<String> = tools::registry()
+        .iter()
+        .map(|t| t.id().to_string())
+        .collect();
+    let defaults: Vec<usize> = (0..options.len()).collect();
     let prompt = t!(keys::WIZARD_QUICK_INIT_SELECT_TOOLS);
-    let selected = prompt_multi_select(&prompt, options, vec![0, 1, 2])?;
+    let selected = prompt_multi_select(&prompt, options.clone(), defaults)?;
     if selected.is_empty() {
-        return Ok(vec![
-            "claude".to_string(),
-            "codex".to_string(),
-            "gemini".to_string(),
-        ]);
+        return Ok(options);
     }
     Ok(selected)
 }
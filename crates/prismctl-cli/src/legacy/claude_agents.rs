@@ -0,0 +1,236 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_config_scope, parse_home, parse_lang,
+    parse_optional_value, parse_project_path_opt, parse_required_value, print_changeset_preview,
+    resolve_project_root, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::claude_agents::{self, AgentOverrides};
+use prismctl_core::paths::{ConfigScope, PrismctlHome};
+use prismctl_core::templates;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn cmd_claude_agent(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("claude agent"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_claude_agent_list(args),
+        "install" => cmd_claude_agent_install(args),
+        "remove" => cmd_claude_agent_remove(args),
+        "create" => cmd_claude_agent_create(args),
+        _ => Err(err_unknown_subcommand_with_help("claude agent", &action)),
+    }
+}
+
+/// `--scope user|project` (+ `--project-path`) and `--home` -> the `agents/` directory.
+fn parse_agents_dir(args: &mut Vec<String>) -> Result<PathBuf, String> {
+    let home = parse_home(args)?;
+    let scope = parse_config_scope(args)?;
+    let project_path = parse_project_path_opt(args)?;
+    let project_root = match scope {
+        ConfigScope::User => None,
+        ConfigScope::Project | ConfigScope::Local => Some(resolve_project_root(project_path)?),
+    };
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    Ok(claude_agents::agents_dir(&home, project_root.as_deref()))
+}
+
+fn parse_overrides(args: &mut Vec<String>) -> Result<AgentOverrides, String> {
+    Ok(AgentOverrides {
+        model: parse_optional_value(args, "--model")?,
+        tools: parse_optional_value(args, "--tools")?,
+    })
+}
+
+fn preview_and_apply(cs: &ChangeSet, mode: ApplyMode) -> Result<bool, String> {
+    print_changeset_preview(cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(false);
+    }
+    apply_changeset(cs, mode)?;
+    Ok(true)
+}
+
+fn cmd_claude_agent_list(mut args: Vec<String>) -> Result<(), String> {
+    let dir = parse_agents_dir(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude agent list", &args));
+    }
+
+    println!(
+        "{}",
+        tf!(keys::CLAUDE_AGENT_LIST_TITLE, "path" => dir.display().to_string())
+    );
+    let agents = claude_agents::list_claude_agents(&dir);
+    if agents.is_empty() {
+        println!("  {}", t!(keys::CLAUDE_AGENT_LIST_EMPTY));
+    }
+    for a in &agents {
+        let mut line = format!("  - {}", a.name);
+        if let Some(model) = &a.model {
+            line.push_str(&format!(" [model: {model}]"));
+        }
+        if let Some(tools) = &a.tools {
+            line.push_str(&format!(" [tools: {tools}]"));
+        }
+        let rel = a.path.strip_prefix(&dir).unwrap_or(&a.path);
+        line.push_str(&format!(" ({})", rel.display()));
+        println!("{line}");
+    }
+
+    println!("\n{}", t!(keys::CLAUDE_AGENT_BUILTIN_TITLE));
+    for name in templates::claude_agent_names() {
+        if agents.iter().any(|a| a.name == *name) {
+            println!("  - {name} {}", t!(keys::CLAUDE_PRESET_INSTALLED_MARKER));
+        } else {
+            println!("  - {name}");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_claude_agent_install(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let lang = parse_lang(&mut args)?;
+    let dir = parse_agents_dir(&mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    let overrides = parse_overrides(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude agent install",
+            &args,
+        ));
+    }
+
+    let cs = claude_agents::plan_install_agent(&dir, &name, lang, &overrides)
+        .map_err(crate::errors::usage)?;
+    let Some((path, bytes)) = cs.iter().find_map(|c| match c {
+        Change::WriteFile { path, bytes, .. } => Some((path.clone(), bytes.clone())),
+        _ => None,
+    }) else {
+        return Ok(());
+    };
+    // Re-installing an unchanged file is harmless; replacing local edits needs --yes.
+    let overwrites = fs::read(&path).is_ok_and(|existing| existing != bytes);
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if overwrites && !yes {
+        return Err(crate::errors::usage(
+            danger_claude_agent_overwrite_confirmation(&path),
+        ));
+    }
+    apply_changeset(&cs, mode)?;
+    print_usage_hint(&path, &name);
+    Ok(())
+}
+
+fn cmd_claude_agent_create(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let dir = parse_agents_dir(&mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    let description = parse_required_value(&mut args, "--description")?;
+    let overrides = parse_overrides(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude agent create", &args));
+    }
+
+    let content = claude_agents::scaffold_agent(&name, &description, &overrides)
+        .map_err(crate::errors::usage)?;
+    if let Some(existing) = claude_agents::find_claude_agent(&dir, &name) {
+        return Err(crate::errors::usage(tf!(
+            keys::ERROR_CLAUDE_AGENT_EXISTS,
+            "path" => existing.path.display().to_string()
+        )));
+    }
+
+    let path = dir.join(format!("{name}.md"));
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: dir });
+    cs.push(Change::WriteFile {
+        path: path.clone(),
+        bytes: content.into_bytes(),
+        overwrite: false,
+    });
+    if preview_and_apply(&cs, mode)? {
+        print_usage_hint(&path, &name);
+    }
+    Ok(())
+}
+
+fn cmd_claude_agent_remove(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let dir = parse_agents_dir(&mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude agent remove", &args));
+    }
+
+    let Some(agent) = claude_agents::find_claude_agent(&dir, &name) else {
+        return Err(crate::errors::usage(
+            tf!(keys::ERROR_CLAUDE_AGENT_NOT_FOUND, "name" => name),
+        ));
+    };
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::RemoveFile {
+        path: agent.path.clone(),
+    });
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(
+            danger_claude_agent_remove_confirmation(&agent.path),
+        ));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn print_usage_hint(path: &Path, name: &str) {
+    println!(
+        "{}",
+        tf!(
+            keys::CLAUDE_AGENT_USAGE_HINT,
+            "path" => path.display().to_string(),
+            "name" => name
+        )
+    );
+}
+
+fn danger_claude_agent_overwrite_confirmation(path: &Path) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_CLAUDE_AGENT_OVERWRITE_TYPE),
+        tf!(keys::DANGER_CLAUDE_AGENT_OVERWRITE_SCOPE, "path" => path.display().to_string()),
+        t!(keys::DANGER_CLAUDE_AGENT_OVERWRITE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
+
+fn danger_claude_agent_remove_confirmation(path: &Path) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        tf!(keys::DANGER_CUSTOM_PROMPT_REMOVE_TYPE, "kind" => "Claude Code subagent"),
+        tf!(keys::DANGER_CUSTOM_PROMPT_REMOVE_SCOPE, "path" => path.display().to_string()),
+        t!(keys::DANGER_CUSTOM_PROMPT_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,201 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_claude_settings_target, parse_home,
+    parse_optional_value, print_changeset_preview,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::claude_hooks::{self, ClaudeHook, HookPreset};
+use prismctl_core::paths::ConfigScope;
+use prismctl_core::paths::PrismctlHome;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn cmd_claude_hooks(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("claude hooks"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_claude_hooks_list(args),
+        "add" => cmd_claude_hooks_add(args),
+        "remove" => cmd_claude_hooks_remove(args),
+        _ => Err(err_unknown_subcommand_with_help("claude hooks", &action)),
+    }
+}
+
+/// What `add`/`remove` act on: a built-in preset, or a raw `--event`/`--command` pair.
+enum HookTarget {
+    Preset(HookPreset),
+    Command { event: String, command: String },
+}
+
+fn parse_hook_target(args: &mut Vec<String>) -> Result<HookTarget, String> {
+    let preset = parse_optional_value(args, "--preset")?;
+    let event = parse_optional_value(args, "--event")?;
+    let command = parse_optional_value(args, "--command")?;
+    match (preset, event, command) {
+        (Some(name), None, None) => HookPreset::parse(&name)
+            .map(HookTarget::Preset)
+            .map_err(crate::errors::usage),
+        (None, Some(event), Some(command)) => Ok(HookTarget::Command { event, command }),
+        _ => Err(crate::errors::usage(t!(keys::ERROR_CLAUDE_HOOKS_TARGET))),
+    }
+}
+
+fn read_settings(args: &mut Vec<String>) -> Result<(ConfigScope, PathBuf, String), String> {
+    let home = parse_home(args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (scope, path) = parse_claude_settings_target(args, &home)?;
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    Ok((scope, path, content))
+}
+
+fn preview_and_apply(cs: &ChangeSet, mode: ApplyMode) -> Result<(), String> {
+    print_changeset_preview(cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(cs, mode)
+}
+
+fn cmd_claude_hooks_list(mut args: Vec<String>) -> Result<(), String> {
+    let (scope, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude hooks list", &args));
+    }
+
+    let hooks = claude_hooks::list_claude_hooks(&content).map_err(crate::errors::runtime)?;
+    println!(
+        "{}",
+        tf!(keys::CLAUDE_HOOKS_TITLE, "path" => path.display().to_string())
+    );
+    if hooks.is_empty() {
+        println!("  {}", t!(keys::CLAUDE_HOOKS_LIST_EMPTY));
+    }
+    for h in &hooks {
+        let mut line = format!("  - {}", h.event);
+        if let Some(m) = &h.matcher {
+            line.push_str(&format!(" [{m}]"));
+        }
+        line.push_str(&format!(": {}", h.command));
+        if let Some(timeout) = h.timeout {
+            line.push_str(&format!(" (timeout {timeout}s)"));
+        }
+        println!("{line}");
+    }
+
+    println!("\n{}", t!(keys::CLAUDE_HOOKS_PRESETS_TITLE));
+    for preset in HookPreset::ALL {
+        let mut line = format!("  - {} ({}", preset.as_str(), preset.event());
+        if let Some(m) = preset.matcher() {
+            line.push_str(&format!(" [{m}]"));
+        }
+        line.push(')');
+        if claude_hooks::hook_preset_installed(&hooks, scope, &path, *preset) {
+            line.push_str(&format!(" {}", t!(keys::CLAUDE_PRESET_INSTALLED_MARKER)));
+        }
+        println!("{line}");
+    }
+    Ok(())
+}
+
+fn cmd_claude_hooks_add(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let target = parse_hook_target(&mut args)?;
+    let matcher = parse_optional_value(&mut args, "--matcher")?;
+    let timeout = match parse_optional_value(&mut args, "--timeout")? {
+        Some(v) => Some(v.parse::<u64>().ok().filter(|t| *t > 0).ok_or_else(|| {
+            crate::errors::usage(tf!(keys::ERROR_CLAUDE_HOOKS_TIMEOUT, "value" => v))
+        })?),
+        None => None,
+    };
+    let (scope, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude hooks add", &args));
+    }
+
+    let cs = match target {
+        HookTarget::Preset(preset) => {
+            if matcher.is_some() || timeout.is_some() {
+                return Err(crate::errors::usage(t!(keys::ERROR_CLAUDE_HOOKS_TARGET)));
+            }
+            claude_hooks::plan_install_hook_preset(scope, &path, &content, preset)
+                .map_err(crate::errors::runtime)?
+        }
+        HookTarget::Command { event, command } => {
+            let hook = ClaudeHook {
+                event,
+                matcher,
+                command,
+                timeout,
+            };
+            let next =
+                claude_hooks::add_claude_hook(&content, &hook).map_err(crate::errors::usage)?;
+            let mut cs = ChangeSet::new();
+            if let Some(parent) = path.parent() {
+                cs.push(Change::CreateDirAll {
+                    path: parent.to_path_buf(),
+                });
+            }
+            cs.push(Change::WriteFile {
+                path,
+                bytes: next.into_bytes(),
+                overwrite: true,
+            });
+            cs
+        }
+    };
+    preview_and_apply(&cs, mode)
+}
+
+fn cmd_claude_hooks_remove(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let target = parse_hook_target(&mut args)?;
+    let (scope, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude hooks remove", &args));
+    }
+
+    let cs = match target {
+        HookTarget::Preset(preset) => {
+            match claude_hooks::plan_remove_hook_preset(scope, &path, &content, preset)
+                .map_err(crate::errors::runtime)?
+            {
+                Some(cs) => cs,
+                None => {
+                    println!(
+                        "{}",
+                        tf!(keys::CLAUDE_HOOKS_PRESET_NOT_INSTALLED, "name" => preset.as_str())
+                    );
+                    return Ok(());
+                }
+            }
+        }
+        HookTarget::Command { event, command } => {
+            match claude_hooks::remove_claude_hook(&content, &event, &command)
+                .map_err(crate::errors::runtime)?
+            {
+                Some(next) => {
+                    let mut cs = ChangeSet::new();
+                    cs.push(Change::WriteFile {
+                        path,
+                        bytes: next.into_bytes(),
+                        overwrite: true,
+                    });
+                    cs
+                }
+                None => {
+                    println!(
+                        "{}",
+                        tf!(keys::CLAUDE_HOOKS_NOT_FOUND, "event" => event, "command" => command)
+                    );
+                    return Ok(());
+                }
+            }
+        }
+    };
+    preview_and_apply(&cs, mode)
+}
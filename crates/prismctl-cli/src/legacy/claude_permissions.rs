@@ -0,0 +1,219 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_claude_settings_path, parse_home,
+    print_changeset_preview,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::claude_permissions::{self, PermissionList, PermissionMode, PermissionsPreset};
+use prismctl_core::paths::PrismctlHome;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn cmd_claude_permissions(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("claude permissions"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_claude_permissions_list(args),
+        "allow" => cmd_claude_permissions_add(args, PermissionList::Allow),
+        "deny" => cmd_claude_permissions_add(args, PermissionList::Deny),
+        "ask" => cmd_claude_permissions_add(args, PermissionList::Ask),
+        "remove" => cmd_claude_permissions_remove(args),
+        "mode" => cmd_claude_permissions_mode(args),
+        "preset" => cmd_claude_permissions_preset(args),
+        _ => Err(err_unknown_subcommand_with_help(
+            "claude permissions",
+            &action,
+        )),
+    }
+}
+
+/// Take the remaining positional arguments (rules may contain spaces, e.g. `Bash(git diff:*)`).
+fn take_positionals(args: &mut Vec<String>) -> Vec<String> {
+    let mut out = Vec::new();
+    while let Some(first) = args.first() {
+        if first.starts_with("--") {
+            break;
+        }
+        out.push(args.remove(0));
+    }
+    out
+}
+
+/// Common flags for write subcommands: `(mode, settings.json path, existing content)`.
+fn parse_target(args: &mut Vec<String>) -> Result<(ApplyMode, PathBuf, String), String> {
+    let home = parse_home(args)?;
+    let mode = parse_apply_mode(args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let path = parse_claude_settings_path(args, &home)?;
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    Ok((mode, path, existing))
+}
+
+fn write_settings(path: PathBuf, next: String, mode: ApplyMode) -> Result<(), String> {
+    let mut cs = ChangeSet::new();
+    if let Some(parent) = path.parent() {
+        cs.push(Change::CreateDirAll {
+            path: parent.to_path_buf(),
+        });
+    }
+    cs.push(Change::WriteFile {
+        path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    println!();
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_claude_permissions_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let path = parse_claude_settings_path(&mut args, &home)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude permissions list",
+            &args,
+        ));
+    }
+
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    let perms =
+        claude_permissions::read_claude_permissions(&content).map_err(crate::errors::runtime)?;
+    println!(
+        "{}",
+        tf!(keys::CLAUDE_PERMISSIONS_TITLE, "path" => path.display().to_string())
+    );
+    match &perms.default_mode {
+        Some(mode) => println!("  {}", tf!(keys::CLAUDE_PERMISSIONS_MODE, "mode" => mode)),
+        None => println!("  {}", t!(keys::CLAUDE_PERMISSIONS_MODE_UNSET)),
+    }
+    for list in PermissionList::ALL {
+        println!("  {}:", list.as_str());
+        let rules = perms.rules(*list);
+        if rules.is_empty() {
+            println!("    {}", t!(keys::CLAUDE_PERMISSIONS_LIST_EMPTY));
+        }
+        for rule in rules {
+            println!("    - {rule}");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_claude_permissions_add(mut args: Vec<String>, list: PermissionList) -> Result<(), String> {
+    let (mode, path, existing) = parse_target(&mut args)?;
+    let rules = take_positionals(&mut args);
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            &format!("claude permissions {}", list.as_str()),
+            &args,
+        ));
+    }
+    if rules.is_empty() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_CLAUDE_PERMISSIONS_NEEDS_RULES
+        )));
+    }
+
+    let next = claude_permissions::add_claude_permission_rules(&existing, list, &rules)
+        .map_err(crate::errors::usage)?;
+    for rule in &rules {
+        println!("{}: {rule}", list.as_str());
+    }
+    write_settings(path, next, mode)
+}
+
+fn cmd_claude_permissions_remove(mut args: Vec<String>) -> Result<(), String> {
+    let (mode, path, existing) = parse_target(&mut args)?;
+    let rules = take_positionals(&mut args);
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude permissions remove",
+            &args,
+        ));
+    }
+    if rules.is_empty() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_CLAUDE_PERMISSIONS_NEEDS_RULES
+        )));
+    }
+
+    let mut next = existing.clone();
+    for rule in &rules {
+        match claude_permissions::remove_claude_permission_rule(&next, rule)
+            .map_err(crate::errors::runtime)?
+        {
+            Some(out) => next = out,
+            None => println!(
+                "{}",
+                tf!(keys::CLAUDE_PERMISSIONS_RULE_NOT_FOUND, "rule" => rule)
+            ),
+        }
+    }
+    if next == existing {
+        return Ok(());
+    }
+    write_settings(path, next, mode)
+}
+
+fn cmd_claude_permissions_mode(mut args: Vec<String>) -> Result<(), String> {
+    let (mode, path, existing) = parse_target(&mut args)?;
+    let Some(value) = take_positionals(&mut args).into_iter().next() else {
+        return Err(err_missing_subcommand_with_help("claude permissions mode"));
+    };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude permissions mode",
+            &args,
+        ));
+    }
+    let permission_mode = PermissionMode::parse(&value).map_err(crate::errors::usage)?;
+
+    let next = claude_permissions::set_claude_permission_mode(&existing, permission_mode)
+        .map_err(crate::errors::runtime)?;
+    if permission_mode == PermissionMode::BypassPermissions {
+        println!("{}", t!(keys::CLAUDE_PERMISSIONS_BYPASS_WARNING));
+    }
+    write_settings(path, next, mode)
+}
+
+fn cmd_claude_permissions_preset(mut args: Vec<String>) -> Result<(), String> {
+    let (mode, path, existing) = parse_target(&mut args)?;
+    let Some(value) = take_positionals(&mut args).into_iter().next() else {
+        return Err(err_missing_subcommand_with_help(
+            "claude permissions preset",
+        ));
+    };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude permissions preset",
+            &args,
+        ));
+    }
+    let preset = PermissionsPreset::parse(&value).map_err(crate::errors::usage)?;
+
+    let next = claude_permissions::apply_claude_permissions_preset(&existing, preset)
+        .map_err(crate::errors::runtime)?;
+    println!(
+        "{}",
+        tf!(
+            keys::CLAUDE_PERMISSIONS_PRESET_RULES,
+            "name" => preset.as_str(),
+            "mode" => preset.default_mode().as_str()
+        )
+    );
+    for list in PermissionList::ALL {
+        println!("  {}: {}", list.as_str(), preset.rules(*list).join(", "));
+    }
+    write_settings(path, next, mode)
+}
@@ -0,0 +1,151 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_claude_settings_target, parse_home,
+    parse_optional_value, parse_required_value, print_changeset_preview,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::claude_statusline::{self, StatusLinePreset};
+use prismctl_core::paths::ConfigScope;
+use prismctl_core::paths::PrismctlHome;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn cmd_claude_statusline(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("claude statusline"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "show" => cmd_claude_statusline_show(args),
+        "set" => cmd_claude_statusline_set(args),
+        "preset" => cmd_claude_statusline_preset(args),
+        "clear" => cmd_claude_statusline_clear(args),
+        _ => Err(err_unknown_subcommand_with_help(
+            "claude statusline",
+            &action,
+        )),
+    }
+}
+
+fn read_settings(args: &mut Vec<String>) -> Result<(ConfigScope, PathBuf, String), String> {
+    let home = parse_home(args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (scope, path) = parse_claude_settings_target(args, &home)?;
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    Ok((scope, path, content))
+}
+
+fn preview_and_apply(cs: &ChangeSet, mode: ApplyMode) -> Result<(), String> {
+    print_changeset_preview(cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(cs, mode)
+}
+
+fn cmd_claude_statusline_show(mut args: Vec<String>) -> Result<(), String> {
+    let (_, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude statusline show",
+            &args,
+        ));
+    }
+
+    println!("{}", path.display());
+    match claude_statusline::read_claude_statusline(&content).map_err(crate::errors::runtime)? {
+        Some(line) => {
+            println!(
+                "  {}",
+                tf!(keys::CLAUDE_STATUSLINE_CURRENT, "command" => line.command)
+            );
+            if let Some(padding) = line.padding {
+                println!("  padding: {padding}");
+            }
+        }
+        None => println!("  {}", t!(keys::CLAUDE_STATUSLINE_UNSET)),
+    }
+
+    println!("\n{}", t!(keys::CLAUDE_STATUSLINE_PRESETS_TITLE));
+    for preset in StatusLinePreset::ALL {
+        println!("  - {}: {}", preset.as_str(), preset.segments());
+    }
+    Ok(())
+}
+
+fn cmd_claude_statusline_set(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let command = parse_required_value(&mut args, "--command")?;
+    let padding = match parse_optional_value(&mut args, "--padding")? {
+        Some(v) => Some(v.parse::<u64>().map_err(|_| {
+            crate::errors::usage(tf!(keys::ERROR_CLAUDE_STATUSLINE_PADDING, "value" => v))
+        })?),
+        None => None,
+    };
+    let (_, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude statusline set",
+            &args,
+        ));
+    }
+
+    let next = claude_statusline::set_claude_statusline(&content, &command, padding)
+        .map_err(crate::errors::usage)?;
+    let mut cs = ChangeSet::new();
+    if let Some(parent) = path.parent() {
+        cs.push(Change::CreateDirAll {
+            path: parent.to_path_buf(),
+        });
+    }
+    cs.push(Change::WriteFile {
+        path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    preview_and_apply(&cs, mode)
+}
+
+fn cmd_claude_statusline_preset(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let Some(name) = args.first().filter(|a| !a.starts_with("--")).cloned() else {
+        return Err(err_missing_subcommand_with_help("claude statusline preset"));
+    };
+    args.remove(0);
+    let preset = StatusLinePreset::parse(&name).map_err(crate::errors::usage)?;
+    let (scope, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude statusline preset",
+            &args,
+        ));
+    }
+
+    let cs = claude_statusline::plan_statusline_preset(scope, &path, &content, preset)
+        .map_err(crate::errors::runtime)?;
+    preview_and_apply(&cs, mode)
+}
+
+fn cmd_claude_statusline_clear(mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let (_, path, content) = read_settings(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "claude statusline clear",
+            &args,
+        ));
+    }
+
+    match claude_statusline::plan_clear_statusline(&path, &content)
+        .map_err(crate::errors::runtime)?
+    {
+        Some(cs) => preview_and_apply(&cs, mode),
+        None => {
+            println!("{}", t!(keys::CLAUDE_STATUSLINE_UNSET));
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_home, parse_optional_value,
+    print_changeset_preview,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::codex_auth::{self, CodexAuthMode};
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+
+pub fn cmd_codex_auth(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("codex auth"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "use" => cmd_codex_auth_use(args),
+        _ => Err(err_unknown_subcommand_with_help("codex auth", &action)),
+    }
+}
+
+fn cmd_codex_auth_use(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let api_key = parse_optional_value(&mut args, "--api-key")?;
+    let auth_mode = match args.first() {
+        Some(first) if !first.starts_with('-') => {
+            CodexAuthMode::parse(&args.remove(0)).map_err(crate::errors::usage)?
+        }
+        _ => return Err(err_missing_subcommand_with_help("codex auth use")),
+    };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex auth use", &args));
+    }
+    if api_key.is_some() && auth_mode != CodexAuthMode::ApiKey {
+        return Err(err_unsupported_args_with_help(
+            "codex auth use",
+            &vec!["--api-key".to_string()],
+        ));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let codex_root = home.tool_root(Tool::Codex);
+    let config_path = codex_root.join("config.toml");
+    let auth_path = codex_root.join("auth.json");
+    let config = fs::read_to_string(&config_path).unwrap_or_default();
+    let auth = fs::read_to_string(&auth_path).unwrap_or_default();
+    let (next_config, next_auth) =
+        codex_auth::use_codex_auth(&config, &auth, auth_mode, api_key.as_deref())
+            .map_err(crate::errors::usage)?;
+
+    let ts = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| tf!(keys::ERROR_TIMESTAMP, "error" => e))?
+        .as_secs();
+    let backup_dir = codex_root
+        .join("backup")
+        .join("prismctl")
+        .join(ts.to_string());
+
+    let mut cs = ChangeSet::new();
+    let mut backups = Vec::new();
+    if !auth.trim().is_empty() {
+        backups.push(("auth.json", auth));
+    }
+    if next_config != config && !config.trim().is_empty() {
+        backups.push(("config.toml", config.clone()));
+    }
+    if !backups.is_empty() {
+        cs.push(Change::CreateDirAll {
+            path: backup_dir.clone(),
+        });
+    }
+    for (name, content) in backups {
+        cs.push(Change::WriteFile {
+            path: backup_dir.join(name),
+            bytes: content.into_bytes(),
+            overwrite: false,
+        });
+    }
+    cs.push(Change::CreateDirAll { path: codex_root });
+    cs.push(Change::WriteFile {
+        path: auth_path,
+        bytes: next_auth.into_bytes(),
+        overwrite: true,
+    });
+    if next_config != config {
+        cs.push(Change::WriteFile {
+            path: config_path,
+            bytes: next_config.into_bytes(),
+            overwrite: true,
+        });
+    }
+
+    print_changeset_preview(&cs, mode);
+    if api_key.is_some() {
+        println!(
+            "{}",
+            tf!(keys::CODEX_AUTH_KEY_HIDDEN, "key" => "OPENAI_API_KEY")
+        );
+    }
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)?;
+    println!(
+        "{}",
+        tf!(keys::CODEX_AUTH_SWITCHED, "mode" => auth_mode.as_str())
+    );
+    Ok(())
+}
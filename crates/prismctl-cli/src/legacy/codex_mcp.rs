@@ -0,0 +1,150 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_home, parse_required_value,
+    print_changeset_preview, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::codex;
+use prismctl_core::mcp::{self, McpTransport};
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+
+pub fn cmd_codex_mcp(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("codex mcp"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_codex_mcp_list(args),
+        "builtin" => cmd_codex_mcp_builtin(args),
+        "add" => cmd_codex_mcp_add(args),
+        "remove" => cmd_codex_mcp_remove(args),
+        _ => Err(err_unknown_subcommand_with_help("codex mcp", &action)),
+    }
+}
+
+fn cmd_codex_mcp_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex mcp list", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let config_path = home.tool_root(Tool::Codex).join("config.toml");
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let names = codex::list_codex_mcp_servers(&existing);
+
+    println!(
+        "{}",
+        tf!(keys::CODEX_MCP_LIST_TITLE, "path" => config_path.display().to_string())
+    );
+    if names.is_empty() {
+        println!("  {}", t!(keys::CODEX_MCP_LIST_EMPTY));
+    }
+    for name in names {
+        match codex::describe_codex_mcp_server(&existing, &name) {
+            Some(desc) => println!("  - {}: {}", name, desc),
+            None => println!("  - {}", name),
+        }
+    }
+    Ok(())
+}
+
+fn cmd_codex_mcp_builtin(args: Vec<String>) -> Result<(), String> {
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex mcp builtin", &args));
+    }
+    println!("{}", t!(keys::CODEX_MCP_BUILTIN_TITLE));
+    for s in mcp::list_builtin_mcp_servers() {
+        match (s.transport, s.url, s.command) {
+            (McpTransport::Stdio, _, Some(cmd)) => {
+                println!("  - {} (stdio): {} {}", s.id, cmd, s.args.join(" "))
+            }
+            (_, Some(url), _) => println!("  - {} (url): {}", s.id, url),
+            _ => println!("  - {}", s.id),
+        }
+    }
+    Ok(())
+}
+
+fn cmd_codex_mcp_add(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex mcp add", &args));
+    }
+
+    let def = mcp::builtin_mcp_server(&name)
+        .ok_or_else(|| crate::errors::usage(format!("未知 MCP server: {}（内置）", name)))?;
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let codex_root = home.tool_root(Tool::Codex);
+    let config_path = codex_root.join("config.toml");
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next = codex::upsert_codex_mcp_server_in_config_toml(&existing, def)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: codex_root });
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_mcp_remove(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex mcp remove", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let config_path = home.tool_root(Tool::Codex).join("config.toml");
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next = codex::remove_codex_mcp_server_in_config_toml(&existing, &name)
+        .map_err(crate::errors::usage)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(danger_codex_mcp_remove_confirmation(
+            &name,
+        )));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn danger_codex_mcp_remove_confirmation(name: &str) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_CODEX_MCP_REMOVE_TYPE),
+        tf!(keys::DANGER_CODEX_MCP_REMOVE_SCOPE, "name" => name),
+        t!(keys::DANGER_CODEX_MCP_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,192 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_home, parse_name_or_positional,
+    parse_optional_value, print_changeset_preview, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::codex::{self, CodexProfileEntry};
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn cmd_codex_profile(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("codex profile"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_codex_profile_list(args),
+        "set" => cmd_codex_profile_set(args),
+        "default" => cmd_codex_profile_default(args),
+        "remove" => cmd_codex_profile_remove(args),
+        _ => Err(err_unknown_subcommand_with_help("codex profile", &action)),
+    }
+}
+
+fn codex_config(home: &PrismctlHome) -> (PathBuf, String) {
+    let path = home.tool_root(Tool::Codex).join("config.toml");
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    (path, content)
+}
+
+fn write_config(home: &PrismctlHome, path: PathBuf, content: String) -> ChangeSet {
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll {
+        path: home.tool_root(Tool::Codex),
+    });
+    cs.push(Change::WriteFile {
+        path,
+        bytes: content.into_bytes(),
+        overwrite: true,
+    });
+    cs
+}
+
+fn cmd_codex_profile_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex profile list", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (path, content) = codex_config(&home);
+    let active = codex::active_codex_profile(&content);
+    let profiles = codex::list_codex_profiles(&content);
+
+    println!(
+        "{}",
+        tf!(keys::CODEX_PROFILE_LIST_TITLE, "path" => path.display().to_string())
+    );
+    if profiles.is_empty() {
+        println!("  {}", t!(keys::CODEX_PROFILE_LIST_EMPTY));
+        return Ok(());
+    }
+    for p in profiles {
+        if active.as_deref() == Some(p.name.as_str()) {
+            println!("  - {} {}", p.name, t!(keys::CODEX_PROFILE_DEFAULT_MARKER));
+        } else {
+            println!("  - {}", p.name);
+        }
+        let unset = || "-".to_string();
+        println!(
+            "    {}",
+            tf!(
+                keys::CODEX_PROFILE_LIST_DETAIL,
+                "model" => p.model.unwrap_or_else(unset),
+                "provider" => p.model_provider.unwrap_or_else(unset),
+                "approval" => p.approval_policy.unwrap_or_else(unset),
+                "effort" => p.model_reasoning_effort.unwrap_or_else(unset)
+            )
+        );
+    }
+    Ok(())
+}
+
+fn cmd_codex_profile_set(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let set_default = take_flag(&mut args, "--default");
+    let profile = CodexProfileEntry {
+        model: parse_optional_value(&mut args, "--model")?,
+        model_provider: parse_optional_value(&mut args, "--model-provider")?,
+        approval_policy: parse_optional_value(&mut args, "--approval-policy")?,
+        model_reasoning_effort: parse_optional_value(&mut args, "--reasoning-effort")?,
+        name: parse_name_or_positional(&mut args, "--name")?,
+    };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex profile set", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (path, content) = codex_config(&home);
+    let next = codex::upsert_codex_profile_in_config_toml(&content, &profile, set_default)
+        .map_err(crate::errors::usage)?;
+    let cs = write_config(&home, path, next);
+
+    print_changeset_preview(&cs, mode);
+    if set_default {
+        println!(
+            "{}",
+            tf!(keys::CODEX_PROFILE_DEFAULT_SET, "name" => &profile.name)
+        );
+    }
+    println!(
+        "{}",
+        tf!(keys::CODEX_PROFILE_USAGE_HINT, "name" => &profile.name)
+    );
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_profile_default(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let name = parse_name_or_positional(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "codex profile default",
+            &args,
+        ));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (path, content) = codex_config(&home);
+    let next =
+        codex::use_codex_profile_in_config_toml(&content, &name).map_err(crate::errors::usage)?;
+    let cs = write_config(&home, path, next);
+
+    print_changeset_preview(&cs, mode);
+    println!("{}", tf!(keys::CODEX_PROFILE_DEFAULT_SET, "name" => &name));
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_profile_remove(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let name = parse_name_or_positional(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "codex profile remove",
+            &args,
+        ));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (path, content) = codex_config(&home);
+    let next = codex::remove_codex_profile(&content, &name).map_err(crate::errors::usage)?;
+    let cs = write_config(&home, path, next);
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(
+            danger_codex_profile_remove_confirmation(&name),
+        ));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn danger_codex_profile_remove_confirmation(name: &str) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_CODEX_PROFILE_REMOVE_TYPE),
+        tf!(keys::DANGER_CODEX_PROFILE_REMOVE_SCOPE, "name" => name),
+        t!(keys::DANGER_CODEX_PROFILE_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,129 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_home, parse_optional_value,
+    print_changeset_preview, take_first_value,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::codex_settings::{
+    self, ApprovalPolicy, CodexSettings, ReasoningEffort, SandboxMode, Verbosity,
+};
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+
+pub fn cmd_codex_settings(mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("codex settings"));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "set" => cmd_codex_settings_set(args),
+        "show" => cmd_codex_settings_show(args),
+        _ => Err(err_unknown_subcommand_with_help("codex settings", &action)),
+    }
+}
+
+/// Parse an optional `--flag <value>` with a typed parser; parse errors are usage errors.
+fn parse_typed<T>(
+    args: &mut Vec<String>,
+    flag: &str,
+    parse: fn(&str) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    match parse_optional_value(args, flag)? {
+        Some(v) => parse(&v).map(Some).map_err(crate::errors::usage),
+        None => Ok(None),
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(tf!(keys::ERROR_INVALID_CHOICE, "choice" => value)),
+    }
+}
+
+/// Repeatable `--writable-root <PATH>`; `None` when the flag is absent.
+fn parse_writable_roots(args: &mut Vec<String>) -> Result<Option<Vec<String>>, String> {
+    let mut roots = Vec::new();
+    while let Some(root) = take_first_value(args, "--writable-root")? {
+        roots.push(root);
+    }
+    Ok((!roots.is_empty()).then_some(roots))
+}
+
+fn cmd_codex_settings_set(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let settings = CodexSettings {
+        approval_policy: parse_typed(&mut args, "--approval-policy", ApprovalPolicy::parse)?,
+        sandbox_mode: parse_typed(&mut args, "--sandbox-mode", SandboxMode::parse)?,
+        model_reasoning_effort: parse_typed(
+            &mut args,
+            "--reasoning-effort",
+            ReasoningEffort::parse,
+        )?,
+        model_verbosity: parse_typed(&mut args, "--verbosity", Verbosity::parse)?,
+        writable_roots: parse_writable_roots(&mut args)?,
+        network_access: parse_typed(&mut args, "--network-access", parse_bool)?,
+        exclude_tmpdir_env_var: parse_typed(&mut args, "--exclude-tmpdir-env-var", parse_bool)?,
+        exclude_slash_tmp: parse_typed(&mut args, "--exclude-slash-tmp", parse_bool)?,
+    };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex settings set", &args));
+    }
+    if settings.is_empty() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_CODEX_SETTINGS_SET_NEEDS_ARGS
+        )));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let codex_root = home.tool_root(Tool::Codex);
+    let config_path = codex_root.join("config.toml");
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next = codex_settings::apply_codex_settings(&existing, &settings);
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: codex_root });
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if settings.sandbox_mode == Some(SandboxMode::DangerFullAccess) {
+        println!("{}", t!(keys::CODEX_SETTINGS_FULL_ACCESS_WARNING));
+    }
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_settings_show(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex settings show", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let config_path = home.tool_root(Tool::Codex).join("config.toml");
+    let content = fs::read_to_string(&config_path).unwrap_or_default();
+
+    println!(
+        "{}",
+        tf!(keys::CODEX_SETTINGS_TITLE, "path" => config_path.display().to_string())
+    );
+    let settings = codex_settings::read_codex_settings(&content);
+    if settings.is_empty() {
+        println!("  {}", t!(keys::CODEX_SETTINGS_NONE));
+    }
+    for (k, v) in settings {
+        println!("  - {} = {}", k, v);
+    }
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use prismctl_core::changeset::{ApplyMode, Change, ChangeSet, RealCommandRunner, RealFileSystem};
 use prismctl_core::claude::{self, ClaudeEnvPatch};
 use prismctl_core::codex::{self, CodexProviderConfig};
-use prismctl_core::installer::{InstallAction, InstallMethod, ToolInstallTarget};
-use prismctl_core::managed_block::{extract_managed_block, upsert_managed_block};
+use prismctl_core::codex_auth;
+use prismctl_core::installer::{InstallAction, InstallMethod};
 use prismctl_core::mcp::{self, ClaudeMcpScope};
-use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_core::paths::{ConfigScope, PrismctlHome, Tool};
 use prismctl_core::providers;
 use prismctl_core::skill;
 use prismctl_core::templates::{self, TemplateLang};
+use prismctl_core::tools::{self, ToolAdapter};
 use prismctl_i18n::{keys, t, tf};
 use std::env;
 use std::fs;
@@ -41,6 +42,7 @@ pub fn help() -> String {
         prismctl_i18n::Locale::En => help_en(),
         prismctl_i18n::Locale::ZhCN => help_zh_cn(),
     }
+    .replace("{tools}", &tools::tool_choices())
 }
 
 fn help_zh_cn() -> String {
@@ -52,27 +54,72 @@ fn help_zh_cn() -> String {
         "  prismctl config <CMD> [ARGS...]        # 镜像入口：等价于 `prismctl <CMD> [ARGS...]`\n",
         "  prismctl doctor [--home <PATH>]",
         "  prismctl d                            # doctor 的短命令（等价于 prismctl doctor）",
-        "  prismctl init --tool <codex|claude|gemini|all> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl init --tool <{tools}> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
         "  prismctl i [-t|--tool <TOOL>] [-p|--provider <NAME>] [-k|--api-key <KEY>] [--lang <zh-CN|en>] [--home <PATH>] [--dry-run|--apply] [-s|--silent]",
-        "  prismctl update --tool <codex|claude|gemini|all> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl update --tool <{tools}> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
         "  prismctl u [-t|--tool <TOOL>] [--lang <zh-CN|en>] [--home <PATH>] [--dry-run|--apply] [-s|--silent]",
-        "  prismctl install --tool <codex|claude|gemini|all> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]",
-        "  prismctl upgrade --tool <codex|claude|gemini|all> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl install --tool <{tools}> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]",
+        "  prismctl upgrade --tool <{tools}> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]\n",
         "  prismctl skill list [--home <PATH>]",
         "  prismctl skill install --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
         "  prismctl skill create --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
         "  prismctl skill remove --name <VALUE> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl codex agent list",
-        "  prismctl codex agent use --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl codex provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]\n",
-        "  prismctl claude env set [--home <PATH>] [--dry-run|--apply] [--auth-token <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--haiku-model <VALUE>] [--sonnet-model <VALUE>] [--opus-model <VALUE>]\n",
-        "  prismctl claude output-style use --name <VALUE> [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex agent use --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]",
+        "  prismctl codex agent current [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl codex agent clear [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]",
+        "  prismctl codex provider add --id <ID> [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]",
+        "  prismctl codex provider list [--home <PATH>]",
+        "  prismctl codex provider use <ID> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex provider remove <ID> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl codex profile list [--home <PATH>]",
+        "  prismctl codex profile set <NAME> [--model <VALUE>] [--model-provider <ID>] [--approval-policy <untrusted|on-failure|on-request|never>] [--reasoning-effort <minimal|low|medium|high>] [--default] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex profile default <NAME> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex profile remove <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl codex settings show [--home <PATH>]",
+        "  prismctl codex settings set [--approval-policy <untrusted|on-failure|on-request|never>] [--sandbox-mode <read-only|workspace-write|danger-full-access>] [--reasoning-effort <minimal|low|medium|high>] [--verbosity <low|medium|high>] [--writable-root <PATH>]... [--network-access <true|false>] [--exclude-tmpdir-env-var <true|false>] [--exclude-slash-tmp <true|false>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex mcp list [--home <PATH>]",
+        "  prismctl codex mcp builtin",
+        "  prismctl codex mcp add --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex mcp remove --name <VALUE> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl codex auth use <api-key|chatgpt> [--api-key <KEY>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex prompt list [--home <PATH>]",
+        "  prismctl codex prompt create --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex prompt edit --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex prompt remove --name <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude env show [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude env set [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--auth-token <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--haiku-model <VALUE>] [--sonnet-model <VALUE>] [--opus-model <VALUE>] [--small-fast-model <VALUE>] [--api-timeout-ms <MS>] [--max-output-tokens <N>] [--disable-telemetry]",
+        "  prismctl claude env unset <KEY>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude env reset [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude output-style use --name <VALUE> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
         "  prismctl claude mcp list [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl claude mcp builtin\n",
         "  prismctl claude mcp add --name <VALUE> [--scope <local|project|user>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl claude mcp get --name <VALUE> [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl claude mcp remove --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl gemini env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] --api-key <VALUE>\n",
+        "  prismctl claude command list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude command create --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--allowed-tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude command edit --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--allowed-tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude command remove --name <NAME> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude agent list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude agent install --name <planner|ui-ux-designer|init-architect|get-current-datetime> [--model <sonnet|opus|haiku|inherit>] [--tools <LIST>] [--lang <zh-CN|en>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl claude agent create --name <NAME> --description <TEXT> [--model <sonnet|opus|haiku|inherit>] [--tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude agent remove --name <NAME> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude permissions list [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude permissions <allow|deny|ask> <RULE>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions remove <RULE>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions mode <default|acceptEdits|plan|bypassPermissions> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions preset <read-only|standard-dev|locked-down> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks list [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude hooks add --preset <format-on-edit|protect-env|notify-on-stop> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks add --event <EVENT> --command <CMD> [--matcher <PATTERN>] [--timeout <SECS>] [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks remove <--preset <NAME>|--event <EVENT> --command <CMD>> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline show [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude statusline set --command <CMD> [--padding <N>] [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline preset <minimal|git|full> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline clear [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl gemini env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--mode <api-key|vertex-ai|base-url>] [--api-key <VALUE>] [--project <ID>] [--location <REGION>] [--base-url <URL>]\n",
         "  prismctl gemini settings set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] --model <VALUE>\n",
         "  prismctl gemini mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl gemini mcp builtin\n",
@@ -80,7 +127,24 @@ fn help_zh_cn() -> String {
         "  prismctl gemini mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl gemini mcp enable --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl gemini mcp disable --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl project init [--path <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl opencode provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>]\n",
+        "  prismctl opencode mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
+        "  prismctl opencode mcp builtin\n",
+        "  prismctl opencode mcp add --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl opencode mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl qwen env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--provider <dashscope|siliconflow>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>]\n",
+        "  prismctl qwen mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
+        "  prismctl qwen mcp builtin\n",
+        "  prismctl qwen mcp add --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl qwen mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl profile list [--home <PATH>]",
+        "  prismctl profile use <NAME> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl profile save <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl profile diff <NAME> [--home <PATH>]\n",
+        "  prismctl provider test [--tool <codex|claude|gemini|all>] [--home <PATH>] [--project-path <PATH>] [--timeout <SECS>]",
+        "  prismctl provider models --tool <codex|claude|gemini> [--provider <ID>] [--base-url <URL>] [--api-key <KEY>] [--home <PATH>] [--project-path <PATH>] [--timeout <SECS>]\n",
+        "  prismctl project init [--path <PATH>] [--lang <zh-CN|en>] [--qwen] [--cursor [--persona <NAME>]] [--copilot] [--dry-run|--apply]\n",
+        "  prismctl project instructions sync [--path <PATH>] [--source <PATH>] [--mode block|import] [--check] [--dry-run|--apply]\n",
         "行为:",
         "  - 默认 dry-run：仅打印将执行的变更，不会写入任何文件。",
         "  - 只有传入 --apply 才会真正落盘。",
@@ -99,27 +163,72 @@ fn help_en() -> String {
         "  prismctl config <CMD> [ARGS...]        # mirror entry: same as `prismctl <CMD> [ARGS...]`\n",
         "  prismctl doctor [--home <PATH>]",
         "  prismctl d                            # short for doctor\n",
-        "  prismctl init --tool <codex|claude|gemini|all> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl init --tool <{tools}> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
         "  prismctl i [-t|--tool <TOOL>] [-p|--provider <NAME>] [-k|--api-key <KEY>] [--lang <zh-CN|en>] [--home <PATH>] [--dry-run|--apply] [-s|--silent]",
-        "  prismctl update --tool <codex|claude|gemini|all> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl update --tool <{tools}> [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
         "  prismctl u [-t|--tool <TOOL>] [--lang <zh-CN|en>] [--home <PATH>] [--dry-run|--apply] [-s|--silent]",
-        "  prismctl install --tool <codex|claude|gemini|all> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]",
-        "  prismctl upgrade --tool <codex|claude|gemini|all> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl install --tool <{tools}> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]",
+        "  prismctl upgrade --tool <{tools}> [--install-method <auto|npm|brew>] [--dry-run|--apply] [--yes]\n",
         "  prismctl skill list [--home <PATH>]",
         "  prismctl skill install --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
         "  prismctl skill create --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
         "  prismctl skill remove --name <VALUE> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl codex agent list",
-        "  prismctl codex agent use --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl codex provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]\n",
-        "  prismctl claude env set [--home <PATH>] [--dry-run|--apply] [--auth-token <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--haiku-model <VALUE>] [--sonnet-model <VALUE>] [--opus-model <VALUE>]\n",
-        "  prismctl claude output-style use --name <VALUE> [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex agent use --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]",
+        "  prismctl codex agent current [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl codex agent clear [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]",
+        "  prismctl codex provider add --id <ID> [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--wire-api <VALUE>] [--default]",
+        "  prismctl codex provider list [--home <PATH>]",
+        "  prismctl codex provider use <ID> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex provider remove <ID> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl codex profile list [--home <PATH>]",
+        "  prismctl codex profile set <NAME> [--model <VALUE>] [--model-provider <ID>] [--approval-policy <untrusted|on-failure|on-request|never>] [--reasoning-effort <minimal|low|medium|high>] [--default] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex profile default <NAME> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex profile remove <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl codex settings show [--home <PATH>]",
+        "  prismctl codex settings set [--approval-policy <untrusted|on-failure|on-request|never>] [--sandbox-mode <read-only|workspace-write|danger-full-access>] [--reasoning-effort <minimal|low|medium|high>] [--verbosity <low|medium|high>] [--writable-root <PATH>]... [--network-access <true|false>] [--exclude-tmpdir-env-var <true|false>] [--exclude-slash-tmp <true|false>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex mcp list [--home <PATH>]",
+        "  prismctl codex mcp builtin",
+        "  prismctl codex mcp add --name <VALUE> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex mcp remove --name <VALUE> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl codex auth use <api-key|chatgpt> [--api-key <KEY>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl codex prompt list [--home <PATH>]",
+        "  prismctl codex prompt create --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex prompt edit --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl codex prompt remove --name <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude env show [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude env set [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--auth-token <VALUE>] [--base-url <VALUE>] [--model <VALUE>] [--haiku-model <VALUE>] [--sonnet-model <VALUE>] [--opus-model <VALUE>] [--small-fast-model <VALUE>] [--api-timeout-ms <MS>] [--max-output-tokens <N>] [--disable-telemetry]",
+        "  prismctl claude env unset <KEY>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude env reset [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude output-style use --name <VALUE> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
         "  prismctl claude mcp list [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl claude mcp builtin\n",
         "  prismctl claude mcp add --name <VALUE> [--scope <local|project|user>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl claude mcp get --name <VALUE> [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl claude mcp remove --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl gemini env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] --api-key <VALUE>\n",
+        "  prismctl claude command list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude command create --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--allowed-tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude command edit --name <NAME> [--description <TEXT>] [--argument-hint <TEXT>] [--allowed-tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude command remove --name <NAME> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude agent list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude agent install --name <planner|ui-ux-designer|init-architect|get-current-datetime> [--model <sonnet|opus|haiku|inherit>] [--tools <LIST>] [--lang <zh-CN|en>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl claude agent create --name <NAME> --description <TEXT> [--model <sonnet|opus|haiku|inherit>] [--tools <LIST>] [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude agent remove --name <NAME> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl claude permissions list [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude permissions <allow|deny|ask> <RULE>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions remove <RULE>... [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions mode <default|acceptEdits|plan|bypassPermissions> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude permissions preset <read-only|standard-dev|locked-down> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks list [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude hooks add --preset <format-on-edit|protect-env|notify-on-stop> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks add --event <EVENT> --command <CMD> [--matcher <PATTERN>] [--timeout <SECS>] [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude hooks remove <--preset <NAME>|--event <EVENT> --command <CMD>> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline show [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>]",
+        "  prismctl claude statusline set --command <CMD> [--padding <N>] [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline preset <minimal|git|full> [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl claude statusline clear [--scope <user|project|local>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl gemini env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--mode <api-key|vertex-ai|base-url>] [--api-key <VALUE>] [--project <ID>] [--location <REGION>] [--base-url <URL>]\n",
         "  prismctl gemini settings set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] --model <VALUE>\n",
         "  prismctl gemini mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
         "  prismctl gemini mcp builtin\n",
@@ -127,7 +236,24 @@ fn help_en() -> String {
         "  prismctl gemini mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl gemini mcp enable --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
         "  prismctl gemini mcp disable --name <VALUE> [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
-        "  prismctl project init [--path <PATH>] [--lang <zh-CN|en>] [--dry-run|--apply]\n",
+        "  prismctl opencode provider set [--home <PATH>] [--dry-run|--apply] [--provider <VALUE>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>]\n",
+        "  prismctl opencode mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
+        "  prismctl opencode mcp builtin\n",
+        "  prismctl opencode mcp add --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply]\n",
+        "  prismctl opencode mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl qwen env set [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--provider <dashscope|siliconflow>] [--api-key <VALUE>] [--base-url <VALUE>] [--model <VALUE>]\n",
+        "  prismctl qwen mcp list [--scope <user|project>] [--project-path <PATH>] [--home <PATH>]\n",
+        "  prismctl qwen mcp builtin\n",
+        "  prismctl qwen mcp add --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl qwen mcp remove --name <VALUE> [--scope <user|project>] [--project-path <PATH>] [--home <PATH>] [--dry-run|--apply] [--yes]\n",
+        "  prismctl profile list [--home <PATH>]",
+        "  prismctl profile use <NAME> [--home <PATH>] [--dry-run|--apply]",
+        "  prismctl profile save <NAME> [--home <PATH>] [--dry-run|--apply] [--yes]",
+        "  prismctl profile diff <NAME> [--home <PATH>]\n",
+        "  prismctl provider test [--tool <codex|claude|gemini|all>] [--home <PATH>] [--project-path <PATH>] [--timeout <SECS>]",
+        "  prismctl provider models --tool <codex|claude|gemini> [--provider <ID>] [--base-url <URL>] [--api-key <KEY>] [--home <PATH>] [--project-path <PATH>] [--timeout <SECS>]\n",
+        "  prismctl project init [--path <PATH>] [--lang <zh-CN|en>] [--qwen] [--cursor [--persona <NAME>]] [--copilot] [--dry-run|--apply]\n",
+        "  prismctl project instructions sync [--path <PATH>] [--source <PATH>] [--mode block|import] [--check] [--dry-run|--apply]\n",
         "Behavior:",
         "  - Default is dry-run: prints planned changes without writing files.",
         "  - Only `--apply` writes changes to disk.",
@@ -144,11 +270,6 @@ pub fn cmd_doctor(mut args: Vec<String>) -> Result<(), String> {
     }
 
     let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-    let codex = home.tool_root(Tool::Codex);
-    let claude = home.tool_root(Tool::ClaudeCode);
-    let gemini = home.tool_root(Tool::GeminiCli);
-    let claude_settings = home.claude_settings_path();
-
     let exists_word = |b: bool| -> &'static str {
         match prismctl_i18n::current_locale() {
             prismctl_i18n::Locale::ZhCN => {
@@ -168,71 +289,54 @@ pub fn cmd_doctor(mut args: Vec<String>) -> Result<(), String> {
         }
     };
 
+    println!("Prismctl HOME: {}", home.home_dir().display());
+    for t in tools::registry() {
+        println!("{} root: {}", t.display_name(), t.root(&home).display());
+    }
+    println!();
+
     match prismctl_i18n::current_locale() {
-        prismctl_i18n::Locale::ZhCN => {
-            println!("Prismctl HOME: {}", home.home_dir().display());
-            println!("Codex root: {}", codex.display());
-            println!("Claude root: {}", claude.display());
-            println!("Claude settings: {}", claude_settings.display());
-            println!("Gemini root: {}", gemini.display());
-            println!();
-
-            println!("用户级文件（存在性）：");
-        }
-        prismctl_i18n::Locale::En => {
-            println!("Prismctl HOME: {}", home.home_dir().display());
-            println!("Codex root: {}", codex.display());
-            println!("Claude root: {}", claude.display());
-            println!("Claude settings: {}", claude_settings.display());
-            println!("Gemini root: {}", gemini.display());
-            println!();
-
-            println!("User-scoped files (existence):");
+        prismctl_i18n::Locale::ZhCN => println!("用户级文件（存在性）："),
+        prismctl_i18n::Locale::En => println!("User-scoped files (existence):"),
+    }
+    for t in tools::registry() {
+        for path in t.user_files(&home) {
+            let shown = match path.strip_prefix(home.home_dir()) {
+                Ok(rel) => format!("~/{}", rel.display()),
+                Err(_) => path.display().to_string(),
+            };
+            println!("  - {}: {}", shown, exists_word(path.exists()));
         }
     }
-
-    let claude_json = home.home_dir().join(".claude.json");
-    let codex_config = codex.join("config.toml");
-    let codex_auth = codex.join("auth.json");
-    let codex_agents = codex.join("AGENTS.md");
-    let gemini_env = gemini.join(".env");
-    let gemini_settings = gemini.join("settings.json");
-
-    println!(
-        "  - ~/.claude/settings.json: {}",
-        exists_word(claude_settings.exists())
-    );
-    println!("  - ~/.claude.json: {}", exists_word(claude_json.exists()));
-    println!(
-        "  - ~/.codex/config.toml: {}",
-        exists_word(codex_config.exists())
-    );
-    println!(
-        "  - ~/.codex/auth.json: {}",
-        exists_word(codex_auth.exists())
-    );
-    println!(
-        "  - ~/.codex/AGENTS.md: {}",
-        exists_word(codex_agents.exists())
-    );
-    println!("  - ~/.gemini/.env: {}", exists_word(gemini_env.exists()));
-    println!(
-        "  - ~/.gemini/settings.json: {}",
-        exists_word(gemini_settings.exists())
-    );
     println!();
 
+    // Codex auth mode (redacted: key tail only, never tokens).
+    let auth_path = home.tool_root(Tool::Codex).join("auth.json");
+    if let Ok(auth) = fs::read_to_string(&auth_path) {
+        let summary = match codex_auth::detect_codex_auth(&auth) {
+            Ok(status) => {
+                let mut parts = vec![status.mode.as_str().to_string()];
+                if let Some(key) = status.api_key {
+                    parts.push(format!("OPENAI_API_KEY={key}"));
+                }
+                if !status.provider_keys.is_empty() {
+                    parts.push(status.provider_keys.join(", "));
+                }
+                parts.join(" | ")
+            }
+            Err(e) => e,
+        };
+        match prismctl_i18n::current_locale() {
+            prismctl_i18n::Locale::ZhCN => println!("Codex 认证方式: {summary}"),
+            prismctl_i18n::Locale::En => println!("Codex auth mode: {summary}"),
+        }
+        println!();
+    }
+
     // Best-effort project context (based on current working directory).
     // Keep it simple: existence checks only (no parsing/merging guesses).
     let cwd = env::current_dir().ok();
     if let Some(project_root) = cwd {
-        let mcp_json = project_root.join(".mcp.json");
-        let gemini_dir = project_root.join(".gemini");
-        let gemini_env = gemini_dir.join(".env");
-        let gemini_settings = gemini_dir.join("settings.json");
-        let gemini_md = gemini_dir.join("GEMINI.md");
-        let codex_agents = project_root.join("AGENTS.md");
-
         match prismctl_i18n::current_locale() {
             prismctl_i18n::Locale::ZhCN => {
                 println!("项目根目录（当前工作目录）: {}", project_root.display());
@@ -244,14 +348,12 @@ pub fn cmd_doctor(mut args: Vec<String>) -> Result<(), String> {
             }
         }
 
-        println!("  - .mcp.json: {}", exists_word(mcp_json.exists()));
-        println!("  - AGENTS.md: {}", exists_word(codex_agents.exists()));
-        println!("  - .gemini/.env: {}", exists_word(gemini_env.exists()));
-        println!(
-            "  - .gemini/settings.json: {}",
-            exists_word(gemini_settings.exists())
-        );
-        println!("  - .gemini/GEMINI.md: {}", exists_word(gemini_md.exists()));
+        for t in tools::registry() {
+            for rel in t.project_files() {
+                let path = project_root.join(rel);
+                println!("  - {}: {}", rel, exists_word(path.exists()));
+            }
+        }
     }
 
     Ok(())
@@ -262,33 +364,15 @@ pub fn cmd_init(mut args: Vec<String>) -> Result<(), String> {
     let mode = parse_apply_mode(&mut args)?;
     let lang = parse_lang(&mut args)?;
 
-    let tool = parse_tool(&mut args)?;
+    let selected = parse_tool(&mut args)?;
     if !args.is_empty() {
         return Err(err_unsupported_args_with_help("init", &args));
     }
 
     let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-    let gemini_existing =
-        fs::read_to_string(home.tool_root(Tool::GeminiCli).join("GEMINI.md")).unwrap_or_default();
     let mut cs = ChangeSet::new();
-
-    match tool {
-        ToolSelection::Codex => cs.extend(templates::plan_templates_codex(&home, lang)),
-        ToolSelection::Claude => cs.extend(templates::plan_templates_claude(&home, lang)),
-        ToolSelection::Gemini => cs.extend(templates::plan_templates_gemini_with_existing(
-            &home,
-            &gemini_existing,
-            lang,
-        )),
-        ToolSelection::All => {
-            cs.extend(templates::plan_templates_codex(&home, lang));
-            cs.extend(templates::plan_templates_claude(&home, lang));
-            cs.extend(templates::plan_templates_gemini_with_existing(
-                &home,
-                &gemini_existing,
-                lang,
-            ));
-        }
+    for t in &selected {
+        cs.extend(t.plan_templates(&home, lang));
     }
 
     if cs.is_empty() {
@@ -318,33 +402,16 @@ pub fn cmd_update(mut args: Vec<String>) -> Result<(), String> {
     let home = parse_home(&mut args)?;
     let mode = parse_apply_mode(&mut args)?;
     let lang = parse_lang(&mut args)?;
-    let tool = parse_tool(&mut args)?;
+    let selected = parse_tool(&mut args)?;
 
     if !args.is_empty() {
         return Err(err_unsupported_args_with_help("update", &args));
     }
 
     let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-    let gemini_existing =
-        fs::read_to_string(home.tool_root(Tool::GeminiCli).join("GEMINI.md")).unwrap_or_default();
     let mut cs = ChangeSet::new();
-    match tool {
-        ToolSelection::Codex => cs.extend(templates::plan_templates_codex(&home, lang)),
-        ToolSelection::Claude => cs.extend(templates::plan_templates_claude(&home, lang)),
-        ToolSelection::Gemini => cs.extend(templates::plan_templates_gemini_with_existing(
-            &home,
-            &gemini_existing,
-            lang,
-        )),
-        ToolSelection::All => {
-            cs.extend(templates::plan_templates_codex(&home, lang));
-            cs.extend(templates::plan_templates_claude(&home, lang));
-            cs.extend(templates::plan_templates_gemini_with_existing(
-                &home,
-                &gemini_existing,
-                lang,
-            ));
-        }
+    for t in &selected {
+        cs.extend(t.plan_templates(&home, lang));
     }
 
     if cs.is_empty() {
@@ -378,6 +445,7 @@ pub fn cmd_project(mut args: Vec<String>) -> Result<(), String> {
 
     match sub.as_str() {
         "init" => cmd_project_init(args),
+        "instructions" => cmd_project_instructions(args),
         _ => Err(err_unknown_subcommand_with_help("project", &sub)),
     }
 }
@@ -413,6 +481,11 @@ pub fn cmd_codex(mut args: Vec<String>) -> Result<(), String> {
 
     match sub.as_str() {
         "provider" => cmd_codex_provider(args),
+        "profile" => super::cmd_codex_profile(args),
+        "settings" => super::cmd_codex_settings(args),
+        "mcp" => super::cmd_tool_mcp(Tool::Codex, args),
+        "auth" => super::cmd_codex_auth(args),
+        "prompt" => super::cmd_codex_prompt(args),
         "agent" => cmd_codex_agent(args),
         _ => Err(err_unknown_subcommand_with_help("codex", &sub)),
     }
@@ -427,12 +500,17 @@ pub fn cmd_claude(mut args: Vec<String>) -> Result<(), String> {
     match sub.as_str() {
         "env" => cmd_claude_env(args),
         "output-style" => cmd_claude_output_style(args),
-        "mcp" => cmd_claude_mcp(args),
+        "mcp" => super::cmd_tool_mcp(Tool::ClaudeCode, args),
+        "command" => super::cmd_claude_command(args),
+        "agent" => super::cmd_claude_agent(args),
+        "permissions" => super::cmd_claude_permissions(args),
+        "hooks" => super::cmd_claude_hooks(args),
+        "statusline" => super::cmd_claude_statusline(args),
         _ => Err(err_unknown_subcommand_with_help("claude", &sub)),
     }
 }
 
-fn cmd_claude_mcp(mut args: Vec<String>) -> Result<(), String> {
+pub(super) fn cmd_claude_mcp(mut args: Vec<String>) -> Result<(), String> {
     let Some(action) = args.first().cloned() else {
         return Err(err_missing_subcommand_with_help("claude mcp"));
     };
@@ -448,7 +526,7 @@ fn cmd_claude_mcp(mut args: Vec<String>) -> Result<(), String> {
     }
 }
 
-fn parse_project_path_opt(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
+pub(super) fn parse_project_path_opt(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
     let mut i = 0;
     while i < args.len() {
         if args[i] == "--project-path" {
@@ -694,14 +772,14 @@ pub fn cmd_gemini(mut args: Vec<String>) -> Result<(), String> {
     match sub.as_str() {
         "env" => cmd_gemini_env(args),
         "settings" => cmd_gemini_settings(args),
-        "mcp" => cmd_gemini_mcp(args),
+        "mcp" => super::cmd_tool_mcp(Tool::GeminiCli, args),
         _ => Err(err_unknown_subcommand_with_help("gemini", &sub)),
     }
 }
 
 // ---- parsing helpers ----
 
-fn err_unsupported_args_with_help(cmd: &str, args: &Vec<String>) -> String {
+pub(super) fn err_unsupported_args_with_help(cmd: &str, args: &Vec<String>) -> String {
     crate::errors::usage(format!(
         "{}\n\n{}",
         tf!(
@@ -713,7 +791,7 @@ fn err_unsupported_args_with_help(cmd: &str, args: &Vec<String>) -> String {
     ))
 }
 
-fn err_missing_subcommand_with_help(cmd: &str) -> String {
+pub(super) fn err_missing_subcommand_with_help(cmd: &str) -> String {
     crate::errors::usage(format!(
         "{}\n\n{}",
         tf!(keys::ERROR_MISSING_SUBCOMMAND_WITH_HELP, "cmd" => cmd),
@@ -721,7 +799,7 @@ fn err_missing_subcommand_with_help(cmd: &str) -> String {
     ))
 }
 
-fn err_unknown_subcommand_with_help(cmd: &str, sub: &str) -> String {
+pub(super) fn err_unknown_subcommand_with_help(cmd: &str, sub: &str) -> String {
     crate::errors::usage(format!(
         "{}\n\n{}",
         tf!(
@@ -733,7 +811,7 @@ fn err_unknown_subcommand_with_help(cmd: &str, sub: &str) -> String {
     ))
 }
 
-fn parse_home(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
+pub(super) fn parse_home(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
     let mut home: Option<PathBuf> = None;
     let mut i = 0;
     while i < args.len() {
@@ -753,7 +831,7 @@ fn parse_home(args: &mut Vec<String>) -> Result<Option<PathBuf>, String> {
     Ok(home)
 }
 
-fn parse_apply_mode(args: &mut Vec<String>) -> Result<ApplyMode, String> {
+pub(super) fn parse_apply_mode(args: &mut Vec<String>) -> Result<ApplyMode, String> {
     let mut mode = ApplyMode::DryRun;
     let mut i = 0;
     while i < args.len() {
@@ -772,7 +850,7 @@ fn parse_apply_mode(args: &mut Vec<String>) -> Result<ApplyMode, String> {
     Ok(mode)
 }
 
-fn parse_lang(args: &mut Vec<String>) -> Result<TemplateLang, String> {
+pub(super) fn parse_lang(args: &mut Vec<String>) -> Result<TemplateLang, String> {
     let mut lang = TemplateLang::ZhCn;
     let mut i = 0;
     while i < args.len() {
@@ -801,16 +879,9 @@ fn parse_lang(args: &mut Vec<String>) -> Result<TemplateLang, String> {
     Ok(lang)
 }
 
-#[derive(Debug, Clone, Copy)]
-enum ToolSelection {
-    Codex,
-    Claude,
-    Gemini,
-    All,
-}
-
-fn parse_tool(args: &mut Vec<String>) -> Result<ToolSelection, String> {
-    let mut tool: Option<ToolSelection> = None;
+/// Parse `--tool <id|alias|all>` into the selected adapters (registry order for `all`).
+fn parse_tool(args: &mut Vec<String>) -> Result<Vec<&'static dyn ToolAdapter>, String> {
+    let mut tool: Option<Vec<&'static dyn ToolAdapter>> = None;
     let mut i = 0;
     while i < args.len() {
         if args[i] == "--tool" {
@@ -820,16 +891,17 @@ fn parse_tool(args: &mut Vec<String>) -> Result<ToolSelection, String> {
                     crate::errors::usage(tf!(keys::ERROR_FLAG_MISSING_VALUE, "flag" => "--tool"))
                 })?
                 .as_str();
-            tool = Some(match value {
-                "codex" => ToolSelection::Codex,
-                "claude" | "claude-code" => ToolSelection::Claude,
-                "gemini" | "gemini-cli" => ToolSelection::Gemini,
-                "all" => ToolSelection::All,
-                _ => {
-                    return Err(crate::errors::usage(tf!(
-                        keys::ERROR_TOOL_VALUE_UNSUPPORTED,
-                        "value" => value
-                    )));
+            tool = Some(if value == "all" {
+                tools::registry().to_vec()
+            } else {
+                match tools::find_tool(value) {
+                    Some(t) => vec![t],
+                    None => {
+                        return Err(crate::errors::usage(tf!(
+                            keys::ERROR_TOOL_VALUE_UNSUPPORTED,
+                            "value" => value
+                        )));
+                    }
                 }
             });
             args.drain(i..=i + 1);
@@ -838,10 +910,12 @@ fn parse_tool(args: &mut Vec<String>) -> Result<ToolSelection, String> {
         i += 1;
     }
 
-    tool.ok_or_else(|| crate::errors::usage(t!(keys::ERROR_TOOL_FLAG_INVALID)))
+    tool.ok_or_else(|| {
+        crate::errors::usage(tf!(keys::ERROR_TOOL_FLAG_INVALID, "tools" => tools::tool_choices()))
+    })
 }
 
-fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+pub(super) fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
     let mut i = 0;
     while i < args.len() {
         if args[i] == flag {
@@ -911,7 +985,7 @@ fn parse_project_path(args: &mut Vec<String>) -> Result<PathBuf, String> {
     Ok(normalize_path(&p))
 }
 
-fn normalize_path(path: &Path) -> PathBuf {
+pub(super) fn normalize_path(path: &Path) -> PathBuf {
     // Best-effort normalization; do not resolve symlinks.
     if path.is_absolute() {
         path.to_path_buf()
@@ -922,24 +996,130 @@ fn normalize_path(path: &Path) -> PathBuf {
     }
 }
 
-fn parse_required_value(args: &mut Vec<String>, flag: &str) -> Result<String, String> {
+pub(super) fn parse_required_value(args: &mut Vec<String>, flag: &str) -> Result<String, String> {
+    parse_optional_value(args, flag)?
+        .ok_or_else(|| crate::errors::usage(tf!(keys::ERROR_MISSING_FLAG, "flag" => flag)))
+}
+
+/// `flag <value>`; when the flag is repeated the last value wins.
+pub(super) fn parse_optional_value(
+    args: &mut Vec<String>,
+    flag: &str,
+) -> Result<Option<String>, String> {
     let mut value: Option<String> = None;
-    let mut i = 0;
-    while i < args.len() {
-        if args[i] == flag {
-            value = Some(
-                args.get(i + 1)
-                    .ok_or_else(|| {
-                        crate::errors::usage(tf!(keys::ERROR_FLAG_MISSING_VALUE, "flag" => flag))
-                    })?
-                    .to_string(),
-            );
-            args.drain(i..=i + 1);
-            continue;
-        }
-        i += 1;
+    while let Some(v) = take_first_value(args, flag)? {
+        value = Some(v);
+    }
+    Ok(value)
+}
+
+/// Remove the first `flag <value>` pair from `args`; call repeatedly for repeatable flags.
+pub(super) fn take_first_value(
+    args: &mut Vec<String>,
+    flag: &str,
+) -> Result<Option<String>, String> {
+    let Some(i) = args.iter().position(|a| a == flag) else {
+        return Ok(None);
+    };
+    let value = args
+        .get(i + 1)
+        .cloned()
+        .ok_or_else(|| crate::errors::usage(tf!(keys::ERROR_FLAG_MISSING_VALUE, "flag" => flag)))?;
+    args.drain(i..=i + 1);
+    Ok(Some(value))
+}
+
+/// `<flag> <VALUE>` or a leading positional value (`prismctl profile use work`).
+pub(super) fn parse_name_or_positional(
+    args: &mut Vec<String>,
+    flag: &str,
+) -> Result<String, String> {
+    if let Some(value) = parse_optional_value(args, flag)? {
+        return Ok(value);
+    }
+    match args.first() {
+        Some(first) if !first.starts_with('-') => Ok(args.remove(0)),
+        _ => parse_required_value(args, flag),
+    }
+}
+
+/// Parse `--scope <user|project>` (default: user) for tools whose config lives in one file per scope.
+pub(super) fn parse_config_scope(args: &mut Vec<String>) -> Result<ConfigScope, String> {
+    parse_scope(args, &[ConfigScope::User, ConfigScope::Project])
+}
+
+/// Parse `--scope` (default: user), accepting only the `allowed` scopes.
+fn parse_scope(args: &mut Vec<String>, allowed: &[ConfigScope]) -> Result<ConfigScope, String> {
+    let Some(value) = parse_optional_value(args, "--scope")? else {
+        return Ok(ConfigScope::User);
+    };
+    allowed
+        .iter()
+        .copied()
+        .find(|s| s.as_str() == value)
+        .ok_or_else(|| crate::errors::usage(tf!(keys::ERROR_INVALID_CHOICE, "choice" => value)))
+}
+
+/// Parse `--scope <user|project|local>` (default: user) and `--project-path`, returning the
+/// Claude Code settings file they select.
+pub(super) fn parse_claude_settings_path(
+    args: &mut Vec<String>,
+    home: &PrismctlHome,
+) -> Result<PathBuf, String> {
+    parse_claude_settings_target(args, home).map(|(_, path)| path)
+}
+
+/// Like [`parse_claude_settings_path`], also returning the parsed scope.
+pub(super) fn parse_claude_settings_target(
+    args: &mut Vec<String>,
+    home: &PrismctlHome,
+) -> Result<(ConfigScope, PathBuf), String> {
+    let scope = parse_scope(
+        args,
+        &[ConfigScope::User, ConfigScope::Project, ConfigScope::Local],
+    )?;
+    let project_path = parse_project_path_opt(args)?;
+    let project_root = match scope {
+        ConfigScope::User => PathBuf::new(),
+        _ => resolve_project_root(project_path)?,
+    };
+    Ok((
+        scope,
+        claude::claude_settings_path(scope, home, &project_root),
+    ))
+}
+
+/// Resolve `--project-path` (normalized) or fall back to the current directory.
+pub(super) fn resolve_project_root(project_path: Option<PathBuf>) -> Result<PathBuf, String> {
+    match project_path {
+        Some(p) => Ok(normalize_path(&p)),
+        None => env::current_dir().map_err(|e| tf!(keys::ERROR_CURRENT_DIR, "error" => e)),
+    }
+}
+
+/// Provider presets: built-ins plus `~/.prismctl/providers.toml` and the current project's
+/// `.prismctl/providers.toml`.
+pub fn load_provider_catalog(home: &PrismctlHome) -> Result<providers::ProviderCatalog, String> {
+    let project_root = env::current_dir().ok();
+    providers::ProviderCatalog::load(home, project_root.as_deref()).map_err(crate::errors::usage)
+}
+
+/// Print the standard "planned changes" preview for a ChangeSet.
+pub(super) fn print_changeset_preview(cs: &ChangeSet, mode: ApplyMode) {
+    let title = tf!(keys::CHANGESET_PREVIEW_TITLE, "mode" => format!("{:?}", mode));
+    println!("{}", title);
+    for c in cs.iter() {
+        println!("  - {}", crate::icons::render_change(c));
     }
-    value.ok_or_else(|| crate::errors::usage(tf!(keys::ERROR_MISSING_FLAG, "flag" => flag)))
+}
+
+/// Apply a ChangeSet with the real filesystem/runner and print the standard footer.
+pub(super) fn apply_changeset(cs: &ChangeSet, mode: ApplyMode) -> Result<(), String> {
+    let fs = RealFileSystem;
+    let runner = RealCommandRunner;
+    cs.apply(mode, &fs, &runner)?;
+    println!("\n{}", t!(keys::APPLY_APPLIED));
+    Ok(())
 }
 
 fn quote_path_display(path: &Path) -> String {
@@ -1099,6 +1279,10 @@ pub(crate) fn danger_skill_remove_confirmation(name: &str) -> String {
 fn cmd_project_init(mut args: Vec<String>) -> Result<(), String> {
     let mode = parse_apply_mode(&mut args)?;
     let lang = parse_lang(&mut args)?;
+    let with_qwen = take_flag(&mut args, "--qwen");
+    let with_cursor = take_flag(&mut args, "--cursor");
+    let persona = parse_optional_value(&mut args, "--persona")?;
+    let with_copilot = take_flag(&mut args, "--copilot");
     let project_root = parse_project_path(&mut args)?;
     if !args.is_empty() {
         return Err(err_unsupported_args_with_help("project init", &args));
@@ -1107,7 +1291,35 @@ fn cmd_project_init(mut args: Vec<String>) -> Result<(), String> {
     let gemini_md_path = project_root.join(".gemini").join("GEMINI.md");
     let existing = fs::read_to_string(&gemini_md_path).unwrap_or_default();
 
-    let cs = prismctl_core::project::plan_project_init(&project_root, lang, &existing);
+    let mut cs = prismctl_core::project::plan_project_init(&project_root, lang, &existing);
+    if with_qwen {
+        let existing_qwen = fs::read_to_string(project_root.join("QWEN.md")).unwrap_or_default();
+        cs.extend(prismctl_core::project::plan_project_qwen_md(
+            &project_root,
+            lang,
+            &existing_qwen,
+        ));
+    }
+    if with_cursor {
+        cs.extend(prismctl_core::cursor::plan_cursor_rules(
+            &project_root,
+            lang,
+            persona.as_deref(),
+            &|p| fs::read_to_string(p).ok(),
+        )?);
+    } else if persona.is_some() {
+        return Err(err_unsupported_args_with_help(
+            "project init",
+            &vec!["--persona".to_string()],
+        ));
+    }
+    if with_copilot {
+        cs.extend(prismctl_core::copilot::plan_copilot_files(
+            &project_root,
+            lang,
+            &|p| fs::read_to_string(p).ok(),
+        ));
+    }
 
     let title = tf!(keys::CHANGESET_PREVIEW_TITLE, "mode" => format!("{:?}", mode));
     println!("{}", title);
@@ -1127,6 +1339,95 @@ fn cmd_project_init(mut args: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
+fn cmd_project_instructions(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("project instructions"));
+    };
+    args.remove(0);
+
+    match sub.as_str() {
+        "sync" => cmd_project_instructions_sync(args),
+        _ => Err(err_unknown_subcommand_with_help(
+            "project instructions",
+            &sub,
+        )),
+    }
+}
+
+fn cmd_project_instructions_sync(mut args: Vec<String>) -> Result<(), String> {
+    use prismctl_core::project::{
+        default_instructions_source, plan_instructions_sync, InstructionsSyncMode,
+    };
+
+    let mode = parse_apply_mode(&mut args)?;
+    let check = take_flag(&mut args, "--check");
+    let source = parse_optional_value(&mut args, "--source")?;
+    let sync_mode = match parse_optional_value(&mut args, "--mode")?.as_deref() {
+        None | Some("block") => InstructionsSyncMode::Block,
+        Some("import") => InstructionsSyncMode::Import,
+        Some(v) => {
+            return Err(crate::errors::usage(tf!(
+                keys::ERROR_INVALID_CHOICE,
+                "choice" => v
+            )))
+        }
+    };
+    let project_root = parse_project_path(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "project instructions sync",
+            &args,
+        ));
+    }
+
+    let source = match source {
+        Some(p) => project_root.join(p),
+        None => default_instructions_source(&project_root),
+    };
+    let source_content = fs::read_to_string(&source).map_err(|_| {
+        crate::errors::usage(tf!(
+            keys::ERROR_INSTRUCTIONS_SOURCE_NOT_FOUND,
+            "path" => source.display()
+        ))
+    })?;
+    let plan = plan_instructions_sync(&project_root, &source, &source_content, sync_mode, &|p| {
+        fs::read_to_string(p).ok()
+    })?;
+
+    println!(
+        "{}",
+        tf!(keys::INSTRUCTIONS_SOURCE, "path" => source.display())
+    );
+    if check {
+        if plan.drifted.is_empty() {
+            println!("{}", t!(keys::INSTRUCTIONS_IN_SYNC));
+            return Ok(());
+        }
+        for p in &plan.drifted {
+            println!(
+                "  - {}",
+                tf!(keys::INSTRUCTIONS_DRIFTED, "path" => p.display())
+            );
+        }
+        return Err(crate::errors::runtime(tf!(
+            keys::ERROR_INSTRUCTIONS_DRIFT,
+            "count" => plan.drifted.len(),
+            "source" => source.display()
+        )));
+    }
+    if plan.changeset.is_empty() {
+        println!("{}", t!(keys::INSTRUCTIONS_IN_SYNC));
+        return Ok(());
+    }
+
+    print_changeset_preview(&plan.changeset, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE_PROJECT));
+        return Ok(());
+    }
+    apply_changeset(&plan.changeset, mode)
+}
+
 // ---- install/upgrade ----
 
 fn cmd_install_or_upgrade(action: InstallAction, args: &mut Vec<String>) -> Result<(), String> {
@@ -1134,7 +1435,7 @@ fn cmd_install_or_upgrade(action: InstallAction, args: &mut Vec<String>) -> Resu
     let method = parse_install_method(args)?;
     let yes = take_flag(args, "--yes");
 
-    let tool = parse_tool(args)?;
+    let selected = parse_tool(args)?;
     if !args.is_empty() {
         let op = match action {
             InstallAction::Install => "install",
@@ -1143,19 +1444,8 @@ fn cmd_install_or_upgrade(action: InstallAction, args: &mut Vec<String>) -> Resu
         return Err(err_unsupported_args_with_help(op, args));
     }
 
-    let targets = match tool {
-        ToolSelection::Codex => vec![ToolInstallTarget::Codex],
-        ToolSelection::Claude => vec![ToolInstallTarget::ClaudeCode],
-        ToolSelection::Gemini => vec![ToolInstallTarget::GeminiCli],
-        ToolSelection::All => vec![
-            ToolInstallTarget::Codex,
-            ToolInstallTarget::ClaudeCode,
-            ToolInstallTarget::GeminiCli,
-        ],
-    };
-
     let mut cs = ChangeSet::new();
-    for t in targets {
+    for t in selected {
         cs.extend(prismctl_core::installer::plan_install(t, method, action));
     }
 
@@ -1206,7 +1496,11 @@ fn cmd_codex_provider(mut args: Vec<String>) -> Result<(), String> {
     args.remove(0);
 
     match action.as_str() {
-        "set" => cmd_codex_provider_set(args),
+        "set" => cmd_codex_provider_write(args, None),
+        "add" => cmd_codex_provider_add(args),
+        "list" => cmd_codex_provider_list(args),
+        "use" => cmd_codex_provider_use(args),
+        "remove" => cmd_codex_provider_remove(args),
         _ => Err(err_unknown_subcommand_with_help("codex provider", &action)),
     }
 }
@@ -1220,6 +1514,8 @@ fn cmd_codex_agent(mut args: Vec<String>) -> Result<(), String> {
     match action.as_str() {
         "list" => cmd_codex_agent_list(args),
         "use" => cmd_codex_agent_use(args),
+        "current" => cmd_codex_agent_current(args),
+        "clear" => cmd_codex_agent_clear(args),
         _ => Err(err_unknown_subcommand_with_help("codex agent", &action)),
     }
 }
@@ -1236,44 +1532,27 @@ fn cmd_codex_agent_list(args: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum CodexAgentScope {
-    User,
-    Project,
-}
-
-fn parse_codex_agent_scope(args: &mut Vec<String>) -> Result<CodexAgentScope, String> {
-    let mut i = 0;
-    while i < args.len() {
-        if args[i] == "--scope" {
-            let v = args.get(i + 1).ok_or_else(|| {
-                crate::errors::usage(tf!(keys::ERROR_FLAG_MISSING_VALUE, "flag" => "--scope"))
-            })?;
-            let scope = match v.as_str() {
-                "user" => CodexAgentScope::User,
-                "project" => CodexAgentScope::Project,
-                _ => {
-                    return Err(crate::errors::usage(tf!(
-                        keys::ERROR_INVALID_CHOICE,
-                        "choice" => v
-                    )))
-                }
-            };
-            args.drain(i..=i + 1);
-            return Ok(scope);
-        }
-        i += 1;
-    }
-    Ok(CodexAgentScope::User)
+/// Parse `--scope <user|project>` (default: user) and `--project-path`, returning the scope and
+/// the `AGENTS.md` it selects.
+fn parse_codex_agents_target(
+    args: &mut Vec<String>,
+    home: &PrismctlHome,
+) -> Result<(ConfigScope, PathBuf), String> {
+    let scope = parse_config_scope(args)?;
+    let project_path = parse_project_path_opt(args)?;
+    let path = match scope {
+        ConfigScope::User => home.tool_root(Tool::Codex).join("AGENTS.md"),
+        _ => resolve_project_root(project_path)?.join("AGENTS.md"),
+    };
+    Ok((scope, path))
 }
 
 fn cmd_codex_agent_use(mut args: Vec<String>) -> Result<(), String> {
     let home = parse_home(&mut args)?;
     let mode = parse_apply_mode(&mut args)?;
     let lang = parse_lang(&mut args)?;
-    let yes = take_flag(&mut args, "--yes");
-    let scope = parse_codex_agent_scope(&mut args)?;
-    let project_path = parse_project_path_opt(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (scope, agents_path) = parse_codex_agents_target(&mut args, &home)?;
 
     let name = parse_required_value(&mut args, "--name")?;
     if !args.is_empty() {
@@ -1283,31 +1562,8 @@ fn cmd_codex_agent_use(mut args: Vec<String>) -> Result<(), String> {
     let tpl = templates::codex_agent_template(&name, lang)
         .ok_or_else(|| crate::errors::usage(tf!(keys::ERROR_UNKNOWN_AGENT, "name" => &name)))?;
 
-    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-
-    let (agents_path, backup_base_dir) = match scope {
-        CodexAgentScope::User => {
-            let codex_root = home.tool_root(Tool::Codex);
-            (
-                codex_root.join("AGENTS.md"),
-                codex_root.join("backup").join("prismctl"),
-            )
-        }
-        CodexAgentScope::Project => {
-            let root = match project_path {
-                Some(p) => normalize_path(&p),
-                None => {
-                    env::current_dir().map_err(|e| tf!(keys::ERROR_CURRENT_DIR, "error" => e))?
-                }
-            };
-            (
-                root.join("AGENTS.md"),
-                root.join(".prismctl").join("backup").join("prismctl"),
-            )
-        }
-    };
-
     let existing = fs::read_to_string(&agents_path).unwrap_or_default();
+    let next = codex::render_codex_persona(&existing, &name, tpl);
 
     let mut cs = ChangeSet::new();
     if let Some(parent) = agents_path.parent() {
@@ -1316,12 +1572,24 @@ fn cmd_codex_agent_use(mut args: Vec<String>) -> Result<(), String> {
         });
     }
 
-    if !existing.trim().is_empty() {
+    // First switch on a file without the persona block: keep a copy of what was there.
+    if codex::codex_persona_needs_backup(&existing) {
+        let backup_base_dir = match scope {
+            ConfigScope::User => home.tool_root(Tool::Codex).join("backup"),
+            _ => agents_path
+                .parent()
+                .unwrap_or(Path::new("."))
+                .join(".prismctl")
+                .join("backup"),
+        };
         let ts = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map_err(|e| tf!(keys::ERROR_TIMESTAMP, "error" => e))?
             .as_secs();
-        let backup_path = backup_base_dir.join(ts.to_string()).join("AGENTS.md");
+        let backup_path = backup_base_dir
+            .join("prismctl")
+            .join(ts.to_string())
+            .join("AGENTS.md");
         if let Some(parent) = backup_path.parent() {
             cs.push(Change::CreateDirAll {
                 path: parent.to_path_buf(),
@@ -1336,50 +1604,90 @@ fn cmd_codex_agent_use(mut args: Vec<String>) -> Result<(), String> {
 
     cs.push(Change::WriteFile {
         path: agents_path,
-        bytes: tpl.as_bytes().to_vec(),
+        bytes: next.into_bytes(),
         overwrite: true,
     });
 
-    let title = tf!(keys::CHANGESET_PREVIEW_TITLE, "mode" => format!("{:?}", mode));
-    println!("{}", title);
-    for c in cs.iter() {
-        println!("  - {}", crate::icons::render_change(c));
-    }
+    print_changeset_preview(&cs, mode);
     println!("{}", tf!(keys::CODEX_AGENT_SWITCHED, "name" => name));
 
     if mode == ApplyMode::DryRun {
         println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
         return Ok(());
     }
+    apply_changeset(&cs, mode)
+}
 
-    if !yes {
-        return Err(crate::errors::usage(danger_codex_agent_use_confirmation()));
+fn cmd_codex_agent_current(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (_, agents_path) = parse_codex_agents_target(&mut args, &home)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex agent current", &args));
     }
 
-    let fs = RealFileSystem;
-    let runner = RealCommandRunner;
-    cs.apply(mode, &fs, &runner)?;
-    println!("\n{}", t!(keys::APPLY_APPLIED));
+    let existing = fs::read_to_string(&agents_path).unwrap_or_default();
+    let path = agents_path.display().to_string();
+    match codex::current_codex_persona(&existing) {
+        Some(name) => println!(
+            "{}",
+            tf!(keys::CODEX_AGENT_CURRENT, "name" => name, "path" => path)
+        ),
+        None => println!("{}", tf!(keys::CODEX_AGENT_CURRENT_NONE, "path" => path)),
+    }
     Ok(())
 }
 
-pub(crate) fn danger_codex_agent_use_confirmation() -> String {
-    [
-        t!(keys::DANGER_TITLE),
-        t!(keys::DANGER_CODEX_AGENT_TYPE),
-        t!(keys::DANGER_CODEX_AGENT_SCOPE),
-        t!(keys::DANGER_CODEX_AGENT_RISK),
-        String::new(),
-        t!(keys::DANGER_CONFIRM_NEED_YES),
-    ]
-    .join("\n")
+fn cmd_codex_agent_clear(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (_, agents_path) = parse_codex_agents_target(&mut args, &home)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex agent clear", &args));
+    }
+
+    let existing = fs::read_to_string(&agents_path).unwrap_or_default();
+    let Some(next) = codex::clear_codex_persona(&existing) else {
+        println!(
+            "{}",
+            tf!(keys::CODEX_AGENT_CURRENT_NONE, "path" => agents_path.display().to_string())
+        );
+        return Ok(());
+    };
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: agents_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_provider_add(mut args: Vec<String>) -> Result<(), String> {
+    let id = parse_required_value(&mut args, "--id")?;
+    codex::validate_codex_provider_id(&id).map_err(crate::errors::usage)?;
+    cmd_codex_provider_write(args, Some(id))
 }
 
-fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
+/// `codex provider set` (fixed `prismctl` table) and `codex provider add --id <ID>`.
+fn cmd_codex_provider_write(mut args: Vec<String>, id: Option<String>) -> Result<(), String> {
+    let command = if id.is_some() {
+        "codex provider add"
+    } else {
+        "codex provider set"
+    };
     let home = parse_home(&mut args)?;
     let mode = parse_apply_mode(&mut args)?;
 
-    let mut provider: Option<providers::Provider> = None;
+    let mut provider: Option<String> = None;
     let mut api_key: Option<String> = None;
     let mut base_url: Option<String> = None;
     let mut model: Option<String> = None;
@@ -1399,7 +1707,7 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
                         ))
                     })?
                     .to_string();
-                provider = Some(providers::parse_provider_id(&v).map_err(crate::errors::usage)?);
+                provider = Some(v);
                 args.drain(i..=i + 1);
             }
             "--api-key" => {
@@ -1462,7 +1770,8 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
         }
     }
 
-    if api_key.is_none()
+    if id.is_none()
+        && api_key.is_none()
         && provider.is_none()
         && base_url.is_none()
         && model.is_none()
@@ -1474,10 +1783,15 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
         )));
     }
     if !args.is_empty() {
-        return Err(err_unsupported_args_with_help("codex provider set", &args));
+        return Err(err_unsupported_args_with_help(command, &args));
     }
 
     let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let catalog = load_provider_catalog(&home)?;
+    let preset = match &provider {
+        Some(v) => Some(catalog.parse_provider_id(v).map_err(crate::errors::usage)?),
+        None => None,
+    };
     let codex_root = home.tool_root(Tool::Codex);
     let config_path = codex_root.join("config.toml");
     let auth_path = codex_root.join("auth.json");
@@ -1486,22 +1800,28 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
     let existing_auth = fs::read_to_string(&auth_path).unwrap_or_default();
 
     // Default values keep the command usable even when only a subset of args is provided.
-    let provider_id = "prismctl".to_string();
-    let temp_env_key = "PRISMCTL_CODEX_API_KEY".to_string();
-
-    let resolved = providers::resolve_codex_provider(provider, base_url, wire_api, model);
-
-    let display_name = match provider {
-        Some(p) => format!("Prismctl ({})", p.id()),
-        None => "Prismctl".to_string(),
+    let provider_id = id.clone().unwrap_or_else(|| "prismctl".to_string());
+    // `--api-key` is stored in auth.json under a per-provider name; a preset's `env_key`
+    // is written as the table's `env_key`, which Codex reads from the environment.
+    let temp_env_key = codex::default_codex_env_key(&provider_id);
+    let env_key = preset.and_then(|p| p.env_key.clone());
+
+    let resolved = providers::resolve_codex_provider(preset, base_url, wire_api, model);
+
+    let display_name = match (&id, preset) {
+        (Some(id), Some(p)) => format!("{} ({})", id, p.id),
+        (Some(id), None) => id.clone(),
+        (None, Some(p)) => format!("Prismctl ({})", p.id),
+        (None, None) => "Prismctl".to_string(),
     };
 
     let cfg = CodexProviderConfig {
-        provider_id,
+        provider_id: provider_id.clone(),
         display_name,
         base_url: resolved.base_url,
         wire_api: resolved.wire_api,
         temp_env_key: temp_env_key.clone(),
+        env_key,
         requires_openai_auth: false,
         model: resolved.model,
     };
@@ -1535,13 +1855,19 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
         println!("  - {}", crate::icons::render_change(c));
     }
     if set_default {
-        println!("{}", t!(keys::CODEX_PROVIDER_DEFAULT_SET));
+        println!(
+            "{}",
+            tf!(keys::CODEX_PROVIDER_DEFAULT_SET, "id" => &provider_id)
+        );
     }
-    if let Some(p) = provider {
-        println!("{}", tf!(keys::CODEX_PROVIDER_PRESET, "provider" => p.id()));
+    if let Some(p) = preset {
+        println!("{}", tf!(keys::CODEX_PROVIDER_PRESET, "provider" => &p.id));
     }
     if has_api_key {
-        println!("{}", t!(keys::CODEX_AUTH_KEY_HIDDEN));
+        println!(
+            "{}",
+            tf!(keys::CODEX_AUTH_KEY_HIDDEN, "key" => &temp_env_key)
+        );
     }
 
     if mode == ApplyMode::DryRun {
@@ -1556,6 +1882,148 @@ fn cmd_codex_provider_set(mut args: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
+fn cmd_codex_provider_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex provider list", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let config_path = home.tool_root(Tool::Codex).join("config.toml");
+    let content = fs::read_to_string(&config_path).unwrap_or_default();
+    let active = codex::active_codex_provider(&content);
+    let entries = codex::list_codex_providers(&content);
+
+    println!(
+        "{}",
+        tf!(keys::CODEX_PROVIDER_LIST_TITLE, "path" => config_path.display().to_string())
+    );
+    if entries.is_empty() {
+        println!("  {}", t!(keys::CODEX_PROVIDER_LIST_EMPTY));
+    }
+    for p in &entries {
+        let marker = if active.as_deref() == Some(p.id.as_str()) {
+            format!(" {}", t!(keys::CODEX_PROVIDER_ACTIVE_MARKER))
+        } else {
+            String::new()
+        };
+        println!("  - {}{}", p.id, marker);
+        println!(
+            "    {}",
+            tf!(
+                keys::CODEX_PROVIDER_LIST_DETAIL,
+                "base_url" => p.base_url.as_deref().unwrap_or("-"),
+                "model" => p.model.as_deref().unwrap_or("-"),
+                "key" => p
+                    .auth_key
+                    .clone()
+                    .or_else(|| p.env_key.as_ref().map(|k| format!("${k}")))
+                    .unwrap_or_else(|| "-".to_string())
+            )
+        );
+    }
+    match active {
+        Some(id) if !entries.iter().any(|p| p.id == id) => {
+            println!("{}", tf!(keys::CODEX_PROVIDER_ACTIVE_BUILTIN, "id" => id))
+        }
+        Some(_) => {}
+        None => println!("{}", t!(keys::CODEX_PROVIDER_ACTIVE_NONE)),
+    }
+    Ok(())
+}
+
+fn cmd_codex_provider_use(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let id = parse_name_or_positional(&mut args, "--id")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("codex provider use", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let codex_root = home.tool_root(Tool::Codex);
+    let config_path = codex_root.join("config.toml");
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next =
+        codex::use_codex_provider_in_config_toml(&existing, &id).map_err(crate::errors::usage)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: codex_root });
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    println!("{}", tf!(keys::CODEX_PROVIDER_DEFAULT_SET, "id" => &id));
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_codex_provider_remove(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let id = parse_name_or_positional(&mut args, "--id")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "codex provider remove",
+            &args,
+        ));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let codex_root = home.tool_root(Tool::Codex);
+    let config_path = codex_root.join("config.toml");
+    let auth_path = codex_root.join("auth.json");
+    let existing_toml = fs::read_to_string(&config_path).unwrap_or_default();
+    let existing_auth = fs::read_to_string(&auth_path).unwrap_or_default();
+    let (next_toml, next_auth) = codex::remove_codex_provider(&existing_toml, &existing_auth, &id)
+        .map_err(crate::errors::usage)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next_toml.into_bytes(),
+        overwrite: true,
+    });
+    if next_auth != existing_auth {
+        cs.push(Change::WriteFile {
+            path: auth_path,
+            bytes: next_auth.into_bytes(),
+            overwrite: true,
+        });
+    }
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(
+            danger_codex_provider_remove_confirmation(&id),
+        ));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn danger_codex_provider_remove_confirmation(id: &str) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_CODEX_PROVIDER_REMOVE_TYPE),
+        tf!(keys::DANGER_CODEX_PROVIDER_REMOVE_SCOPE, "id" => id),
+        t!(keys::DANGER_CODEX_PROVIDER_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
+
 // ---- claude ----
 
 fn cmd_claude_env(mut args: Vec<String>) -> Result<(), String> {
@@ -1565,7 +2033,10 @@ fn cmd_claude_env(mut args: Vec<String>) -> Result<(), String> {
     args.remove(0);
 
     match action.as_str() {
+        "show" => cmd_claude_env_show(args),
         "set" => cmd_claude_env_set(args),
+        "unset" => cmd_claude_env_unset(args),
+        "reset" => cmd_claude_env_reset(args),
         _ => Err(err_unknown_subcommand_with_help("claude env", &action)),
     }
 }
@@ -1574,6 +2045,10 @@ fn cmd_claude_env_set(mut args: Vec<String>) -> Result<(), String> {
     let home = parse_home(&mut args)?;
     let mode = parse_apply_mode(&mut args)?;
 
+    let preset = match parse_optional_value(&mut args, "--provider")? {
+        Some(v) => Some(providers::claude_preset(&v).map_err(crate::errors::usage)?),
+        None => None,
+    };
     let mut auth_token: Option<String> = None;
     let mut base_url: Option<String> = None;
     let mut model: Option<String> = None;
@@ -1664,43 +2139,53 @@ fn cmd_claude_env_set(mut args: Vec<String>) -> Result<(), String> {
             _ => i += 1,
         }
     }
+    let small_fast_model = parse_optional_value(&mut args, "--small-fast-model")?;
+    let api_timeout_ms = parse_optional_value(&mut args, "--api-timeout-ms")?;
+    let max_output_tokens = parse_optional_value(&mut args, "--max-output-tokens")?;
+    let disable_telemetry = take_flag(&mut args, "--disable-telemetry").then(|| "1".to_string());
 
-    if auth_token.is_none()
-        && base_url.is_none()
-        && model.is_none()
-        && haiku_model.is_none()
-        && sonnet_model.is_none()
-        && opus_model.is_none()
-    {
+    let has_auth_token = auth_token.is_some();
+    let mut patch = ClaudeEnvPatch {
+        auth_token,
+        base_url,
+        model,
+        default_haiku_model: haiku_model,
+        default_sonnet_model: sonnet_model,
+        default_opus_model: opus_model,
+        small_fast_model,
+        api_timeout_ms,
+        max_output_tokens,
+        disable_telemetry,
+    };
+    if preset.is_none() && patch.is_empty() {
         return Err(crate::errors::usage(t!(
             keys::ERROR_CLAUDE_ENV_SET_NEEDS_ARGS
         )));
     }
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (scope, settings_path) = parse_claude_settings_target(&mut args, &home)?;
     if !args.is_empty() {
         return Err(err_unsupported_args_with_help("claude env set", &args));
     }
+    patch.check_scope(scope).map_err(crate::errors::usage)?;
 
-    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-    let claude_root = home.tool_root(Tool::ClaudeCode);
-    let settings_path = claude_root.join("settings.json");
     let existing = fs::read_to_string(&settings_path).unwrap_or_default();
 
-    let has_auth_token = auth_token.is_some();
-
-    let patch = ClaudeEnvPatch {
-        auth_token,
-        base_url,
-        model,
-        default_haiku_model: haiku_model,
-        default_sonnet_model: sonnet_model,
-        default_opus_model: opus_model,
-    };
-    let next = claude::apply_claude_env_patch_to_settings_json(&existing, &patch)?;
+    let next = match preset {
+        Some(p) => {
+            patch = patch.with_preset(p);
+            claude::apply_claude_env_preset_to_settings_json(&existing, &patch)
+        }
+        None => claude::apply_claude_env_patch_to_settings_json(&existing, &patch),
+    }
+    .map_err(crate::errors::usage)?;
 
     let mut cs = ChangeSet::new();
-    cs.push(Change::CreateDirAll {
-        path: claude_root.clone(),
-    });
+    if let Some(parent) = settings_path.parent() {
+        cs.push(Change::CreateDirAll {
+            path: parent.to_path_buf(),
+        });
+    }
     cs.push(Change::WriteFile {
         path: settings_path,
         bytes: next.into_bytes(),
@@ -1712,6 +2197,9 @@ fn cmd_claude_env_set(mut args: Vec<String>) -> Result<(), String> {
     for c in cs.iter() {
         println!("  - {}", crate::icons::render_change(c));
     }
+    if let Some(p) = preset {
+        println!("{}", tf!(keys::CLAUDE_PROVIDER_PRESET, "provider" => p.id));
+    }
     if has_auth_token {
         println!("{}", t!(keys::CLAUDE_AUTH_TOKEN_HIDDEN));
     }
@@ -1728,6 +2216,122 @@ fn cmd_claude_env_set(mut args: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
+fn cmd_claude_env_show(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let settings_path = parse_claude_settings_path(&mut args, &home)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude env show", &args));
+    }
+
+    let existing = fs::read_to_string(&settings_path).unwrap_or_default();
+    let env = claude::read_claude_env_redacted(&existing).map_err(crate::errors::runtime)?;
+
+    println!(
+        "{}",
+        tf!(keys::CLAUDE_ENV_TITLE, "path" => settings_path.display().to_string())
+    );
+    if env.is_empty() {
+        println!("  {}", t!(keys::CLAUDE_ENV_EMPTY));
+    }
+    for (key, value) in env {
+        println!("  {key}={value}");
+    }
+    Ok(())
+}
+
+fn cmd_claude_env_unset(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let settings_path = parse_claude_settings_path(&mut args, &home)?;
+    if let Some(flag) = args.iter().find(|a| a.starts_with("--")) {
+        return Err(err_unsupported_args_with_help(
+            "claude env unset",
+            &vec![flag.clone()],
+        ));
+    }
+    if args.is_empty() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_CLAUDE_ENV_UNSET_NEEDS_KEYS
+        )));
+    }
+
+    let existing = fs::read_to_string(&settings_path).unwrap_or_default();
+    let (next, missing) =
+        claude::unset_claude_env_keys(&existing, &args).map_err(crate::errors::runtime)?;
+    for key in &missing {
+        println!("{}", tf!(keys::CLAUDE_ENV_KEY_NOT_SET, "key" => key));
+    }
+    if missing.len() == args.len() {
+        return Ok(());
+    }
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: settings_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_claude_env_reset(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let settings_path = parse_claude_settings_path(&mut args, &home)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("claude env reset", &args));
+    }
+
+    let existing = fs::read_to_string(&settings_path).unwrap_or_default();
+    let Some(next) = claude::reset_claude_env(&existing).map_err(crate::errors::runtime)? else {
+        println!("{}", t!(keys::CLAUDE_ENV_RESET_NOTHING));
+        return Ok(());
+    };
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: settings_path.clone(),
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(danger_claude_env_reset_confirmation(
+            &settings_path,
+        )));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn danger_claude_env_reset_confirmation(settings_path: &Path) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_CLAUDE_ENV_RESET_TYPE),
+        tf!(
+            keys::DANGER_CLAUDE_ENV_RESET_SCOPE,
+            "keys" => claude::CLAUDE_ENV_KEYS.join(", "),
+            "path" => settings_path.display().to_string()
+        ),
+        t!(keys::DANGER_CLAUDE_ENV_RESET_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
+
 fn cmd_claude_output_style(mut args: Vec<String>) -> Result<(), String> {
     let Some(action) = args.first().cloned() else {
         return Err(err_missing_subcommand_with_help("claude output-style"));
@@ -1748,6 +2352,8 @@ fn cmd_claude_output_style_use(mut args: Vec<String>) -> Result<(), String> {
     let mode = parse_apply_mode(&mut args)?;
 
     let name = parse_required_value(&mut args, "--name")?;
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let settings_path = parse_claude_settings_path(&mut args, &home)?;
     if !args.is_empty() {
         return Err(err_unsupported_args_with_help(
             "claude output-style use",
@@ -1755,16 +2361,15 @@ fn cmd_claude_output_style_use(mut args: Vec<String>) -> Result<(), String> {
         ));
     }
 
-    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
-    let claude_root = home.tool_root(Tool::ClaudeCode);
-    let settings_path = claude_root.join("settings.json");
     let existing = fs::read_to_string(&settings_path).unwrap_or_default();
     let next = claude::set_claude_output_style_in_settings_json(&existing, &name)?;
 
     let mut cs = ChangeSet::new();
-    cs.push(Change::CreateDirAll {
-        path: claude_root.clone(),
-    });
+    if let Some(parent) = settings_path.parent() {
+        cs.push(Change::CreateDirAll {
+            path: parent.to_path_buf(),
+        });
+    }
     cs.push(Change::WriteFile {
         path: settings_path,
         bytes: next.into_bytes(),
@@ -1816,7 +2421,7 @@ fn cmd_gemini_settings(mut args: Vec<String>) -> Result<(), String> {
     }
 }
 
-fn cmd_gemini_mcp(mut args: Vec<String>) -> Result<(), String> {
+pub(super) fn cmd_gemini_mcp(mut args: Vec<String>) -> Result<(), String> {
     let Some(action) = args.first().cloned() else {
         return Err(err_missing_subcommand_with_help("gemini mcp"));
     };
@@ -1833,7 +2438,7 @@ fn cmd_gemini_mcp(mut args: Vec<String>) -> Result<(), String> {
     }
 }
 
-fn parse_gemini_mcp_scope(
+pub(super) fn parse_gemini_mcp_scope(
     args: &mut Vec<String>,
 ) -> Result<prismctl_core::mcp::GeminiMcpScope, String> {
     let mut i = 0;
@@ -1893,34 +2498,25 @@ fn parse_gemini_scope(args: &mut Vec<String>) -> Result<GeminiScope, String> {
 }
 
 fn cmd_gemini_env_set(mut args: Vec<String>) -> Result<(), String> {
+    use prismctl_core::gemini::{self, GeminiEnvPatch};
+
     let home = parse_home(&mut args)?;
     let mode = parse_apply_mode(&mut args)?;
     let scope = parse_gemini_scope(&mut args)?;
     let project_path = parse_project_path_opt(&mut args)?;
 
-    let mut api_key: Option<String> = None;
-
-    let mut i = 0;
-    while i < args.len() {
-        match args[i].as_str() {
-            "--api-key" => {
-                api_key = Some(
-                    args.get(i + 1)
-                        .ok_or_else(|| {
-                            crate::errors::usage(tf!(
-                                keys::ERROR_FLAG_MISSING_VALUE,
-                                "flag" => "--api-key"
-                            ))
-                        })?
-                        .to_string(),
-                );
-                args.drain(i..=i + 1);
-            }
-            _ => i += 1,
-        }
-    }
+    let patch = GeminiEnvPatch {
+        mode: match parse_optional_value(&mut args, "--mode")? {
+            Some(v) => Some(gemini::parse_gemini_auth_mode(&v).map_err(crate::errors::usage)?),
+            None => None,
+        },
+        api_key: parse_optional_value(&mut args, "--api-key")?,
+        project: parse_optional_value(&mut args, "--project")?,
+        location: parse_optional_value(&mut args, "--location")?,
+        base_url: parse_optional_value(&mut args, "--base-url")?,
+    };
 
-    if api_key.is_none() {
+    if patch.is_empty() {
         return Err(crate::errors::usage(t!(
             keys::ERROR_GEMINI_ENV_SET_NEEDS_ARGS
         )));
@@ -1947,18 +2543,9 @@ fn cmd_gemini_env_set(mut args: Vec<String>) -> Result<(), String> {
         }
     };
     let existing = fs::read_to_string(&env_path).unwrap_or_default();
-    let start_marker = "# prismctl:start";
-    let end_marker = "# prismctl:end";
-
-    let mut kv = parse_env_block(
-        &extract_managed_block(&existing, start_marker, end_marker).unwrap_or_default(),
-    );
-    if let Some(v) = api_key {
-        kv.insert("GEMINI_API_KEY".to_string(), v);
-    }
-
-    let block = format_env_block(&kv);
-    let next = upsert_managed_block(&existing, start_marker, end_marker, &block);
+    // Keys of other auth modes are dropped from the managed block; user lines outside stay.
+    let (next, auth_mode) =
+        gemini::upsert_gemini_env(&existing, &patch).map_err(crate::errors::usage)?;
     let mut cs = ChangeSet::new();
     cs.push(Change::CreateDirAll { path: gemini_dir });
     cs.push(Change::WriteFile {
@@ -1972,7 +2559,10 @@ fn cmd_gemini_env_set(mut args: Vec<String>) -> Result<(), String> {
     for c in cs.iter() {
         println!("  - {}", crate::icons::render_change(c));
     }
-    println!("{}", t!(keys::GEMINI_ENV_MANAGED_KEYS));
+    println!(
+        "{}",
+        tf!(keys::GEMINI_ENV_MANAGED_KEYS, "mode" => auth_mode.id(), "keys" => gemini::GEMINI_ENV_KEYS.join(", "))
+    );
 
     if mode == ApplyMode::DryRun {
         println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
@@ -2304,48 +2894,3 @@ pub(crate) fn danger_gemini_mcp_confirmation() -> String {
     ]
     .join("\n")
 }
-
-fn parse_env_block(block: &str) -> std::collections::HashMap<String, String> {
-    let mut out = std::collections::HashMap::new();
-    for raw in block.lines() {
-        let line = raw.trim();
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-        let Some((k, v)) = line.split_once('=') else {
-            continue;
-        };
-        let key = k.trim().to_string();
-        let value = v.trim().trim_matches('"').to_string();
-        if !key.is_empty() {
-            out.insert(key, value);
-        }
-    }
-    out
-}
-
-fn format_env_value(value: &str) -> String {
-    let needs_quotes = value
-        .chars()
-        .any(|c| c.is_whitespace() || c == '#' || c == '"');
-    if !needs_quotes {
-        return value.to_string();
-    }
-    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
-    format!("\"{}\"", escaped)
-}
-
-fn format_env_block(kv: &std::collections::HashMap<String, String>) -> String {
-    // Prismctl only owns GEMINI_API_KEY for Gemini CLI; keep other keys outside the managed block.
-    let mut keys: Vec<&str> = vec!["GEMINI_API_KEY"];
-    keys.retain(|k| kv.contains_key(*k));
-    let mut out = String::new();
-    for k in keys {
-        let v = kv.get(k).expect("key exists");
-        out.push_str(k);
-        out.push('=');
-        out.push_str(&format_env_value(v));
-        out.push('\n');
-    }
-    out
-}
@@ -0,0 +1,240 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_config_scope, parse_home,
+    parse_optional_value, parse_project_path_opt, parse_required_value, print_changeset_preview,
+    resolve_project_root, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::custom_prompt::{self, PromptKind};
+use prismctl_core::paths::{ConfigScope, PrismctlHome};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `codex prompt list|create|edit|remove` (user scope only: Codex reads `~/.codex/prompts/`).
+pub fn cmd_codex_prompt(args: Vec<String>) -> Result<(), String> {
+    cmd_custom_prompt(PromptKind::CodexPrompt, args)
+}
+
+/// `claude command list|create|edit|remove` (`--scope user|project`).
+pub fn cmd_claude_command(args: Vec<String>) -> Result<(), String> {
+    cmd_custom_prompt(PromptKind::ClaudeCommand, args)
+}
+
+fn command_name(kind: PromptKind) -> &'static str {
+    match kind {
+        PromptKind::CodexPrompt => "codex prompt",
+        PromptKind::ClaudeCommand => "claude command",
+    }
+}
+
+fn cmd_custom_prompt(kind: PromptKind, mut args: Vec<String>) -> Result<(), String> {
+    let Some(action) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help(command_name(kind)));
+    };
+    args.remove(0);
+
+    match action.as_str() {
+        "list" => cmd_custom_prompt_list(kind, args),
+        "create" => cmd_custom_prompt_write(kind, args, false),
+        "edit" => cmd_custom_prompt_write(kind, args, true),
+        "remove" => cmd_custom_prompt_remove(kind, args),
+        _ => Err(err_unknown_subcommand_with_help(
+            command_name(kind),
+            &action,
+        )),
+    }
+}
+
+/// Resolve the target directory; `--scope`/`--project-path` are only accepted for Claude commands.
+fn parse_prompt_dir(kind: PromptKind, args: &mut Vec<String>) -> Result<PathBuf, String> {
+    let home = parse_home(args)?;
+    let project_root = match kind {
+        PromptKind::ClaudeCommand => {
+            let scope = parse_config_scope(args)?;
+            let project_path = parse_project_path_opt(args)?;
+            match scope {
+                ConfigScope::User => None,
+                ConfigScope::Project | ConfigScope::Local => {
+                    Some(resolve_project_root(project_path)?)
+                }
+            }
+        }
+        PromptKind::CodexPrompt => None,
+    };
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    Ok(kind.dir(&home, project_root.as_deref()))
+}
+
+fn usage_of(kind: PromptKind, name: &str) -> String {
+    match kind {
+        PromptKind::CodexPrompt => format!("/prompts:{name}"),
+        PromptKind::ClaudeCommand => format!("/{name}"),
+    }
+}
+
+fn cmd_custom_prompt_list(kind: PromptKind, mut args: Vec<String>) -> Result<(), String> {
+    let dir = parse_prompt_dir(kind, &mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            &format!("{} list", command_name(kind)),
+            &args,
+        ));
+    }
+
+    let title = match kind {
+        PromptKind::CodexPrompt => keys::CODEX_PROMPT_LIST_TITLE,
+        PromptKind::ClaudeCommand => keys::CLAUDE_COMMAND_LIST_TITLE,
+    };
+    println!("{}", tf!(title, "path" => dir.display().to_string()));
+    let prompts = custom_prompt::list_custom_prompts(kind, &dir);
+    if prompts.is_empty() {
+        println!("  {}", t!(keys::CUSTOM_PROMPT_LIST_EMPTY));
+    }
+    for p in prompts {
+        let mut line = format!("  - {}", usage_of(kind, &p.name));
+        if let Some(hint) = p.field("argument-hint") {
+            line.push_str(&format!(" {hint}"));
+        }
+        if let Some(desc) = p.field("description").filter(|d| !d.is_empty()) {
+            line.push_str(&format!(": {desc}"));
+        }
+        println!("{line}");
+        if let Some(error) = p.error {
+            println!("    {}", tf!(keys::CUSTOM_PROMPT_INVALID, "error" => error));
+        }
+    }
+    Ok(())
+}
+
+/// `create` (new file with scaffolded frontmatter) and `edit` (patch frontmatter, keep body).
+fn cmd_custom_prompt_write(
+    kind: PromptKind,
+    mut args: Vec<String>,
+    edit: bool,
+) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let dir = parse_prompt_dir(kind, &mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    let mut fields = Vec::new();
+    for key in kind.known_keys() {
+        if let Some(v) = parse_optional_value(&mut args, &format!("--{key}"))? {
+            fields.push((key.to_string(), v));
+        }
+    }
+    let action = if edit { "edit" } else { "create" };
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            &format!("{} {action}", command_name(kind)),
+            &args,
+        ));
+    }
+    custom_prompt::validate_prompt_name(&name).map_err(crate::errors::usage)?;
+
+    let path = dir.join(format!("{name}.md"));
+    let existing = fs::read_to_string(&path).ok();
+    let content = match (edit, existing) {
+        (false, Some(_)) => {
+            return Err(crate::errors::usage(
+                tf!(keys::ERROR_CUSTOM_PROMPT_EXISTS, "path" => path.display().to_string()),
+            ));
+        }
+        (false, None) => {
+            if !fields.iter().any(|(k, _)| k == "description") {
+                fields.insert(
+                    0,
+                    ("description".to_string(), name.replace(['-', '_'], " ")),
+                );
+            }
+            custom_prompt::scaffold_prompt(kind, &fields)
+        }
+        (true, None) => {
+            return Err(crate::errors::usage(
+                tf!(keys::ERROR_CUSTOM_PROMPT_NOT_FOUND, "path" => path.display().to_string()),
+            ));
+        }
+        (true, Some(_)) if fields.is_empty() => {
+            return Err(crate::errors::usage(t!(
+                keys::ERROR_CUSTOM_PROMPT_EDIT_NEEDS_ARGS
+            )));
+        }
+        (true, Some(existing)) => custom_prompt::edit_prompt(kind, &existing, &fields),
+    }
+    .map_err(crate::errors::usage)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: dir });
+    cs.push(Change::WriteFile {
+        path: path.clone(),
+        bytes: content.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)?;
+    println!(
+        "{}",
+        tf!(
+            keys::CUSTOM_PROMPT_USAGE_HINT,
+            "path" => path.display().to_string(),
+            "usage" => usage_of(kind, &name)
+        )
+    );
+    Ok(())
+}
+
+fn cmd_custom_prompt_remove(kind: PromptKind, mut args: Vec<String>) -> Result<(), String> {
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let dir = parse_prompt_dir(kind, &mut args)?;
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            &format!("{} remove", command_name(kind)),
+            &args,
+        ));
+    }
+    custom_prompt::validate_prompt_name(&name).map_err(crate::errors::usage)?;
+
+    let path = dir.join(format!("{name}.md"));
+    if !path.is_file() {
+        return Err(crate::errors::usage(
+            tf!(keys::ERROR_CUSTOM_PROMPT_NOT_FOUND, "path" => path.display().to_string()),
+        ));
+    }
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::RemoveFile { path: path.clone() });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(
+            danger_custom_prompt_remove_confirmation(kind, &path),
+        ));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn danger_custom_prompt_remove_confirmation(kind: PromptKind, path: &Path) -> String {
+    let kind_label = match kind {
+        PromptKind::CodexPrompt => "Codex prompt",
+        PromptKind::ClaudeCommand => "Claude Code command",
+    };
+    [
+        t!(keys::DANGER_TITLE),
+        tf!(keys::DANGER_CUSTOM_PROMPT_REMOVE_TYPE, "kind" => kind_label),
+        tf!(keys::DANGER_CUSTOM_PROMPT_REMOVE_SCOPE, "path" => path.display().to_string()),
+        t!(keys::DANGER_CUSTOM_PROMPT_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
@@ -0,0 +1,64 @@
+use super::commands::err_unknown_subcommand_with_help;
+use prismctl_core::paths::Tool;
+use prismctl_core::tools::{self, McpBackend};
+
+type McpCommand = fn(Vec<String>) -> Result<(), String>;
+
+/// `prismctl <tool> mcp ...` handlers, keyed by adapter id. Each one implements the
+/// adapter's [`McpBackend`]: the tool's own `mcp` CLI, or its config file.
+const MCP_COMMANDS: &[(&str, McpBackend, McpCommand)] = &[
+    ("codex", McpBackend::ConfigFile, super::cmd_codex_mcp),
+    (
+        "claude",
+        McpBackend::ToolCli,
+        super::commands::cmd_claude_mcp,
+    ),
+    (
+        "gemini",
+        McpBackend::ToolCli,
+        super::commands::cmd_gemini_mcp,
+    ),
+    (
+        "opencode",
+        McpBackend::ConfigFile,
+        super::opencode::cmd_opencode_mcp,
+    ),
+    ("qwen", McpBackend::ToolCli, super::qwen::cmd_qwen_mcp),
+];
+
+fn mcp_command(adapter: &dyn tools::ToolAdapter) -> Option<McpCommand> {
+    let backend = adapter.mcp_backend()?;
+    MCP_COMMANDS
+        .iter()
+        .find(|(id, b, _)| *id == adapter.id() && *b == backend)
+        .map(|(_, _, cmd)| *cmd)
+}
+
+/// Route `prismctl <tool> mcp ...` through the tool registry.
+pub fn cmd_tool_mcp(tool: Tool, args: Vec<String>) -> Result<(), String> {
+    let adapter = tools::adapter_for(tool);
+    match mcp_command(adapter) {
+        Some(cmd) => cmd(args),
+        None => Err(err_unknown_subcommand_with_help(adapter.id(), "mcp")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_mcp_backend_has_a_command() {
+        for adapter in tools::registry() {
+            assert_eq!(
+                adapter.mcp_backend().is_some(),
+                mcp_command(*adapter).is_some(),
+                "{}",
+                adapter.id()
+            );
+        }
+        assert!(MCP_COMMANDS
+            .iter()
+            .all(|(id, _, _)| tools::find_tool(id).is_some()));
+    }
+}
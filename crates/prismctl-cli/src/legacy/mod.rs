@@ -1,3 +1,31 @@
+pub mod claude_agents;
+pub mod claude_hooks;
+pub mod claude_permissions;
+pub mod claude_statusline;
+pub mod codex_auth;
+pub mod codex_mcp;
+pub mod codex_profile;
+pub mod codex_settings;
 pub mod commands;
+pub mod custom_prompt;
+pub mod mcp;
+pub mod opencode;
+pub mod profile;
+pub mod provider;
+pub mod qwen;
 
+pub use claude_agents::*;
+pub use claude_hooks::*;
+pub use claude_permissions::*;
+pub use claude_statusline::*;
+pub use codex_auth::*;
+pub use codex_mcp::*;
+pub use codex_profile::*;
+pub use codex_settings::*;
 pub use commands::*;
+pub use custom_prompt::*;
+pub use mcp::*;
+pub use opencode::*;
+pub use profile::*;
+pub use provider::*;
+pub use qwen::*;
@@ -0,0 +1,262 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, load_provider_catalog, parse_apply_mode, parse_config_scope,
+    parse_home, parse_optional_value, parse_project_path_opt, parse_required_value,
+    print_changeset_preview, resolve_project_root, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::mcp;
+use prismctl_core::opencode::{self, OpenCodeProviderConfig};
+use prismctl_core::paths::{ConfigScope, PrismctlHome, Tool};
+use prismctl_core::providers;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::PathBuf;
+
+pub fn cmd_opencode(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("opencode"));
+    };
+    args.remove(0);
+
+    match sub.as_str() {
+        "provider" => cmd_opencode_provider(args),
+        "mcp" => super::cmd_tool_mcp(Tool::OpenCode, args),
+        _ => Err(err_unknown_subcommand_with_help("opencode", &sub)),
+    }
+}
+
+fn cmd_opencode_provider(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("opencode provider"));
+    };
+    args.remove(0);
+    match sub.as_str() {
+        "set" => cmd_opencode_provider_set(args),
+        _ => Err(err_unknown_subcommand_with_help("opencode provider", &sub)),
+    }
+}
+
+pub(super) fn cmd_opencode_mcp(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("opencode mcp"));
+    };
+    args.remove(0);
+    match sub.as_str() {
+        "list" => cmd_opencode_mcp_list(args),
+        "builtin" => cmd_opencode_mcp_builtin(args),
+        "add" => cmd_opencode_mcp_add(args),
+        "remove" => cmd_opencode_mcp_remove(args),
+        _ => Err(err_unknown_subcommand_with_help("opencode mcp", &sub)),
+    }
+}
+
+/// Resolve `(config_dir, opencode.json)` for the given scope.
+fn opencode_config_path(
+    home: &PrismctlHome,
+    scope: ConfigScope,
+    project_path: Option<PathBuf>,
+) -> Result<(PathBuf, PathBuf), String> {
+    let dir = match scope {
+        ConfigScope::User => home.tool_root(Tool::OpenCode),
+        ConfigScope::Project | ConfigScope::Local => resolve_project_root(project_path)?,
+    };
+    let path = dir.join("opencode.json");
+    Ok((dir, path))
+}
+
+fn cmd_opencode_provider_set(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+
+    let provider = parse_optional_value(&mut args, "--provider")?;
+    let api_key = parse_optional_value(&mut args, "--api-key")?;
+    let base_url = parse_optional_value(&mut args, "--base-url")?;
+    let model = parse_optional_value(&mut args, "--model")?;
+
+    if provider.is_none() && api_key.is_none() && base_url.is_none() && model.is_none() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_OPENCODE_PROVIDER_SET_NEEDS_ARGS
+        )));
+    }
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "opencode provider set",
+            &args,
+        ));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let catalog = load_provider_catalog(&home)?;
+    let preset = match &provider {
+        Some(v) => Some(catalog.parse_provider_id(v).map_err(crate::errors::usage)?),
+        None => None,
+    };
+    let (root, config_path) = opencode_config_path(&home, ConfigScope::User, None)?;
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+
+    // OpenCode speaks the OpenAI-compatible API, so the Codex presets apply as-is.
+    let resolved = providers::resolve_codex_provider(preset, base_url, None, model);
+    let has_api_key = api_key.is_some();
+    let cfg = OpenCodeProviderConfig {
+        base_url: resolved.base_url,
+        model: resolved.model.clone(),
+        api_key,
+    };
+    let next = opencode::upsert_opencode_provider_in_config_json(&existing, &cfg)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: root });
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    println!(
+        "{}",
+        tf!(keys::OPENCODE_PROVIDER_MODEL_SET, "model" => resolved.model)
+    );
+    if let Some(p) = preset {
+        println!("{}", tf!(keys::CODEX_PROVIDER_PRESET, "provider" => &p.id));
+    }
+    if has_api_key {
+        println!("{}", t!(keys::OPENCODE_API_KEY_HIDDEN));
+    }
+
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_opencode_mcp_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let scope = parse_config_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("opencode mcp list", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (_, config_path) = opencode_config_path(&home, scope, project_path)?;
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let names = opencode::list_opencode_mcp_servers(&existing)?;
+
+    println!(
+        "{}",
+        tf!(keys::OPENCODE_MCP_LIST_TITLE, "path" => config_path.display().to_string())
+    );
+    if names.is_empty() {
+        println!("  {}", t!(keys::OPENCODE_MCP_LIST_EMPTY));
+    }
+    for name in names {
+        println!("  - {}", name);
+    }
+    Ok(())
+}
+
+fn cmd_opencode_mcp_builtin(args: Vec<String>) -> Result<(), String> {
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help(
+            "opencode mcp builtin",
+            &args,
+        ));
+    }
+    println!("{}", t!(keys::OPENCODE_MCP_BUILTIN_TITLE));
+    for s in mcp::list_builtin_mcp_servers() {
+        match (s.url, s.command) {
+            (Some(url), _) => println!("  - {} (remote): {}", s.id, url),
+            (None, Some(cmd)) => println!("  - {} (local): {} {}", s.id, cmd, s.args.join(" ")),
+            (None, None) => println!("  - {}", s.id),
+        }
+    }
+    Ok(())
+}
+
+fn cmd_opencode_mcp_add(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let scope = parse_config_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("opencode mcp add", &args));
+    }
+
+    let def = mcp::builtin_mcp_server(&name)
+        .ok_or_else(|| crate::errors::usage(format!("未知 MCP server: {}（内置）", name)))?;
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (dir, config_path) = opencode_config_path(&home, scope, project_path)?;
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next = opencode::upsert_opencode_mcp_server_in_config_json(&existing, def)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: dir });
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_opencode_mcp_remove(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let scope = parse_config_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("opencode mcp remove", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let (_, config_path) = opencode_config_path(&home, scope, project_path)?;
+    let existing = fs::read_to_string(&config_path).unwrap_or_default();
+    let next = opencode::remove_opencode_mcp_server_in_config_json(&existing, &name)
+        .map_err(crate::errors::usage)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: config_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(
+            danger_opencode_mcp_remove_confirmation(&name),
+        ));
+    }
+    apply_changeset(&cs, mode)
+}
+
+pub(crate) fn danger_opencode_mcp_remove_confirmation(name: &str) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_OPENCODE_MCP_REMOVE_TYPE),
+        tf!(keys::DANGER_OPENCODE_MCP_REMOVE_SCOPE, "name" => name),
+        t!(keys::DANGER_OPENCODE_MCP_REMOVE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
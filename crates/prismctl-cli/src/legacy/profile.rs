@@ -0,0 +1,154 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_home, parse_name_or_positional,
+    print_changeset_preview, take_flag,
+};
+use prismctl_core::changeset::ApplyMode;
+use prismctl_core::paths::PrismctlHome;
+use prismctl_core::profile;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::Path;
+
+pub fn cmd_profile(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("profile"));
+    };
+    args.remove(0);
+
+    match sub.as_str() {
+        "list" => cmd_profile_list(args),
+        "use" => cmd_profile_use(args),
+        "save" => cmd_profile_save(args),
+        "diff" => cmd_profile_diff(args),
+        _ => Err(err_unknown_subcommand_with_help("profile", &sub)),
+    }
+}
+
+fn read_existing(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+fn cmd_profile_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("profile list", &args));
+    }
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+
+    println!("{}", t!(keys::PROFILE_LIST_TITLE));
+    let names = profile::list_profiles(&home);
+    if names.is_empty() {
+        println!("  {}", t!(keys::PROFILE_NONE));
+        return Ok(());
+    }
+    let current = profile::capture_profile(&home, &read_existing).ok();
+    for name in names {
+        let active = match (&current, profile::load_profile(&home, &name)) {
+            (Some(cur), Ok(p)) => profile::is_active_profile(cur, &p),
+            _ => false,
+        };
+        if active {
+            println!("  - {} {}", name, t!(keys::PROFILE_ACTIVE_MARKER));
+        } else {
+            println!("  - {}", name);
+        }
+    }
+    Ok(())
+}
+
+fn cmd_profile_use(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let name = parse_name_or_positional(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("profile use", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let target = profile::load_profile(&home, &name).map_err(crate::errors::usage)?;
+    if target.entries().is_empty() {
+        println!("{}", tf!(keys::PROFILE_EMPTY, "name" => name));
+        return Ok(());
+    }
+    let cs = profile::plan_profile_use(&home, &target, &read_existing)?;
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_profile_save(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let name = parse_name_or_positional(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("profile save", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let current = profile::capture_profile(&home, &read_existing)?;
+    if current.entries().is_empty() {
+        return Err(crate::errors::usage(t!(keys::PROFILE_NOTHING_TO_SAVE)));
+    }
+    let cs = profile::plan_save_profile(&home, &name, &current).map_err(crate::errors::usage)?;
+
+    print_changeset_preview(&cs, mode);
+    println!("{}", t!(keys::PROFILE_SECRETS_HINT));
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if profile::profile_path(&home, &name).exists() && !yes {
+        return Err(crate::errors::usage(danger_profile_overwrite_confirmation(
+            &name,
+        )));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_profile_diff(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let name = parse_name_or_positional(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("profile diff", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let target = profile::load_profile(&home, &name).map_err(crate::errors::usage)?;
+    let current = profile::capture_profile(&home, &read_existing)?;
+    let diff = profile::diff_profile(&current, &target);
+    if diff.is_empty() {
+        println!("{}", tf!(keys::PROFILE_IN_SYNC, "name" => name));
+        return Ok(());
+    }
+
+    println!("{}", tf!(keys::PROFILE_DIFF_TITLE, "name" => name));
+    let display = |key: &str, value: Option<String>| {
+        value
+            .map(|v| profile::display_profile_value(key, &v))
+            .unwrap_or_else(|| t!(keys::PROFILE_UNSET))
+    };
+    for d in diff {
+        let current = display(&d.key, d.current);
+        let target = display(&d.key, d.target);
+        println!("  - {}: {} -> {}", d.key, current, target);
+    }
+    Ok(())
+}
+
+fn danger_profile_overwrite_confirmation(name: &str) -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_PROFILE_OVERWRITE_TYPE),
+        tf!(keys::DANGER_PROFILE_OVERWRITE_SCOPE, "name" => name),
+        t!(keys::DANGER_PROFILE_OVERWRITE_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
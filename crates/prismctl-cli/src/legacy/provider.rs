@@ -0,0 +1,267 @@
+use super::commands::{
+    err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, load_provider_catalog, parse_home, parse_optional_value,
+    parse_project_path_opt, resolve_project_root,
+};
+use crate::http::UreqClient;
+use prismctl_core::paths::{PrismctlHome, Tool};
+use prismctl_core::provider_api::{self, ApiFlavor, Endpoint, ProbeOutcome, ProbeReport};
+use prismctl_core::providers;
+use prismctl_core::tools;
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+pub fn cmd_provider(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("provider"));
+    };
+    args.remove(0);
+
+    match sub.as_str() {
+        "test" => cmd_provider_test(args),
+        "models" => cmd_provider_models(args),
+        _ => Err(err_unknown_subcommand_with_help("provider", &sub)),
+    }
+}
+
+fn read_existing(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+}
+
+/// Tools whose provider endpoint can be resolved and probed.
+const PROVIDER_TOOLS: &[Tool] = &[Tool::Codex, Tool::ClaudeCode, Tool::GeminiCli];
+
+/// `--tool <codex|claude|gemini|all>` (default: all three).
+fn parse_provider_tools(args: &mut Vec<String>) -> Result<Vec<Tool>, String> {
+    match parse_optional_value(args, "--tool")?.as_deref() {
+        None | Some("all") => Ok(PROVIDER_TOOLS.to_vec()),
+        Some(value) => parse_provider_tool(value).map(|tool| vec![tool]),
+    }
+}
+
+/// One of [`PROVIDER_TOOLS`] by id or alias.
+fn parse_provider_tool(value: &str) -> Result<Tool, String> {
+    match tools::find_tool(value).map(|t| t.tool()) {
+        Some(tool) if PROVIDER_TOOLS.contains(&tool) => Ok(tool),
+        _ => Err(crate::errors::usage(tf!(
+            keys::ERROR_TOOL_VALUE_UNSUPPORTED,
+            "value" => value
+        ))),
+    }
+}
+
+fn parse_timeout(args: &mut Vec<String>) -> Result<Duration, String> {
+    let Some(value) = parse_optional_value(args, "--timeout")? else {
+        return Ok(Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+    };
+    match value.parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(Duration::from_secs(secs)),
+        _ => Err(crate::errors::usage(tf!(
+            keys::ERROR_PROVIDER_TIMEOUT_INVALID,
+            "value" => value
+        ))),
+    }
+}
+
+fn cmd_provider_test(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let tools = parse_provider_tools(&mut args)?;
+    let timeout = parse_timeout(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("provider test", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let project_root = resolve_project_root(project_path)?;
+    let client = UreqClient::new(timeout);
+    let mut failed = 0;
+    for tool in tools {
+        let id = tools::adapter_for(tool).id();
+        let endpoint = provider_api::resolve_endpoint(&home, &project_root, tool, &read_existing)?;
+        let report = provider_api::probe_endpoint(&client, &endpoint);
+        println!(
+            "{}",
+            tf!(
+                keys::PROVIDER_TEST_LINE,
+                "tool" => id,
+                "outcome" => report.outcome.id(),
+                "status" => report.status.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string()),
+                "latency" => report.latency_ms,
+                "url" => report.url
+            )
+        );
+        if report.outcome == ProbeOutcome::Ok {
+            continue;
+        }
+        failed += 1;
+        if let Some(detail) = &report.detail {
+            println!(
+                "    {}",
+                tf!(keys::PROVIDER_TEST_DETAIL, "detail" => detail)
+            );
+        }
+        println!("    {}", probe_hint(report.outcome, tool));
+    }
+
+    if failed > 0 {
+        return Err(crate::errors::runtime(tf!(
+            keys::ERROR_PROVIDER_TEST_FAILED,
+            "count" => failed
+        )));
+    }
+    Ok(())
+}
+
+fn probe_hint(outcome: ProbeOutcome, tool: Tool) -> String {
+    match outcome {
+        ProbeOutcome::Ok => String::new(),
+        ProbeOutcome::Unauthorized => t!(keys::PROVIDER_TEST_HINT_UNAUTHORIZED),
+        ProbeOutcome::Forbidden => t!(keys::PROVIDER_TEST_HINT_FORBIDDEN),
+        ProbeOutcome::NotFound => t!(keys::PROVIDER_TEST_HINT_NOT_FOUND),
+        ProbeOutcome::BadRequest => t!(keys::PROVIDER_TEST_HINT_BAD_REQUEST),
+        ProbeOutcome::RateLimited => t!(keys::PROVIDER_TEST_HINT_RATE_LIMITED),
+        ProbeOutcome::ServerError => t!(keys::PROVIDER_TEST_HINT_SERVER_ERROR),
+        ProbeOutcome::UnexpectedStatus => t!(keys::PROVIDER_TEST_HINT_UNEXPECTED_STATUS),
+        ProbeOutcome::Timeout => t!(keys::PROVIDER_TEST_HINT_TIMEOUT),
+        ProbeOutcome::Dns => t!(keys::PROVIDER_TEST_HINT_DNS),
+        ProbeOutcome::ConnectionRefused => t!(keys::PROVIDER_TEST_HINT_CONNECTION_REFUSED),
+        ProbeOutcome::Tls => t!(keys::PROVIDER_TEST_HINT_TLS),
+        ProbeOutcome::Network => t!(keys::PROVIDER_TEST_HINT_NETWORK),
+        ProbeOutcome::Unsupported => t!(keys::PROVIDER_TEST_HINT_UNSUPPORTED),
+        ProbeOutcome::MissingCredentials => {
+            let command = match tool {
+                Tool::Codex => "codex provider set --api-key <KEY>",
+                Tool::ClaudeCode => "claude env set --auth-token <TOKEN>",
+                _ => "gemini env set --api-key <KEY>",
+            };
+            tf!(keys::PROVIDER_TEST_HINT_MISSING_CREDENTIALS, "command" => command)
+        }
+    }
+}
+
+/// List models served by `endpoint` (used by `provider models` and the config wizards).
+pub fn fetch_models(endpoint: &Endpoint) -> Result<Vec<String>, ProbeReport> {
+    let client = UreqClient::new(Duration::from_secs(DEFAULT_TIMEOUT_SECS));
+    provider_api::list_models(&client, endpoint)
+}
+
+fn cmd_provider_models(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let tool = match parse_optional_value(&mut args, "--tool")?.as_deref() {
+        None | Some("all") => {
+            return Err(crate::errors::usage(t!(keys::ERROR_PROVIDER_MODELS_TOOL)))
+        }
+        Some(value) => parse_provider_tool(value)?,
+    };
+    let timeout = parse_timeout(&mut args)?;
+    let provider = parse_optional_value(&mut args, "--provider")?;
+    let base_url = parse_optional_value(&mut args, "--base-url")?;
+    let api_key = parse_optional_value(&mut args, "--api-key")?;
+    let project_path = parse_project_path_opt(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("provider models", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let project_root = resolve_project_root(project_path)?;
+    let mut endpoint = provider_api::resolve_endpoint(&home, &project_root, tool, &read_existing)?;
+    let mut fallback = endpoint.model.clone();
+    if let Some(id) = &provider {
+        let (preset_url, preset_model, env_key) = match endpoint.flavor {
+            ApiFlavor::OpenAi => {
+                let catalog = load_provider_catalog(&home)?;
+                let p = catalog
+                    .parse_provider_id(id)
+                    .map_err(crate::errors::usage)?;
+                (
+                    p.base_url.clone(),
+                    Some(p.default_model.clone()).filter(|m| !m.is_empty()),
+                    p.env_key.clone(),
+                )
+            }
+            ApiFlavor::Anthropic => {
+                let p = providers::claude_preset(id).map_err(crate::errors::usage)?;
+                (
+                    p.base_url.to_string(),
+                    p.sonnet_model.map(str::to_string),
+                    None,
+                )
+            }
+            ApiFlavor::Gemini | ApiFlavor::VertexAi => {
+                return Err(crate::errors::usage(tf!(
+                    keys::ERROR_TOOL_VALUE_UNSUPPORTED,
+                    "value" => tools::adapter_for(tool).id()
+                )));
+            }
+        };
+        fallback = preset_model.or(fallback);
+        set_base_url(&mut endpoint, preset_url);
+        if let Some(v) = env_key.and_then(|k| std::env::var(k).ok()) {
+            endpoint.api_key = Some(v);
+        }
+    }
+    if let Some(url) = base_url {
+        set_base_url(&mut endpoint, url);
+    }
+    if api_key.is_some() {
+        endpoint.api_key = api_key;
+    }
+
+    let client = UreqClient::new(timeout);
+    match provider_api::list_models(&client, &endpoint) {
+        Ok(models) => {
+            let url = provider_api::models_request(&endpoint)
+                .map(|r| r.url)
+                .unwrap_or_else(|| endpoint.base_url.clone());
+            println!("{}", tf!(keys::PROVIDER_MODELS_TITLE, "url" => url));
+            if models.is_empty() {
+                println!("  {}", t!(keys::PROVIDER_MODELS_EMPTY));
+            }
+            for m in models {
+                println!("  - {}", m);
+            }
+            Ok(())
+        }
+        Err(report) => {
+            println!(
+                "{}",
+                tf!(
+                    keys::PROVIDER_MODELS_UNAVAILABLE,
+                    "url" => report.url,
+                    "outcome" => report.outcome.id()
+                )
+            );
+            if let Some(detail) = &report.detail {
+                println!(
+                    "    {}",
+                    tf!(keys::PROVIDER_TEST_DETAIL, "detail" => detail)
+                );
+            }
+            println!("    {}", probe_hint(report.outcome, tool));
+            let Some(model) = fallback else {
+                return Err(crate::errors::runtime(t!(
+                    keys::ERROR_PROVIDER_MODELS_NO_FALLBACK
+                )));
+            };
+            println!(
+                "{}",
+                tf!(keys::PROVIDER_MODELS_FALLBACK, "model" => model.clone())
+            );
+            println!("  - {}", model);
+            Ok(())
+        }
+    }
+}
+
+/// Point `endpoint` at another base URL; the configured key belongs to the old one, so drop it.
+fn set_base_url(endpoint: &mut Endpoint, base_url: String) {
+    if endpoint.base_url.trim_end_matches('/') != base_url.trim_end_matches('/') {
+        endpoint.api_key = None;
+    }
+    endpoint.base_url = base_url;
+}
@@ -0,0 +1,229 @@
+use super::commands::{
+    apply_changeset, err_missing_subcommand_with_help, err_unknown_subcommand_with_help,
+    err_unsupported_args_with_help, parse_apply_mode, parse_config_scope, parse_gemini_mcp_scope,
+    parse_home, parse_optional_value, parse_project_path_opt, parse_required_value,
+    print_changeset_preview, resolve_project_root, take_flag,
+};
+use prismctl_core::changeset::{ApplyMode, Change, ChangeSet};
+use prismctl_core::mcp::{self, GeminiMcpScope};
+use prismctl_core::paths::{ConfigScope, PrismctlHome, Tool};
+use prismctl_core::providers;
+use prismctl_core::qwen::{self, QwenEnvPatch};
+use prismctl_i18n::{keys, t, tf};
+use std::fs;
+
+pub fn cmd_qwen(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("qwen"));
+    };
+    args.remove(0);
+
+    match sub.as_str() {
+        "env" => cmd_qwen_env(args),
+        "mcp" => super::cmd_tool_mcp(Tool::QwenCode, args),
+        _ => Err(err_unknown_subcommand_with_help("qwen", &sub)),
+    }
+}
+
+fn cmd_qwen_env(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("qwen env"));
+    };
+    args.remove(0);
+    match sub.as_str() {
+        "set" => cmd_qwen_env_set(args),
+        _ => Err(err_unknown_subcommand_with_help("qwen env", &sub)),
+    }
+}
+
+pub(super) fn cmd_qwen_mcp(mut args: Vec<String>) -> Result<(), String> {
+    let Some(sub) = args.first().cloned() else {
+        return Err(err_missing_subcommand_with_help("qwen mcp"));
+    };
+    args.remove(0);
+    match sub.as_str() {
+        "list" => cmd_qwen_mcp_list(args),
+        "builtin" => cmd_qwen_mcp_builtin(args),
+        "add" => cmd_qwen_mcp_add(args),
+        "remove" => cmd_qwen_mcp_remove(args),
+        _ => Err(err_unknown_subcommand_with_help("qwen mcp", &sub)),
+    }
+}
+
+fn cmd_qwen_env_set(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let scope = parse_config_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+
+    let preset = match parse_optional_value(&mut args, "--provider")? {
+        Some(v) => Some(providers::qwen_preset(&v).map_err(crate::errors::usage)?),
+        None => None,
+    };
+    let mut patch = QwenEnvPatch {
+        api_key: parse_optional_value(&mut args, "--api-key")?,
+        base_url: parse_optional_value(&mut args, "--base-url")?,
+        model: parse_optional_value(&mut args, "--model")?,
+    };
+    if let Some(p) = preset {
+        patch = patch.with_preset(p);
+    }
+
+    if patch.is_empty() {
+        return Err(crate::errors::usage(t!(
+            keys::ERROR_QWEN_ENV_SET_NEEDS_ARGS
+        )));
+    }
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("qwen env set", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let qwen_dir = match scope {
+        ConfigScope::User => home.tool_root(Tool::QwenCode),
+        ConfigScope::Project | ConfigScope::Local => {
+            resolve_project_root(project_path)?.join(".qwen")
+        }
+    };
+    let env_path = qwen_dir.join(".env");
+    let existing = fs::read_to_string(&env_path).unwrap_or_default();
+    let has_api_key = patch.api_key.is_some();
+    let next = qwen::upsert_qwen_env(&existing, &patch);
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: qwen_dir });
+    cs.push(Change::WriteFile {
+        path: env_path,
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+
+    print_changeset_preview(&cs, mode);
+    println!("{}", t!(keys::QWEN_ENV_MANAGED_KEYS));
+    if let Some(p) = preset {
+        println!("{}", tf!(keys::CODEX_PROVIDER_PRESET, "provider" => p.id));
+    }
+    if has_api_key {
+        println!("{}", t!(keys::QWEN_API_KEY_HIDDEN));
+    }
+
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_qwen_mcp_list(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let scope = parse_gemini_mcp_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("qwen mcp list", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let home_dir = home.home_dir().to_string_lossy().to_string();
+    let mut cmd = std::process::Command::new("qwen");
+    cmd.args(["mcp", "list", "--scope", scope.as_flag_value()])
+        .env("HOME", &home_dir)
+        .env("USERPROFILE", &home_dir);
+    if scope == GeminiMcpScope::Project {
+        if let Some(cwd) = project_path {
+            cmd.current_dir(cwd);
+        }
+    }
+    let status = cmd
+        .status()
+        .map_err(|e| format!("执行命令失败: qwen: {}", e))?;
+    if !status.success() {
+        return Err(format!("qwen mcp list 失败（exit={}）", status));
+    }
+    Ok(())
+}
+
+fn cmd_qwen_mcp_builtin(args: Vec<String>) -> Result<(), String> {
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("qwen mcp builtin", &args));
+    }
+    println!("{}", t!(keys::QWEN_MCP_BUILTIN_TITLE));
+    for s in mcp::list_builtin_mcp_servers() {
+        match (s.url, s.command) {
+            (Some(url), _) => println!("  - {} ({}): {}", s.id, s.transport.as_flag_value(), url),
+            (None, Some(cmd)) => println!(
+                "  - {} ({}): {} {}",
+                s.id,
+                s.transport.as_flag_value(),
+                cmd,
+                s.args.join(" ")
+            ),
+            (None, None) => println!("  - {}", s.id),
+        }
+    }
+    Ok(())
+}
+
+fn cmd_qwen_mcp_add(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let scope = parse_gemini_mcp_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("qwen mcp add", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let cs =
+        mcp::plan_qwen_mcp_add(&home, scope, &name, project_path).map_err(crate::errors::usage)?;
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(danger_qwen_mcp_confirmation()));
+    }
+    apply_changeset(&cs, mode)
+}
+
+fn cmd_qwen_mcp_remove(mut args: Vec<String>) -> Result<(), String> {
+    let home = parse_home(&mut args)?;
+    let mode = parse_apply_mode(&mut args)?;
+    let yes = take_flag(&mut args, "--yes");
+    let scope = parse_gemini_mcp_scope(&mut args)?;
+    let project_path = parse_project_path_opt(&mut args)?;
+
+    let name = parse_required_value(&mut args, "--name")?;
+    if !args.is_empty() {
+        return Err(err_unsupported_args_with_help("qwen mcp remove", &args));
+    }
+
+    let home = PrismctlHome::discover(home).map_err(crate::errors::usage)?;
+    let cs = mcp::plan_qwen_mcp_remove(&home, scope, &name, project_path);
+
+    print_changeset_preview(&cs, mode);
+    if mode == ApplyMode::DryRun {
+        println!("\n{}", t!(keys::DRY_RUN_HINT_WRITE));
+        return Ok(());
+    }
+    if !yes {
+        return Err(crate::errors::usage(danger_qwen_mcp_confirmation()));
+    }
+    apply_changeset(&cs, mode)
+}
+
+pub(crate) fn danger_qwen_mcp_confirmation() -> String {
+    [
+        t!(keys::DANGER_TITLE),
+        t!(keys::DANGER_QWEN_MCP_TYPE),
+        t!(keys::DANGER_QWEN_MCP_SCOPE),
+        t!(keys::DANGER_QWEN_MCP_RISK),
+        String::new(),
+        t!(keys::DANGER_CONFIRM_NEED_YES),
+    ]
+    .join("\n")
+}
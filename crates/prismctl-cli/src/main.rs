@@ -1,5 +1,6 @@
 mod app_config;
 mod errors;
+mod http;
 mod icons;
 mod interactive;
 mod legacy;
@@ -103,6 +104,10 @@ fn dispatch_command(cmd: &str, args: Vec<String>, verbose: bool) -> Result<(), C
         "codex" => prompted::cmd_codex(args),
         "claude" => prompted::cmd_claude(args),
         "gemini" => prompted::cmd_gemini(args),
+        "opencode" => prompted::cmd_opencode(args),
+        "qwen" => prompted::cmd_qwen(args),
+        "profile" => prompted::cmd_profile(args),
+        "provider" => prompted::cmd_provider(args),
 
         _ => {
             return Err(CliFailure {
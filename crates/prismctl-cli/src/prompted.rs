@@ -2,6 +2,7 @@ use crate::interactive::utils::{prompt_confirm, prompt_required, prompt_select};
 use crate::legacy;
 use prismctl_core::installer::InstallAction;
 use prismctl_core::templates;
+use prismctl_core::tools;
 use prismctl_i18n::{keys, t, tf};
 use std::io;
 use std::io::IsTerminal;
@@ -29,22 +30,19 @@ fn non_tty_completion_error(invocation: &str, missing: &str) -> String {
 }
 
 fn tool_options() -> Vec<String> {
-    vec![
-        "codex".to_string(),
-        "claude".to_string(),
-        "gemini".to_string(),
-        "all".to_string(),
-    ]
+    let mut options: Vec<String> = tools::registry()
+        .iter()
+        .map(|t| t.id().to_string())
+        .collect();
+    options.push("all".to_string());
+    options
 }
 
 fn normalize_tool_value(raw: &str) -> Option<&'static str> {
-    match raw {
-        "codex" => Some("codex"),
-        "claude" | "claude-code" => Some("claude"),
-        "gemini" | "gemini-cli" => Some("gemini"),
-        "all" => Some("all"),
-        _ => None,
+    if raw == "all" {
+        return Some("all");
     }
+    tools::find_tool(raw).map(|t| t.id())
 }
 
 fn tool_flag_is_missing_or_incomplete(args: &[String]) -> bool {
@@ -162,7 +160,7 @@ pub fn cmd_init(mut args: Vec<String>) -> Result<(), String> {
         if tool_flag_is_missing_or_incomplete(&args) {
             return Err(non_tty_completion_error(
                 "prismctl init",
-                &t!(keys::ERROR_TOOL_FLAG_INVALID),
+                &tf!(keys::ERROR_TOOL_FLAG_INVALID, "tools" => tools::tool_choices()),
             ));
         }
         return legacy::cmd_init(args);
@@ -182,7 +180,7 @@ pub fn cmd_update(mut args: Vec<String>) -> Result<(), String> {
         if tool_flag_is_missing_or_incomplete(&args) {
             return Err(non_tty_completion_error(
                 "prismctl update",
-                &t!(keys::ERROR_TOOL_FLAG_INVALID),
+                &tf!(keys::ERROR_TOOL_FLAG_INVALID, "tools" => tools::tool_choices()),
             ));
         }
         return legacy::cmd_update(args);
@@ -202,7 +200,7 @@ pub fn cmd_install(mut args: Vec<String>) -> Result<(), String> {
         if tool_flag_is_missing_or_incomplete(&args) {
             return Err(non_tty_completion_error(
                 "prismctl install",
-                &t!(keys::ERROR_TOOL_FLAG_INVALID),
+                &tf!(keys::ERROR_TOOL_FLAG_INVALID, "tools" => tools::tool_choices()),
             ));
         }
         return legacy::cmd_install(args);
@@ -233,7 +231,7 @@ pub fn cmd_upgrade(mut args: Vec<String>) -> Result<(), String> {
         if tool_flag_is_missing_or_incomplete(&args) {
             return Err(non_tty_completion_error(
                 "prismctl upgrade",
-                &t!(keys::ERROR_TOOL_FLAG_INVALID),
+                &tf!(keys::ERROR_TOOL_FLAG_INVALID, "tools" => tools::tool_choices()),
             ));
         }
         return legacy::cmd_upgrade(args);
@@ -317,24 +315,12 @@ pub fn cmd_codex(mut args: Vec<String>) -> Result<(), String> {
         args.push(choice.to_string());
     }
 
-    // Parameter completion for: `prismctl codex agent use --name <...> [--apply] [--yes]`
+    // Parameter completion for: `prismctl codex agent use --name <...> [--apply]`
     if args.first().map(|s| s.as_str()) == Some("agent")
         && args.get(1).map(|s| s.as_str()) == Some("use")
+        && is_interactive_tty()
     {
-        if !is_interactive_tty() {
-            return legacy::cmd_codex(args);
-        }
-
         ensure_codex_agent_name(&mut args)?;
-
-        if args.iter().any(|a| a == "--apply") && !args.iter().any(|a| a == "--yes") {
-            let warning = legacy::danger_codex_agent_use_confirmation();
-            if ensure_yes_confirmation(&warning)? {
-                args.push("--yes".to_string());
-            } else {
-                return Err(t!(keys::ACTION_CANCEL));
-            }
-        }
     }
 
     legacy::cmd_codex(args)
@@ -350,6 +336,26 @@ pub fn cmd_gemini(args: Vec<String>) -> Result<(), String> {
     legacy::cmd_gemini(args)
 }
 
+pub fn cmd_opencode(args: Vec<String>) -> Result<(), String> {
+    // opencode 子命令没有必填参数需要补全；直接透传。
+    legacy::cmd_opencode(args)
+}
+
+pub fn cmd_qwen(args: Vec<String>) -> Result<(), String> {
+    // qwen 子命令与 gemini 一致：没有必填参数需要补全；直接透传。
+    legacy::cmd_qwen(args)
+}
+
+pub fn cmd_profile(args: Vec<String>) -> Result<(), String> {
+    // profile 名称可通过 --name 或位置参数给出；无需交互补全，直接透传。
+    legacy::cmd_profile(args)
+}
+
+pub fn cmd_provider(args: Vec<String>) -> Result<(), String> {
+    // provider test 只读配置并发起请求，没有必填参数；直接透传。
+    legacy::cmd_provider(args)
+}
+
 pub fn cmd_project(args: Vec<String>) -> Result<(), String> {
     // project init 已有默认 path=current_dir；这里仅做缺少子命令时的 TTY 友好补全。
     if args.is_empty() {
@@ -1,6 +1,7 @@
 use crate::interactive::utils::{prompt_line, validate_api_key_format};
 use crate::legacy;
-use prismctl_core::providers;
+use prismctl_core::paths::PrismctlHome;
+use prismctl_core::providers::{self, ProviderCatalog};
 use prismctl_i18n::{current_locale, keys, t, tf, Locale};
 use std::io;
 use std::io::IsTerminal;
@@ -110,9 +111,12 @@ pub fn quick_init(mut args: Vec<String>) -> Result<(), String> {
     let tool = tool.unwrap_or_else(|| "all".to_string());
     let lang = lang.unwrap_or_else(|| current_locale().as_str().to_string());
 
+    let prismctl_home = PrismctlHome::discover(home.clone()).map_err(crate::errors::usage)?;
+    let catalog = legacy::load_provider_catalog(&prismctl_home)?;
+
     // Provider selection is mainly for Codex; keep it optional.
     if provider.is_none() && !silent {
-        provider = prompt_provider_if_tty()?;
+        provider = prompt_provider_if_tty(&catalog)?;
     }
 
     // API key is only required when the user explicitly asks for provider-based config.
@@ -156,7 +160,7 @@ pub fn quick_init(mut args: Vec<String>) -> Result<(), String> {
                 }
                 if let Some(p) = &provider {
                     // Validate provider early in hybrid mode.
-                    providers::parse_provider_id(p).map_err(crate::errors::usage)?;
+                    catalog.parse_provider_id(p).map_err(crate::errors::usage)?;
                     codex_args.push("--provider".to_string());
                     codex_args.push(p.clone());
                     codex_args.push("--default".to_string());
@@ -182,11 +186,9 @@ pub fn quick_init(mut args: Vec<String>) -> Result<(), String> {
                     claude_args.push("--home".to_string());
                     claude_args.push(h.display().to_string());
                 }
-                if let Some(p) = &provider {
-                    if let Some(url) = map_provider_to_base_url(p) {
-                        claude_args.push("--base-url".to_string());
-                        claude_args.push(url.to_string());
-                    }
+                if let Some(id) = provider.as_deref().and_then(claude_provider_for) {
+                    claude_args.push("--provider".to_string());
+                    claude_args.push(id.to_string());
                 }
                 if let Some(k) = &api_key {
                     claude_args.push("--auth-token".to_string());
@@ -225,13 +227,13 @@ pub fn quick_init(mut args: Vec<String>) -> Result<(), String> {
     Ok(())
 }
 
-fn prompt_provider_if_tty() -> Result<Option<String>, String> {
+fn prompt_provider_if_tty(catalog: &ProviderCatalog) -> Result<Option<String>, String> {
     if !is_interactive_tty() {
         return Ok(None);
     }
     println!("\n{}", t!(keys::QUICK_PROVIDER_PRESETS_TITLE));
-    for (i, id) in providers::list_provider_ids().iter().enumerate() {
-        println!("  {}) {}", i + 1, id);
+    for (i, p) in catalog.presets().iter().enumerate() {
+        println!("  {}) {} ({})", i + 1, p.id, p.name);
     }
     println!("  {}", t!(keys::QUICK_SKIP));
     let prompt = format!("{} ", t!(keys::QUICK_PROMPT));
@@ -242,8 +244,8 @@ fn prompt_provider_if_tty() -> Result<Option<String>, String> {
     let idx: usize = choice
         .parse()
         .map_err(|_| crate::errors::usage(tf!(keys::ERROR_INVALID_CHOICE, "choice" => choice)))?;
-    let ids = providers::list_provider_ids();
-    let Some(id) = ids.get(idx - 1) else {
+    let ids = catalog.list_provider_ids();
+    let Some(id) = idx.checked_sub(1).and_then(|i| ids.get(i)) else {
         return Err(crate::errors::usage(
             tf!(keys::ERROR_INVALID_CHOICE, "choice" => choice),
         ));
@@ -292,15 +294,9 @@ fn help_quick_init() -> String {
     }
 }
 
-fn map_provider_to_base_url(provider: &str) -> Option<&'static str> {
-    match provider.trim().to_ascii_lowercase().as_str() {
-        "openrouter" => Some("https://openrouter.ai/api/v1"),
-        "deepseek" => Some("https://api.deepseek.com/v1"),
-        "ollama" => Some("http://localhost:11434/v1"),
-        "volcengine" => Some("https://ark.cn-beijing.volces.com/api/v3"),
-        "siliconflow" => Some("https://api.siliconflow.cn/v1"),
-        _ => None,
-    }
+/// Claude preset matching a Codex provider id, if the vendor has an Anthropic-compatible endpoint.
+fn claude_provider_for(provider: &str) -> Option<&'static str> {
+    providers::claude_preset(provider).ok().map(|p| p.id)
 }
 
 #[cfg(test)]
@@ -308,19 +304,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn maps_known_provider_to_base_url() {
-        assert_eq!(
-            map_provider_to_base_url("openrouter"),
-            Some("https://openrouter.ai/api/v1")
-        );
-        assert_eq!(
-            map_provider_to_base_url("DeepSeek"),
-            Some("https://api.deepseek.com/v1")
-        );
-        assert_eq!(
-            map_provider_to_base_url("ollama"),
-            Some("http://localhost:11434/v1")
-        );
-        assert_eq!(map_provider_to_base_url("unknown"), None);
+    fn maps_known_provider_to_claude_preset() {
+        assert_eq!(claude_provider_for("openrouter"), Some("openrouter"));
+        assert_eq!(claude_provider_for("DeepSeek"), Some("deepseek"));
+        assert_eq!(claude_provider_for("siliconflow"), Some("siliconflow"));
+        assert_eq!(claude_provider_for("ollama"), None);
+        assert_eq!(claude_provider_for("unknown"), None);
     }
 }
@@ -0,0 +1,38 @@
+#!/usr/bin/env sh
+# Prismctl hook (PostToolUse: Edit|MultiEdit|Write): format the edited file with the
+# formatter for its language, when that formatter is installed. Never blocks Claude Code.
+input=$(cat)
+file=$(printf '%s' "$input" | sed -n 's/.*"file_path"[[:space:]]*:[[:space:]]*"\([^"]*\)".*/\1/p' | head -n 1)
+[ -n "$file" ] && [ -f "$file" ] || exit 0
+
+has() { command -v "$1" >/dev/null 2>&1; }
+
+# Edition from the nearest Cargo.toml that sets one (a crate, or its workspace root).
+rust_edition() {
+  dir=$(cd "$(dirname "$1")" && pwd) || return
+  while :; do
+    if [ -f "$dir/Cargo.toml" ]; then
+      edition=$(sed -n 's/^[[:space:]]*edition[[:space:]]*=[[:space:]]*"\([0-9]*\)".*/\1/p' "$dir/Cargo.toml" | head -n 1)
+      [ -n "$edition" ] && { printf '%s' "$edition"; return; }
+    fi
+    parent=$(dirname "$dir")
+    [ "$parent" = "$dir" ] && return
+    dir=$parent
+  done
+}
+
+case "$file" in
+  *.rs)
+    if has rustfmt; then
+      edition=$(rust_edition "$file")
+      rustfmt ${edition:+--edition "$edition"} "$file"
+    fi ;;
+  *.go) has gofmt && gofmt -w "$file" ;;
+  *.py)
+    if has ruff; then ruff format --quiet "$file"
+    elif has black; then black --quiet "$file"
+    fi ;;
+  *.js|*.jsx|*.ts|*.tsx|*.json|*.css|*.scss|*.md|*.yaml|*.yml)
+    has prettier && prettier --write --log-level warn "$file" ;;
+esac
+exit 0
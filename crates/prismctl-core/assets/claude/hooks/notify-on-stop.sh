@@ -0,0 +1,14 @@
+#!/usr/bin/env sh
+# Prismctl hook (Stop): desktop notification when Claude Code finishes responding.
+cat >/dev/null
+title="Claude Code"
+message="Claude has finished and is waiting for you."
+
+if command -v osascript >/dev/null 2>&1; then
+  osascript -e "display notification \"$message\" with title \"$title\"" >/dev/null 2>&1
+elif command -v notify-send >/dev/null 2>&1; then
+  notify-send "$title" "$message" >/dev/null 2>&1
+elif command -v powershell.exe >/dev/null 2>&1; then
+  powershell.exe -NoProfile -Command "[console]::beep(880,200)" >/dev/null 2>&1
+fi
+exit 0
@@ -0,0 +1,13 @@
+#!/usr/bin/env sh
+# Prismctl hook (PreToolUse: Edit|MultiEdit|Write): block edits to .env files.
+# Exit code 2 blocks the tool call and shows stderr to Claude.
+input=$(cat)
+file=$(printf '%s' "$input" | sed -n 's/.*"file_path"[[:space:]]*:[[:space:]]*"\([^"]*\)".*/\1/p' | head -n 1)
+[ -n "$file" ] || exit 0
+
+case "$(basename "$file")" in
+  .env|.env.*)
+    echo "Blocked by prismctl protect-env hook: $file may contain secrets; edit it manually." >&2
+    exit 2 ;;
+esac
+exit 0
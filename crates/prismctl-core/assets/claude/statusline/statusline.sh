@@ -0,0 +1,54 @@
+#!/usr/bin/env sh
+# Prismctl status line for Claude Code.
+# Usage: statusline.sh [segments]   (comma-separated: model,branch,cwd,context)
+# Claude Code passes the session JSON on stdin and shows the first line printed.
+segments=${1:-model,branch,cwd}
+input=$(cat)
+
+json_str() {
+  printf '%s' "$input" | sed -n "s/.*\"$1\"[[:space:]]*:[[:space:]]*\"\([^\"]*\)\".*/\1/p" | head -n 1
+}
+
+dir=$(json_str current_dir)
+[ -n "$dir" ] || dir=$(json_str cwd)
+[ -n "$dir" ] || dir=$(pwd)
+
+segment() {
+  case "$1" in
+    model)
+      json_str display_name ;;
+    cwd)
+      case "$dir" in
+        "$HOME") printf '~' ;;
+        "$HOME"/*) printf '~/%s' "${dir#"$HOME"/}" ;;
+        *) printf '%s' "$dir" ;;
+      esac ;;
+    branch)
+      branch=$(git -C "$dir" branch --show-current 2>/dev/null)
+      [ -n "$branch" ] && printf 'git:%s' "$branch" ;;
+    context)
+      transcript=$(json_str transcript_path)
+      [ -f "$transcript" ] || return 0
+      usage=$(tail -n 200 "$transcript" | grep '"input_tokens"' | tail -n 1)
+      [ -n "$usage" ] || return 0
+      used=0
+      for key in input_tokens cache_creation_input_tokens cache_read_input_tokens; do
+        n=$(printf '%s' "$usage" | sed -n "s/.*\"$key\"[[:space:]]*:[[:space:]]*\([0-9][0-9]*\).*/\1/p" | head -n 1)
+        used=$(( used + ${n:-0} ))
+      done
+      limit=${PRISMCTL_STATUSLINE_CONTEXT_LIMIT:-200000}
+      printf 'ctx:%s%%' $(( used * 100 / limit )) ;;
+  esac
+}
+
+out=""
+old_ifs=$IFS
+IFS=,
+for name in $segments; do
+  IFS=$old_ifs
+  value=$(segment "$name")
+  if [ -n "$value" ]; then
+    if [ -n "$out" ]; then out="$out | $value"; else out=$value; fi
+  fi
+done
+printf '%s\n' "$out"
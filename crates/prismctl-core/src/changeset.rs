@@ -20,6 +20,9 @@ pub enum Change {
     RemoveDirAll {
         path: PathBuf,
     },
+    RemoveFile {
+        path: PathBuf,
+    },
     WriteFile {
         path: PathBuf,
         bytes: Vec<u8>,
@@ -38,6 +41,7 @@ impl fmt::Display for Change {
         match self {
             Change::CreateDirAll { path } => write!(f, "mkdir -p {}", quote_path(path)),
             Change::RemoveDirAll { path } => write!(f, "rm -rf {}", quote_path(path)),
+            Change::RemoveFile { path } => write!(f, "rm {}", quote_path(path)),
             Change::WriteFile {
                 path, overwrite, ..
             } => {
@@ -177,6 +181,9 @@ impl ChangeSet {
                     Change::RemoveDirAll { path } => fs
                         .remove_dir_all(path)
                         .map_err(|e| format!("删除目录失败: {}: {}", path.display(), e))?,
+                    Change::RemoveFile { path } => fs
+                        .remove_file(path)
+                        .map_err(|e| format!("删除文件失败: {}: {}", path.display(), e))?,
                     Change::WriteFile {
                         path,
                         bytes,
@@ -199,11 +206,26 @@ impl ChangeSet {
 
         Ok(())
     }
+
+    /// Test helper: the text planned for the `WriteFile` whose path ends with `suffix`.
+    #[cfg(test)]
+    pub(crate) fn written(&self, suffix: &str) -> String {
+        self.changes
+            .iter()
+            .find_map(|c| match c {
+                Change::WriteFile { path, bytes, .. } if path.ends_with(suffix) => {
+                    Some(String::from_utf8(bytes.clone()).expect("utf8"))
+                }
+                _ => None,
+            })
+            .expect("file planned")
+    }
 }
 
 pub trait FileSystem {
     fn create_dir_all(&self, path: &Path) -> io::Result<()>;
     fn remove_dir_all(&self, path: &Path) -> io::Result<()>;
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
     fn write_file(&self, path: &Path, bytes: &[u8], overwrite: bool) -> io::Result<()>;
     fn path_exists(&self, path: &Path) -> bool;
 }
@@ -223,6 +245,13 @@ impl FileSystem for RealFileSystem {
         fs::remove_dir_all(path)
     }
 
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        if !path.exists() {
+            return Ok(());
+        }
+        fs::remove_file(path)
+    }
+
     fn write_file(&self, path: &Path, bytes: &[u8], overwrite: bool) -> io::Result<()> {
         if !overwrite && path.exists() {
             return Ok(());
@@ -283,6 +312,7 @@ mod tests {
     struct RecordingFileSystem {
         created_dirs: std::sync::Mutex<HashSet<PathBuf>>,
         removed_dirs: std::sync::Mutex<HashSet<PathBuf>>,
+        removed_files: std::sync::Mutex<HashSet<PathBuf>>,
         written_files: std::sync::Mutex<HashSet<PathBuf>>,
     }
 
@@ -291,6 +321,7 @@ mod tests {
             Self {
                 created_dirs: std::sync::Mutex::new(HashSet::new()),
                 removed_dirs: std::sync::Mutex::new(HashSet::new()),
+                removed_files: std::sync::Mutex::new(HashSet::new()),
                 written_files: std::sync::Mutex::new(HashSet::new()),
             }
         }
@@ -313,6 +344,14 @@ mod tests {
             Ok(())
         }
 
+        fn remove_file(&self, path: &Path) -> io::Result<()> {
+            self.removed_files
+                .lock()
+                .expect("lock")
+                .insert(path.to_path_buf());
+            Ok(())
+        }
+
         fn write_file(&self, path: &Path, _bytes: &[u8], _overwrite: bool) -> io::Result<()> {
             self.written_files
                 .lock()
@@ -358,6 +397,9 @@ mod tests {
         cs.push(Change::RemoveDirAll {
             path: unique_path(),
         });
+        cs.push(Change::RemoveFile {
+            path: unique_path().join("b.txt"),
+        });
         cs.push(Change::WriteFile {
             path: unique_path().join("a.txt"),
             bytes: b"hello".to_vec(),
@@ -371,6 +413,7 @@ mod tests {
 
         assert!(fs.created_dirs.lock().expect("lock").is_empty());
         assert!(fs.removed_dirs.lock().expect("lock").is_empty());
+        assert!(fs.removed_files.lock().expect("lock").is_empty());
         assert!(fs.written_files.lock().expect("lock").is_empty());
     }
 
@@ -401,6 +444,22 @@ mod tests {
         let _ = fs::remove_dir_all(&root);
     }
 
+    #[test]
+    fn apply_routes_each_removal_to_its_own_call() {
+        let dir = unique_path();
+        let file = dir.join("a.txt");
+        let mut cs = ChangeSet::new();
+        cs.push(Change::RemoveFile { path: file.clone() });
+        cs.push(Change::RemoveDirAll { path: dir.clone() });
+
+        let fs = RecordingFileSystem::new();
+        cs.apply(ApplyMode::Apply, &fs, &NoopRunner).expect("apply");
+
+        assert!(fs.removed_files.lock().expect("lock").contains(&file));
+        assert!(!fs.removed_dirs.lock().expect("lock").contains(&file));
+        assert!(fs.removed_dirs.lock().expect("lock").contains(&dir));
+    }
+
     #[test]
     fn apply_removes_directory_tree() {
         let root = unique_path();
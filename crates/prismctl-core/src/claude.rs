@@ -1,4 +1,45 @@
 use crate::json_text;
+use crate::paths::{ConfigScope, PrismctlHome};
+use crate::providers::ClaudeProviderPreset;
+use crate::redact::mask_secret;
+use std::path::{Path, PathBuf};
+
+/// The Claude Code `settings.json` a scope selects: `~/.claude/settings.json`, the project's
+/// shared `.claude/settings.json`, or its personal `.claude/settings.local.json`.
+pub fn claude_settings_path(
+    scope: ConfigScope,
+    home: &PrismctlHome,
+    project_root: &Path,
+) -> PathBuf {
+    match scope {
+        ConfigScope::User => home.claude_settings_path(),
+        ConfigScope::Project => project_root.join(".claude").join("settings.json"),
+        ConfigScope::Local => project_root.join(".claude").join("settings.local.json"),
+    }
+}
+
+/// Env keys managed through [`ClaudeEnvPatch`], in the order they are written.
+pub const CLAUDE_ENV_KEYS: &[&str] = &[
+    "ANTHROPIC_AUTH_TOKEN",
+    "ANTHROPIC_BASE_URL",
+    "ANTHROPIC_MODEL",
+    "ANTHROPIC_DEFAULT_HAIKU_MODEL",
+    "ANTHROPIC_DEFAULT_SONNET_MODEL",
+    "ANTHROPIC_DEFAULT_OPUS_MODEL",
+    "ANTHROPIC_SMALL_FAST_MODEL",
+    "API_TIMEOUT_MS",
+    "CLAUDE_CODE_MAX_OUTPUT_TOKENS",
+    "DISABLE_TELEMETRY",
+];
+
+/// The model-selection subset of [`CLAUDE_ENV_KEYS`] that a provider preset owns.
+pub const CLAUDE_MODEL_ENV_KEYS: &[&str] = &[
+    "ANTHROPIC_MODEL",
+    "ANTHROPIC_DEFAULT_HAIKU_MODEL",
+    "ANTHROPIC_DEFAULT_SONNET_MODEL",
+    "ANTHROPIC_DEFAULT_OPUS_MODEL",
+    "ANTHROPIC_SMALL_FAST_MODEL",
+];
 
 #[derive(Debug, Default, Clone)]
 /// A patch describing which Claude Code environment variables should be upserted.
@@ -9,6 +50,98 @@ pub struct ClaudeEnvPatch {
     pub default_haiku_model: Option<String>,
     pub default_sonnet_model: Option<String>,
     pub default_opus_model: Option<String>,
+    pub small_fast_model: Option<String>,
+    /// `API_TIMEOUT_MS` (positive integer).
+    pub api_timeout_ms: Option<String>,
+    /// `CLAUDE_CODE_MAX_OUTPUT_TOKENS` (positive integer).
+    pub max_output_tokens: Option<String>,
+    /// `DISABLE_TELEMETRY` (`1` to opt out).
+    pub disable_telemetry: Option<String>,
+}
+
+impl ClaudeEnvPatch {
+    pub fn is_empty(&self) -> bool {
+        self.fields().iter().all(|(_, v)| v.is_none())
+    }
+
+    /// `(env key, value)` pairs in [`CLAUDE_ENV_KEYS`] order.
+    pub fn fields(&self) -> [(&'static str, &Option<String>); 10] {
+        [
+            (CLAUDE_ENV_KEYS[0], &self.auth_token),
+            (CLAUDE_ENV_KEYS[1], &self.base_url),
+            (CLAUDE_ENV_KEYS[2], &self.model),
+            (CLAUDE_ENV_KEYS[3], &self.default_haiku_model),
+            (CLAUDE_ENV_KEYS[4], &self.default_sonnet_model),
+            (CLAUDE_ENV_KEYS[5], &self.default_opus_model),
+            (CLAUDE_ENV_KEYS[6], &self.small_fast_model),
+            (CLAUDE_ENV_KEYS[7], &self.api_timeout_ms),
+            (CLAUDE_ENV_KEYS[8], &self.max_output_tokens),
+            (CLAUDE_ENV_KEYS[9], &self.disable_telemetry),
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [(&'static str, &mut Option<String>); 10] {
+        [
+            (CLAUDE_ENV_KEYS[0], &mut self.auth_token),
+            (CLAUDE_ENV_KEYS[1], &mut self.base_url),
+            (CLAUDE_ENV_KEYS[2], &mut self.model),
+            (CLAUDE_ENV_KEYS[3], &mut self.default_haiku_model),
+            (CLAUDE_ENV_KEYS[4], &mut self.default_sonnet_model),
+            (CLAUDE_ENV_KEYS[5], &mut self.default_opus_model),
+            (CLAUDE_ENV_KEYS[6], &mut self.small_fast_model),
+            (CLAUDE_ENV_KEYS[7], &mut self.api_timeout_ms),
+            (CLAUDE_ENV_KEYS[8], &mut self.max_output_tokens),
+            (CLAUDE_ENV_KEYS[9], &mut self.disable_telemetry),
+        ]
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for (key, value) in [
+            ("API_TIMEOUT_MS", &self.api_timeout_ms),
+            ("CLAUDE_CODE_MAX_OUTPUT_TOKENS", &self.max_output_tokens),
+        ] {
+            if let Some(v) = value {
+                if !v.parse::<u64>().is_ok_and(|n| n > 0) {
+                    return Err(format!("{key} 必须是正整数: {v}"));
+                }
+            }
+        }
+        if let Some(v) = &self.disable_telemetry {
+            if v != "0" && v != "1" {
+                return Err(format!("DISABLE_TELEMETRY 只能为 0 或 1: {v}"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse credentials in the project's shared `settings.json`, which is usually committed.
+    pub fn check_scope(&self, scope: ConfigScope) -> Result<(), String> {
+        if scope == ConfigScope::Project && self.auth_token.is_some() {
+            return Err(
+                "ANTHROPIC_AUTH_TOKEN 不能写入项目共享的 .claude/settings.json（通常会提交到仓库），请改用 --scope local"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Fill base URL and model mappings from a preset unless given explicitly.
+    ///
+    /// `ANTHROPIC_MODEL` defaults to the preset's sonnet model.
+    pub fn with_preset(mut self, preset: ClaudeProviderPreset) -> Self {
+        self.base_url
+            .get_or_insert_with(|| preset.base_url.to_string());
+        let fill = |slot: &mut Option<String>, value: Option<&str>| {
+            if slot.is_none() {
+                *slot = value.map(str::to_string);
+            }
+        };
+        fill(&mut self.model, preset.sonnet_model);
+        fill(&mut self.default_opus_model, preset.opus_model);
+        fill(&mut self.default_sonnet_model, preset.sonnet_model);
+        fill(&mut self.default_haiku_model, preset.haiku_model);
+        self
+    }
 }
 
 /// Apply a `ClaudeEnvPatch` to the given `settings.json` content (as text).
@@ -16,28 +149,92 @@ pub fn apply_claude_env_patch_to_settings_json(
     content: &str,
     patch: &ClaudeEnvPatch,
 ) -> Result<String, String> {
+    patch.validate()?;
     let mut out = content.to_string();
-
-    if let Some(v) = &patch.auth_token {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_AUTH_TOKEN", v)?;
-    }
-    if let Some(v) = &patch.base_url {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_BASE_URL", v)?;
+    for (key, value) in patch.fields() {
+        if let Some(v) = value {
+            out = json_text::upsert_string_map_entry(&out, "env", key, v)?;
+        }
     }
-    if let Some(v) = &patch.model {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_MODEL", v)?;
+    Ok(out)
+}
+
+/// Apply a patch built with [`ClaudeEnvPatch::with_preset`]: model keys the patch leaves unset
+/// are removed first, so models from a previously applied preset don't leak into the new one.
+pub fn apply_claude_env_preset_to_settings_json(
+    content: &str,
+    patch: &ClaudeEnvPatch,
+) -> Result<String, String> {
+    patch.validate()?;
+    let stale: Vec<String> = patch
+        .fields()
+        .into_iter()
+        .filter(|(key, value)| value.is_none() && CLAUDE_MODEL_ENV_KEYS.contains(key))
+        .map(|(key, _)| key.to_string())
+        .collect();
+    let (out, _) = unset_claude_env_keys(content, &stale)?;
+    apply_claude_env_patch_to_settings_json(&out, patch)
+}
+
+/// Read the managed env keys currently set in `settings.json` (the inverse of
+/// [`apply_claude_env_patch_to_settings_json`]).
+pub fn read_claude_env_patch(content: &str) -> Result<ClaudeEnvPatch, String> {
+    let mut patch = ClaudeEnvPatch::default();
+    for (key, slot) in patch.fields_mut() {
+        *slot = json_text::string_at_path(content, &["env", key])?;
     }
-    if let Some(v) = &patch.default_haiku_model {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_DEFAULT_HAIKU_MODEL", v)?;
+    Ok(patch)
+}
+
+/// All string entries of `env` in `settings.json`, sorted by key, with secrets masked.
+pub fn read_claude_env_redacted(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut out = Vec::new();
+    for key in json_text::object_keys_at_path(content, &["env"])? {
+        if let Some(value) = json_text::string_at_path(content, &["env", &key])? {
+            let value = if is_secret_env_key(&key) {
+                mask_secret(&value)
+            } else {
+                value
+            };
+            out.push((key, value));
+        }
     }
-    if let Some(v) = &patch.default_sonnet_model {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_DEFAULT_SONNET_MODEL", v)?;
+    Ok(out)
+}
+
+fn is_secret_env_key(key: &str) -> bool {
+    key.ends_with("_TOKEN") || key.ends_with("_KEY") || key.contains("SECRET")
+}
+
+/// Remove `keys` from `env`, returning the new content and the keys that were not set.
+///
+/// An `env` object left empty is removed.
+pub fn unset_claude_env_keys(
+    content: &str,
+    keys: &[String],
+) -> Result<(String, Vec<String>), String> {
+    let mut out = content.to_string();
+    let mut missing = Vec::new();
+    for key in keys {
+        match json_text::remove_value_at_path(&out, &["env", key])? {
+            Some(next) => out = next,
+            None => missing.push(key.clone()),
+        }
     }
-    if let Some(v) = &patch.default_opus_model {
-        out = json_text::upsert_string_map_entry(&out, "env", "ANTHROPIC_DEFAULT_OPUS_MODEL", v)?;
+    if json_text::object_keys_at_path(&out, &["env"])?.is_empty() {
+        if let Some(next) = json_text::remove_value_at_path(&out, &["env"])? {
+            out = next;
+        }
     }
+    Ok((out, missing))
+}
 
-    Ok(out)
+/// Remove every [`CLAUDE_ENV_KEYS`] entry (back to the official API with defaults); other
+/// `env` entries are kept. `None` when none of them is set.
+pub fn reset_claude_env(content: &str) -> Result<Option<String>, String> {
+    let keys: Vec<String> = CLAUDE_ENV_KEYS.iter().map(|k| k.to_string()).collect();
+    let (out, missing) = unset_claude_env_keys(content, &keys)?;
+    Ok((missing.len() < keys.len()).then_some(out))
 }
 
 /// Set `outputStyle` in Claude Code `settings.json` content.
@@ -52,6 +249,24 @@ pub fn set_claude_output_style_in_settings_json(
 mod tests {
     use super::*;
 
+    #[test]
+    fn settings_scope_paths() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-home"));
+        let project = Path::new("/work/app");
+        assert_eq!(
+            claude_settings_path(ConfigScope::User, &home, project),
+            home.claude_settings_path()
+        );
+        assert_eq!(
+            claude_settings_path(ConfigScope::Project, &home, project),
+            PathBuf::from("/work/app/.claude/settings.json")
+        );
+        assert_eq!(
+            claude_settings_path(ConfigScope::Local, &home, project),
+            PathBuf::from("/work/app/.claude/settings.local.json")
+        );
+    }
+
     #[test]
     fn env_patch_sets_keys() {
         let patch = ClaudeEnvPatch {
@@ -61,11 +276,103 @@ mod tests {
             default_haiku_model: None,
             default_sonnet_model: None,
             default_opus_model: None,
+            ..Default::default()
         };
         let out = apply_claude_env_patch_to_settings_json("", &patch).expect("ok");
         assert!(out.contains("\"ANTHROPIC_AUTH_TOKEN\": \"t\""));
         assert!(out.contains("\"ANTHROPIC_BASE_URL\": \"u\""));
         assert!(out.contains("\"ANTHROPIC_MODEL\": \"m\""));
+
+        let read = read_claude_env_patch(&out).expect("read");
+        assert_eq!(read.base_url.as_deref(), Some("u"));
+        assert_eq!(read.default_opus_model, None);
+    }
+
+    #[test]
+    fn preset_fills_missing_values_only() {
+        let patch = ClaudeEnvPatch {
+            default_haiku_model: Some("my-haiku".into()),
+            ..Default::default()
+        }
+        .with_preset(crate::providers::claude_preset("zhipu").unwrap());
+        let out = apply_claude_env_patch_to_settings_json("", &patch).expect("ok");
+        assert!(out.contains("\"ANTHROPIC_BASE_URL\": \"https://open.bigmodel.cn/api/anthropic\""));
+        assert!(out.contains("\"ANTHROPIC_MODEL\": \"glm-4.6\""));
+        assert!(out.contains("\"ANTHROPIC_DEFAULT_HAIKU_MODEL\": \"my-haiku\""));
+        assert!(!out.contains("ANTHROPIC_AUTH_TOKEN"));
+    }
+
+    #[test]
+    fn switching_preset_clears_stale_models() {
+        let preset = |id| crate::providers::claude_preset(id).unwrap();
+        let deepseek = ClaudeEnvPatch {
+            small_fast_model: Some("deepseek-chat".into()),
+            api_timeout_ms: Some("600000".into()),
+            ..Default::default()
+        }
+        .with_preset(preset("deepseek"));
+        let out = apply_claude_env_preset_to_settings_json("", &deepseek).expect("deepseek");
+        assert!(out.contains("\"ANTHROPIC_DEFAULT_OPUS_MODEL\": \"deepseek-reasoner\""));
+
+        let local = ClaudeEnvPatch::default().with_preset(preset("local"));
+        let out = apply_claude_env_preset_to_settings_json(&out, &local).expect("local");
+        let read = read_claude_env_patch(&out).expect("read");
+        assert_eq!(read.base_url.as_deref(), Some("http://127.0.0.1:3456"));
+        for key in CLAUDE_MODEL_ENV_KEYS {
+            assert!(!out.contains(key), "{key} should be cleared: {out}");
+        }
+        assert_eq!(read.api_timeout_ms.as_deref(), Some("600000"));
+    }
+
+    #[test]
+    fn env_show_unset_and_reset() {
+        let patch = ClaudeEnvPatch {
+            auth_token: Some("sk-ant-1234567890".into()),
+            base_url: Some("https://gw.example.com".into()),
+            api_timeout_ms: Some("600000".into()),
+            disable_telemetry: Some("1".into()),
+            ..Default::default()
+        };
+        let content = r#"{"env": {"HTTPS_PROXY": "http://proxy:8080"}}"#;
+        let out = apply_claude_env_patch_to_settings_json(content, &patch).expect("ok");
+        let shown = read_claude_env_redacted(&out).expect("show");
+        assert!(shown.contains(&("ANTHROPIC_AUTH_TOKEN".into(), "****7890".into())));
+        assert!(shown.contains(&("API_TIMEOUT_MS".into(), "600000".into())));
+
+        let (out, missing) =
+            unset_claude_env_keys(&out, &["ANTHROPIC_BASE_URL".into(), "NOPE".into()])
+                .expect("unset");
+        assert_eq!(missing, vec!["NOPE"]);
+        assert!(!out.contains("ANTHROPIC_BASE_URL"));
+
+        let out = reset_claude_env(&out).expect("reset").expect("changed");
+        assert!(out.contains("HTTPS_PROXY") && !out.contains("ANTHROPIC_AUTH_TOKEN"));
+        assert_eq!(reset_claude_env(&out).expect("noop"), None);
+
+        let bad = ClaudeEnvPatch {
+            max_output_tokens: Some("lots".into()),
+            ..Default::default()
+        };
+        assert!(apply_claude_env_patch_to_settings_json("", &bad).is_err());
+    }
+
+    #[test]
+    fn auth_token_stays_out_of_project_settings() {
+        let patch = ClaudeEnvPatch {
+            auth_token: Some("t".into()),
+            ..Default::default()
+        };
+        let err = patch
+            .check_scope(ConfigScope::Project)
+            .expect_err("project");
+        assert!(err.contains("--scope local"));
+        assert!(patch.check_scope(ConfigScope::Local).is_ok());
+        assert!(patch.check_scope(ConfigScope::User).is_ok());
+        let model_only = ClaudeEnvPatch {
+            model: Some("m".into()),
+            ..Default::default()
+        };
+        assert!(model_only.check_scope(ConfigScope::Project).is_ok());
     }
 
     #[test]
@@ -0,0 +1,218 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::claude_permissions::{split_tool_list, validate_tool_name};
+use crate::custom_prompt::{parse_prompt, render_prompt, validate_prompt_name};
+use crate::paths::{PrismctlHome, Tool};
+use crate::templates::{self, TemplateLang};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Values accepted for a subagent's `model`; `inherit` reuses the main conversation's model.
+pub const AGENT_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A subagent definition found under an `agents/` directory.
+pub struct ClaudeAgent {
+    pub name: String,
+    pub path: PathBuf,
+    pub model: Option<String>,
+    pub tools: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Frontmatter written on top of a subagent template.
+pub struct AgentOverrides {
+    pub model: Option<String>,
+    /// Comma-separated tool names; without it the subagent inherits every tool.
+    pub tools: Option<String>,
+}
+
+impl AgentOverrides {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(model) = &self.model {
+            if !AGENT_MODELS.contains(&model.as_str()) {
+                return Err(format!(
+                    "不支持的 model: {model}（可选: {}）",
+                    AGENT_MODELS.join(", ")
+                ));
+            }
+        }
+        if let Some(tools) = &self.tools {
+            for tool in split_tool_list(tools) {
+                validate_tool_name(tool)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply(&self, fields: &mut Vec<(String, String)>) {
+        for (key, value) in [("tools", &self.tools), ("model", &self.model)] {
+            let Some(value) = value else {
+                continue;
+            };
+            match fields.iter_mut().find(|(k, _)| k == key) {
+                Some((_, v)) => *v = value.clone(),
+                None => fields.push((key.to_string(), value.clone())),
+            }
+        }
+    }
+}
+
+/// User-scope `~/.claude/agents`, or `<project>/.claude/agents` when `project_root` is set.
+pub fn agents_dir(home: &PrismctlHome, project_root: Option<&Path>) -> PathBuf {
+    match project_root {
+        Some(root) => root.join(".claude").join("agents"),
+        None => home.tool_root(Tool::ClaudeCode).join("agents"),
+    }
+}
+
+/// Every `*.md` subagent under `dir`, including nested ones like `prismctl/plan/`, sorted by name.
+pub fn list_claude_agents(dir: &Path) -> Vec<ClaudeAgent> {
+    let mut out = Vec::new();
+    collect_agents(dir, &mut out);
+    out.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path)));
+    out
+}
+
+fn collect_agents(dir: &Path, out: &mut Vec<ClaudeAgent>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            collect_agents(&path, out);
+            continue;
+        }
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let content = fs::read_to_string(&path).unwrap_or_default();
+        let fields = parse_prompt(&content)
+            .map(|(fields, _)| fields)
+            .unwrap_or_default();
+        let get = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+        out.push(ClaudeAgent {
+            name: get("name").unwrap_or_else(|| stem.to_string()),
+            path,
+            model: get("model"),
+            tools: get("tools"),
+        });
+    }
+}
+
+/// The installed subagent called `name`, wherever it lives under `dir`.
+pub fn find_claude_agent(dir: &Path, name: &str) -> Option<ClaudeAgent> {
+    list_claude_agents(dir).into_iter().find(|a| a.name == name)
+}
+
+/// A built-in subagent template with `overrides` applied to its frontmatter.
+pub fn render_builtin_agent(
+    name: &str,
+    lang: TemplateLang,
+    overrides: &AgentOverrides,
+) -> Result<String, String> {
+    let Some(template) = templates::claude_agent_template(name, lang) else {
+        return Err(format!(
+            "未知的内置 subagent: {name}（可选: {}）",
+            templates::claude_agent_names().join(", ")
+        ));
+    };
+    overrides.validate()?;
+    let (mut fields, body) = parse_prompt(template)?;
+    overrides.apply(&mut fields);
+    Ok(render_prompt(&fields, &body))
+}
+
+/// Plan installing one built-in subagent. An existing copy (e.g. from `init`) is updated in place;
+/// the CLI asks for `--yes` before replacing one whose content differs.
+pub fn plan_install_agent(
+    dir: &Path,
+    name: &str,
+    lang: TemplateLang,
+    overrides: &AgentOverrides,
+) -> Result<ChangeSet, String> {
+    let content = render_builtin_agent(name, lang, overrides)?;
+    let path = find_claude_agent(dir, name)
+        .map(|a| a.path)
+        .unwrap_or_else(|| dir.join(format!("{name}.md")));
+
+    let mut cs = ChangeSet::new();
+    if let Some(parent) = path.parent() {
+        cs.push(Change::CreateDirAll {
+            path: parent.to_path_buf(),
+        });
+    }
+    cs.push(Change::WriteFile {
+        path,
+        bytes: content.into_bytes(),
+        overwrite: true,
+    });
+    Ok(cs)
+}
+
+/// A new subagent file with frontmatter and a placeholder system prompt.
+pub fn scaffold_agent(
+    name: &str,
+    description: &str,
+    overrides: &AgentOverrides,
+) -> Result<String, String> {
+    validate_prompt_name(name)?;
+    if description.trim().is_empty() || description.contains('\n') {
+        return Err("description 不能为空，且不能包含换行".to_string());
+    }
+    overrides.validate()?;
+    let mut fields = vec![
+        ("name".to_string(), name.to_string()),
+        ("description".to_string(), description.to_string()),
+    ];
+    overrides.apply(&mut fields);
+    let body = "\nDescribe this subagent's role, the steps it should follow and what it returns.\n";
+    Ok(render_prompt(&fields, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_overrides_and_scaffold() {
+        let overrides = AgentOverrides {
+            model: Some("opus".into()),
+            tools: Some("Read, Grep, mcp__my-server__search".into()),
+        };
+        let out = render_builtin_agent("get-current-datetime", TemplateLang::En, &overrides)
+            .expect("render");
+        let (fields, body) = parse_prompt(&out).expect("parse");
+        assert!(fields.contains(&(
+            "tools".to_string(),
+            "Read, Grep, mcp__my-server__search".to_string()
+        )));
+        assert!(fields.contains(&("model".to_string(), "opus".to_string())));
+        assert!(fields.contains(&("color".to_string(), "cyan".to_string())));
+        assert!(body.contains("date"));
+
+        assert!(render_builtin_agent("nope", TemplateLang::En, &overrides).is_err());
+        let bad_model = AgentOverrides {
+            model: Some("gpt".into()),
+            ..Default::default()
+        };
+        assert!(render_builtin_agent("planner", TemplateLang::En, &bad_model).is_err());
+
+        let created = scaffold_agent("reviewer", "Reviews diffs", &AgentOverrides::default())
+            .expect("scaffold");
+        assert!(created.starts_with("---\nname: reviewer\ndescription: Reviews diffs\n---\n"));
+        let bad_tools = AgentOverrides {
+            tools: Some("Read, -Bash".into()),
+            ..Default::default()
+        };
+        assert!(scaffold_agent("reviewer", "x", &bad_tools).is_err());
+        assert!(scaffold_agent("../x", "x", &AgentOverrides::default()).is_err());
+    }
+}
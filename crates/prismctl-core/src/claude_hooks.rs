@@ -0,0 +1,385 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::json_text::to_stable_pretty_json;
+use crate::parse_choice;
+use crate::paths::ConfigScope;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+/// Hook events Claude Code reads from `settings.json`.
+pub const HOOK_EVENTS: &[&str] = &[
+    "PreToolUse",
+    "PostToolUse",
+    "UserPromptSubmit",
+    "Notification",
+    "Stop",
+    "SubagentStop",
+    "PreCompact",
+    "SessionStart",
+    "SessionEnd",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One `{"type": "command"}` hook, flattened out of its event/matcher group.
+pub struct ClaudeHook {
+    pub event: String,
+    /// Tool-name pattern (e.g. `Edit|Write`); `None` matches everything.
+    pub matcher: Option<String>,
+    pub command: String,
+    /// Timeout in seconds.
+    pub timeout: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Built-in hooks shipped with Prismctl (script + registration).
+pub enum HookPreset {
+    /// PostToolUse: format edited files with rustfmt/gofmt/ruff/black/prettier when installed.
+    FormatOnEdit,
+    /// PreToolUse: block edits to `.env` / `.env.*`.
+    ProtectEnv,
+    /// Stop: desktop notification when Claude Code is waiting for input.
+    NotifyOnStop,
+}
+
+impl HookPreset {
+    pub const ALL: &'static [Self] = &[Self::FormatOnEdit, Self::ProtectEnv, Self::NotifyOnStop];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FormatOnEdit => "format-on-edit",
+            Self::ProtectEnv => "protect-env",
+            Self::NotifyOnStop => "notify-on-stop",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("hook preset", value, Self::ALL, |p| p.as_str())
+    }
+
+    pub fn event(self) -> &'static str {
+        match self {
+            Self::FormatOnEdit => "PostToolUse",
+            Self::ProtectEnv => "PreToolUse",
+            Self::NotifyOnStop => "Stop",
+        }
+    }
+
+    pub fn matcher(self) -> Option<&'static str> {
+        match self {
+            Self::FormatOnEdit | Self::ProtectEnv => Some("Edit|MultiEdit|Write"),
+            Self::NotifyOnStop => None,
+        }
+    }
+
+    fn script(self) -> &'static str {
+        match self {
+            Self::FormatOnEdit => include_str!("../assets/claude/hooks/format-on-edit.sh"),
+            Self::ProtectEnv => include_str!("../assets/claude/hooks/protect-env.sh"),
+            Self::NotifyOnStop => include_str!("../assets/claude/hooks/notify-on-stop.sh"),
+        }
+    }
+
+    /// Where the script lives: `.claude/hooks/prismctl/<name>.sh` next to `settings_path`.
+    pub fn script_path(self, settings_path: &Path) -> PathBuf {
+        hooks_dir(settings_path).join(format!("{}.sh", self.as_str()))
+    }
+
+    /// The registered command. Project settings use `$CLAUDE_PROJECT_DIR` so they stay portable.
+    pub fn command(self, scope: ConfigScope, settings_path: &Path) -> String {
+        match scope {
+            ConfigScope::User => {
+                format!("sh \"{}\"", self.script_path(settings_path).display())
+            }
+            _ => format!(
+                "sh \"$CLAUDE_PROJECT_DIR/.claude/hooks/prismctl/{}.sh\"",
+                self.as_str()
+            ),
+        }
+    }
+}
+
+fn hooks_dir(settings_path: &Path) -> PathBuf {
+    settings_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("hooks")
+        .join("prismctl")
+}
+
+pub fn validate_hook(hook: &ClaudeHook) -> Result<(), String> {
+    if !HOOK_EVENTS.contains(&hook.event.as_str()) {
+        return Err(format!(
+            "不支持的 hook 事件: {}（可选: {}）",
+            hook.event,
+            HOOK_EVENTS.join(", ")
+        ));
+    }
+    if hook.command.trim().is_empty() {
+        return Err("hook 命令不能为空".to_string());
+    }
+    if hook.matcher.as_deref().is_some_and(|m| m.trim().is_empty()) {
+        return Err("hook matcher 不能为空".to_string());
+    }
+    if hook.timeout == Some(0) {
+        return Err("hook timeout 必须大于 0".to_string());
+    }
+    Ok(())
+}
+
+/// All command hooks in `settings.json` content, in file order per event.
+pub fn list_claude_hooks(content: &str) -> Result<Vec<ClaudeHook>, String> {
+    let obj = parse(content)?;
+    let Some(events) = obj.get("hooks").and_then(Value::as_object) else {
+        return Ok(Vec::new());
+    };
+    let mut out = Vec::new();
+    for (event, groups) in events {
+        for group in groups.as_array().into_iter().flatten() {
+            let matcher = group
+                .get("matcher")
+                .and_then(Value::as_str)
+                .filter(|m| !m.is_empty())
+                .map(str::to_string);
+            for hook in group
+                .get("hooks")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                let Some(command) = hook.get("command").and_then(Value::as_str) else {
+                    continue;
+                };
+                out.push(ClaudeHook {
+                    event: event.clone(),
+                    matcher: matcher.clone(),
+                    command: command.to_string(),
+                    timeout: hook.get("timeout").and_then(Value::as_u64),
+                });
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Register `hook` under its event, joining an existing group with the same matcher.
+///
+/// A hook with the same event and command is replaced, so re-adding is idempotent.
+pub fn add_claude_hook(content: &str, hook: &ClaudeHook) -> Result<String, String> {
+    validate_hook(hook)?;
+    let (_, stripped) = remove_hook_value(content, &hook.event, &hook.command)?;
+    let mut obj = stripped;
+    let groups = event_groups_mut(&mut obj, &hook.event)?;
+
+    let mut entry = json!({ "type": "command", "command": hook.command });
+    if let Some(timeout) = hook.timeout {
+        entry["timeout"] = json!(timeout);
+    }
+    let matcher = hook.matcher.as_deref().unwrap_or("");
+    match groups
+        .iter_mut()
+        .find(|g| g.get("matcher").and_then(Value::as_str).unwrap_or("") == matcher)
+    {
+        Some(group) => match group.get_mut("hooks").and_then(Value::as_array_mut) {
+            Some(hooks) => hooks.push(entry),
+            None => group["hooks"] = json!([entry]),
+        },
+        None => {
+            let mut group = json!({ "hooks": [entry] });
+            if let Some(m) = &hook.matcher {
+                group["matcher"] = json!(m);
+            }
+            groups.push(group);
+        }
+    }
+    Ok(to_stable_pretty_json(&obj))
+}
+
+/// Remove every hook with `command` under `event`; `None` when nothing matched.
+///
+/// Empty matcher groups, events and the `hooks` object are dropped.
+pub fn remove_claude_hook(
+    content: &str,
+    event: &str,
+    command: &str,
+) -> Result<Option<String>, String> {
+    let (removed, obj) = remove_hook_value(content, event, command)?;
+    Ok(removed.then(|| to_stable_pretty_json(&obj)))
+}
+
+/// Plan writing the preset script and registering it in `settings.json` as one change set.
+pub fn plan_install_hook_preset(
+    scope: ConfigScope,
+    settings_path: &Path,
+    settings: &str,
+    preset: HookPreset,
+) -> Result<ChangeSet, String> {
+    let hook = ClaudeHook {
+        event: preset.event().to_string(),
+        matcher: preset.matcher().map(str::to_string),
+        command: preset.command(scope, settings_path),
+        timeout: None,
+    };
+    let next = add_claude_hook(settings, &hook)?;
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll {
+        path: hooks_dir(settings_path),
+    });
+    cs.push(Change::WriteFile {
+        path: preset.script_path(settings_path),
+        bytes: preset.script().as_bytes().to_vec(),
+        overwrite: true,
+    });
+    cs.push(Change::WriteFile {
+        path: settings_path.to_path_buf(),
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    Ok(cs)
+}
+
+/// Plan unregistering the preset and deleting its script; `None` when neither exists.
+pub fn plan_remove_hook_preset(
+    scope: ConfigScope,
+    settings_path: &Path,
+    settings: &str,
+    preset: HookPreset,
+) -> Result<Option<ChangeSet>, String> {
+    let mut cs = ChangeSet::new();
+    if let Some(next) = remove_claude_hook(
+        settings,
+        preset.event(),
+        &preset.command(scope, settings_path),
+    )? {
+        cs.push(Change::WriteFile {
+            path: settings_path.to_path_buf(),
+            bytes: next.into_bytes(),
+            overwrite: true,
+        });
+    }
+    let script = preset.script_path(settings_path);
+    if script.is_file() {
+        cs.push(Change::RemoveFile { path: script });
+    }
+    Ok((!cs.is_empty()).then_some(cs))
+}
+
+/// Whether `preset` is registered in `settings.json` content.
+pub fn hook_preset_installed(
+    hooks: &[ClaudeHook],
+    scope: ConfigScope,
+    settings_path: &Path,
+    preset: HookPreset,
+) -> bool {
+    let command = preset.command(scope, settings_path);
+    hooks
+        .iter()
+        .any(|h| h.event == preset.event() && h.command == command)
+}
+
+fn remove_hook_value(content: &str, event: &str, command: &str) -> Result<(bool, Value), String> {
+    let mut obj = parse(content)?;
+    let mut removed = false;
+    if let Some(events) = obj.get_mut("hooks").and_then(Value::as_object_mut) {
+        if let Some(groups) = events.get_mut(event).and_then(Value::as_array_mut) {
+            for group in groups.iter_mut() {
+                if let Some(hooks) = group.get_mut("hooks").and_then(Value::as_array_mut) {
+                    let before = hooks.len();
+                    hooks.retain(|h| h.get("command").and_then(Value::as_str) != Some(command));
+                    removed |= hooks.len() != before;
+                }
+            }
+            groups.retain(|g| {
+                g.get("hooks")
+                    .and_then(Value::as_array)
+                    .is_none_or(|h| !h.is_empty())
+            });
+            if groups.is_empty() {
+                events.remove(event);
+            }
+        }
+        if events.is_empty() {
+            obj.as_object_mut().map(|o| o.remove("hooks"));
+        }
+    }
+    Ok((removed, obj))
+}
+
+fn event_groups_mut<'a>(obj: &'a mut Value, event: &str) -> Result<&'a mut Vec<Value>, String> {
+    obj.as_object_mut()
+        .ok_or_else(|| "JSON 顶层不是对象".to_string())?
+        .entry("hooks")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .ok_or_else(|| "JSON 字段 hooks 不是对象".to_string())?
+        .entry(event)
+        .or_insert_with(|| json!([]))
+        .as_array_mut()
+        .ok_or_else(|| format!("JSON 字段 hooks.{event} 不是数组"))
+}
+
+fn parse(content: &str) -> Result<Value, String> {
+    if content.trim().is_empty() {
+        return Ok(Value::Object(Default::default()));
+    }
+    let obj = serde_json::from_str::<Value>(content).map_err(|e| format!("JSON 解析失败: {e}"))?;
+    if !obj.is_object() {
+        return Err("JSON 顶层不是对象".to_string());
+    }
+    Ok(obj)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(event: &str, matcher: Option<&str>, command: &str) -> ClaudeHook {
+        ClaudeHook {
+            event: event.to_string(),
+            matcher: matcher.map(str::to_string),
+            command: command.to_string(),
+            timeout: None,
+        }
+    }
+
+    #[test]
+    fn add_groups_by_matcher_and_remove_cleans_up() {
+        let content = r#"{"model": "opus"}"#;
+        let out = add_claude_hook(content, &hook("PostToolUse", Some("Edit"), "a")).expect("a");
+        let out = add_claude_hook(&out, &hook("PostToolUse", Some("Edit"), "b")).expect("b");
+        let out = add_claude_hook(&out, &hook("PostToolUse", Some("Edit"), "a")).expect("again");
+        let hooks = list_claude_hooks(&out).expect("list");
+        assert_eq!(hooks.len(), 2);
+        assert!(out.contains("\"model\""));
+        assert_eq!(out.matches("\"matcher\"").count(), 1);
+
+        assert!(add_claude_hook(&out, &hook("OnEdit", None, "x")).is_err());
+
+        let out = remove_claude_hook(&out, "PostToolUse", "a")
+            .expect("remove")
+            .expect("changed");
+        let out = remove_claude_hook(&out, "PostToolUse", "b")
+            .expect("remove")
+            .expect("changed");
+        assert!(!out.contains("hooks"));
+        assert_eq!(remove_claude_hook(&out, "Stop", "a").expect("noop"), None);
+    }
+
+    #[test]
+    fn preset_plan_writes_script_and_registration_together() {
+        let settings = PathBuf::from("/p/.claude/settings.json");
+        let cs =
+            plan_install_hook_preset(ConfigScope::Project, &settings, "", HookPreset::ProtectEnv)
+                .expect("plan");
+        let script = cs.written("/p/.claude/hooks/prismctl/protect-env.sh");
+        assert!(script.starts_with("#!"));
+        let registered = cs.written("/p/.claude/settings.json");
+        let hooks = list_claude_hooks(&registered).expect("list");
+        assert!(hook_preset_installed(
+            &hooks,
+            ConfigScope::Project,
+            &settings,
+            HookPreset::ProtectEnv
+        ));
+        assert!(hooks[0].command.contains("$CLAUDE_PROJECT_DIR"));
+    }
+}
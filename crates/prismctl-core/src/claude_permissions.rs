@@ -0,0 +1,420 @@
+use crate::json_text;
+use crate::parse_choice;
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// One of the `permissions.allow|deny|ask` rule lists.
+pub enum PermissionList {
+    Allow,
+    Deny,
+    Ask,
+}
+
+impl PermissionList {
+    pub const ALL: &'static [Self] = &[Self::Allow, Self::Ask, Self::Deny];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Deny => "deny",
+            Self::Ask => "ask",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `permissions.defaultMode`.
+pub enum PermissionMode {
+    Default,
+    AcceptEdits,
+    Plan,
+    BypassPermissions,
+}
+
+impl PermissionMode {
+    pub const ALL: &'static [Self] = &[
+        Self::Default,
+        Self::AcceptEdits,
+        Self::Plan,
+        Self::BypassPermissions,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::AcceptEdits => "acceptEdits",
+            Self::Plan => "plan",
+            Self::BypassPermissions => "bypassPermissions",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("defaultMode", value, Self::ALL, |m| m.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Curated permission presets; applying one merges its rules and sets `defaultMode`.
+pub enum PermissionsPreset {
+    ReadOnly,
+    StandardDev,
+    LockedDown,
+}
+
+/// Secrets no preset should let Claude read.
+const SECRET_READS: &[&str] = &["Read(./.env)", "Read(./.env.*)", "Read(./secrets/**)"];
+
+impl PermissionsPreset {
+    pub const ALL: &'static [Self] = &[Self::ReadOnly, Self::StandardDev, Self::LockedDown];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ReadOnly => "read-only",
+            Self::StandardDev => "standard-dev",
+            Self::LockedDown => "locked-down",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("preset", value, Self::ALL, |p| p.as_str())
+    }
+
+    pub fn default_mode(self) -> PermissionMode {
+        match self {
+            Self::ReadOnly => PermissionMode::Plan,
+            Self::StandardDev | Self::LockedDown => PermissionMode::Default,
+        }
+    }
+
+    /// Rules per list, in the order they are added.
+    pub fn rules(self, list: PermissionList) -> Vec<&'static str> {
+        let read_tools = ["Read", "Glob", "Grep"];
+        let git_read = ["Bash(git status:*)", "Bash(git diff:*)", "Bash(git log:*)"];
+        match (self, list) {
+            (Self::ReadOnly, PermissionList::Allow) => [&read_tools[..], &git_read[..]].concat(),
+            (Self::ReadOnly, PermissionList::Deny) => [
+                &["Edit", "MultiEdit", "Write", "NotebookEdit"][..],
+                SECRET_READS,
+            ]
+            .concat(),
+            (Self::ReadOnly, PermissionList::Ask) => vec!["Bash", "WebFetch"],
+            (Self::StandardDev, PermissionList::Allow) => [
+                &read_tools[..],
+                &git_read[..],
+                &[
+                    "Edit",
+                    "MultiEdit",
+                    "Write",
+                    "Bash(git add:*)",
+                    "Bash(git commit:*)",
+                ][..],
+            ]
+            .concat(),
+            (Self::StandardDev, PermissionList::Deny) => SECRET_READS.to_vec(),
+            (Self::StandardDev, PermissionList::Ask) => {
+                vec!["Bash(git push:*)", "Bash(rm:*)", "WebFetch"]
+            }
+            (Self::LockedDown, PermissionList::Allow) => read_tools.to_vec(),
+            (Self::LockedDown, PermissionList::Deny) => [
+                &[
+                    "Bash(curl:*)",
+                    "Bash(wget:*)",
+                    "Bash(git push:*)",
+                    "WebFetch",
+                ][..],
+                SECRET_READS,
+            ]
+            .concat(),
+            (Self::LockedDown, PermissionList::Ask) => {
+                vec!["Bash", "Edit", "MultiEdit", "Write", "NotebookEdit"]
+            }
+        }
+    }
+}
+
+/// Split a comma-separated tool list (command `allowed-tools`, subagent `tools`); commas inside
+/// a rule's parentheses, as in `Bash(echo a,b)`, do not split it.
+pub fn split_tool_list(list: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                out.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    out.push(list[start..].trim());
+    out
+}
+
+/// Validate a tool name: a built-in such as `Bash` or `WebFetch`, or `mcp__<server>[__<tool>]`.
+pub fn validate_tool_name(name: &str) -> Result<(), String> {
+    let ok = match name.strip_prefix("mcp__") {
+        Some(server) => {
+            !server.is_empty()
+                && server
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        }
+        // Built-ins are not whitelisted (new ones keep appearing); they are PascalCase words.
+        None => {
+            name.starts_with(|c: char| c.is_ascii_uppercase())
+                && name.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(format!(
+            "无效的工具名: {name}（应形如 Bash、WebFetch 或 mcp__<server>__<tool>）"
+        ))
+    }
+}
+
+/// Validate a tool rule: `Tool`, `Tool(specifier)` or `mcp__<server>[__<tool>]`. Permission
+/// rules and command `allowed-tools` entries share this syntax.
+///
+/// Examples: `Bash(git diff:*)`, `Read(./secrets/**)`, `WebFetch(domain:example.com)`.
+pub fn validate_permission_rule(rule: &str) -> Result<(), String> {
+    let invalid = |why: &str| Err(format!("无效的工具规则: {rule}（{why}）"));
+    let (tool, spec) = match rule.find('(') {
+        Some(i) => match rule[i + 1..].strip_suffix(')') {
+            Some(spec) => (&rule[..i], Some(spec)),
+            None => return invalid("缺少结尾的 )"),
+        },
+        None => (rule, None),
+    };
+    validate_tool_name(tool)?;
+
+    let Some(spec) = spec else {
+        return Ok(());
+    };
+    if tool.starts_with("mcp__") {
+        return invalid("MCP 规则不支持括号参数");
+    }
+    if spec.trim().is_empty() {
+        return invalid("括号内不能为空");
+    }
+    match tool {
+        "Bash" if spec.trim_end_matches(":*").contains(":*") => {
+            invalid("Bash 规则的 :* 只能出现在末尾（前缀匹配）")
+        }
+        "WebFetch" if !spec.starts_with("domain:") => invalid("WebFetch 规则格式: domain:<host>"),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// The `permissions` object of a Claude Code `settings.json`.
+pub struct ClaudePermissions {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+    pub ask: Vec<String>,
+    pub default_mode: Option<String>,
+}
+
+impl ClaudePermissions {
+    pub fn rules(&self, list: PermissionList) -> &[String] {
+        match list {
+            PermissionList::Allow => &self.allow,
+            PermissionList::Deny => &self.deny,
+            PermissionList::Ask => &self.ask,
+        }
+    }
+
+    fn rules_mut(&mut self, list: PermissionList) -> &mut Vec<String> {
+        match list {
+            PermissionList::Allow => &mut self.allow,
+            PermissionList::Deny => &mut self.deny,
+            PermissionList::Ask => &mut self.ask,
+        }
+    }
+}
+
+pub fn read_claude_permissions(content: &str) -> Result<ClaudePermissions, String> {
+    let obj = parse(content)?;
+    let strings = |list: &str| -> Vec<String> {
+        obj.pointer(&format!("/permissions/{list}"))
+            .and_then(Value::as_array)
+            .map(|a| {
+                a.iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    Ok(ClaudePermissions {
+        allow: strings("allow"),
+        deny: strings("deny"),
+        ask: strings("ask"),
+        default_mode: obj
+            .pointer("/permissions/defaultMode")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    })
+}
+
+/// Add `rules` to `list`, moving them out of the other lists so each rule lives in one place.
+pub fn add_claude_permission_rules(
+    content: &str,
+    list: PermissionList,
+    rules: &[String],
+) -> Result<String, String> {
+    for rule in rules {
+        validate_permission_rule(rule)?;
+    }
+    let mut perms = read_claude_permissions(content)?;
+    for rule in rules {
+        for other in PermissionList::ALL {
+            if *other != list {
+                perms.rules_mut(*other).retain(|r| r != rule);
+            }
+        }
+        let target = perms.rules_mut(list);
+        if !target.contains(rule) {
+            target.push(rule.clone());
+        }
+    }
+    write_rule_lists(content, &perms)
+}
+
+/// Remove `rule` from every list; `None` when it is not present.
+pub fn remove_claude_permission_rule(content: &str, rule: &str) -> Result<Option<String>, String> {
+    let mut perms = read_claude_permissions(content)?;
+    let before = perms.clone();
+    for list in PermissionList::ALL {
+        perms.rules_mut(*list).retain(|r| r != rule);
+    }
+    if perms == before {
+        return Ok(None);
+    }
+    write_rule_lists(content, &perms).map(Some)
+}
+
+pub fn set_claude_permission_mode(content: &str, mode: PermissionMode) -> Result<String, String> {
+    json_text::upsert_value_at_path(
+        content,
+        &["permissions", "defaultMode"],
+        Value::String(mode.as_str().to_string()),
+    )
+}
+
+/// Merge a preset's rules into the existing lists and set its `defaultMode`.
+pub fn apply_claude_permissions_preset(
+    content: &str,
+    preset: PermissionsPreset,
+) -> Result<String, String> {
+    let mut out = content.to_string();
+    for list in PermissionList::ALL {
+        let rules: Vec<String> = preset.rules(*list).into_iter().map(String::from).collect();
+        out = add_claude_permission_rules(&out, *list, &rules)?;
+    }
+    set_claude_permission_mode(&out, preset.default_mode())
+}
+
+/// Write the three lists back, dropping empty ones (other `permissions` keys are kept).
+fn write_rule_lists(content: &str, perms: &ClaudePermissions) -> Result<String, String> {
+    let mut out = content.to_string();
+    for list in PermissionList::ALL {
+        let path = ["permissions", list.as_str()];
+        let rules = perms.rules(*list);
+        if rules.is_empty() {
+            if let Some(next) = json_text::remove_value_at_path(&out, &path)? {
+                out = next;
+            }
+        } else {
+            let value = Value::Array(rules.iter().cloned().map(Value::String).collect());
+            out = json_text::upsert_value_at_path(&out, &path, value)?;
+        }
+    }
+    Ok(out)
+}
+
+fn parse(content: &str) -> Result<Value, String> {
+    if content.trim().is_empty() {
+        return Ok(Value::Object(Default::default()));
+    }
+    serde_json::from_str::<Value>(content).map_err(|e| format!("JSON 解析失败: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_rule_syntax() {
+        for ok in [
+            "Bash",
+            "Bash(git diff:*)",
+            "Read(./secrets/**)",
+            "WebFetch(domain:example.com)",
+            "mcp__github",
+            "mcp__github__get_issue",
+            "ExitPlanMode",
+            "KillShell",
+        ] {
+            assert!(validate_permission_rule(ok).is_ok(), "{ok}");
+        }
+        for bad in [
+            "bash(ls)",
+            "Web Fetch",
+            "(ls)",
+            "Bash(git diff:*",
+            "Read()",
+            "Bash(git:* diff)",
+            "WebFetch(example.com)",
+            "mcp__x(y)",
+        ] {
+            assert!(validate_permission_rule(bad).is_err(), "{bad}");
+        }
+        assert_eq!(
+            split_tool_list("Bash(echo a,b), Read ,mcp__my-server__search"),
+            vec!["Bash(echo a,b)", "Read", "mcp__my-server__search"]
+        );
+    }
+
+    #[test]
+    fn rules_move_between_lists_and_preserve_other_settings() {
+        let content = r#"{"env": {"A": "1"}, "permissions": {"allow": ["Bash(git push:*)"], "additionalDirectories": ["../x"]}}"#;
+        let out = add_claude_permission_rules(
+            content,
+            PermissionList::Deny,
+            &["Bash(git push:*)".to_string()],
+        )
+        .expect("add");
+        let perms = read_claude_permissions(&out).expect("read");
+        assert!(perms.allow.is_empty());
+        assert_eq!(perms.deny, vec!["Bash(git push:*)"]);
+        assert!(out.contains("additionalDirectories") && out.contains("\"A\""));
+        assert!(!out.contains("\"allow\""));
+
+        let out = remove_claude_permission_rule(&out, "Bash(git push:*)")
+            .expect("remove")
+            .expect("changed");
+        assert_eq!(read_claude_permissions(&out).expect("read").deny.len(), 0);
+        assert_eq!(
+            remove_claude_permission_rule(&out, "Read").expect("noop"),
+            None
+        );
+    }
+
+    #[test]
+    fn presets_are_valid_and_set_mode() {
+        for preset in PermissionsPreset::ALL {
+            let out = apply_claude_permissions_preset("", *preset).expect("preset");
+            let perms = read_claude_permissions(&out).expect("read");
+            assert_eq!(
+                perms.default_mode.as_deref(),
+                Some(preset.default_mode().as_str())
+            );
+            assert!(perms.deny.contains(&"Read(./.env)".to_string()));
+        }
+    }
+}
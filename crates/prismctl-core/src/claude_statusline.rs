@@ -0,0 +1,192 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::json_text;
+use crate::parse_choice;
+use crate::paths::ConfigScope;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+
+const STATUSLINE_SCRIPT: &str = include_str!("../assets/claude/statusline/statusline.sh");
+/// Path of the bundled script relative to the `.claude` directory.
+const SCRIPT_REL_PATH: &str = "prismctl/statusline/statusline.sh";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The `statusLine` entry of Claude Code `settings.json`.
+pub struct ClaudeStatusLine {
+    pub command: String,
+    pub padding: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Segment sets for the bundled status line script.
+pub enum StatusLinePreset {
+    /// Model and working directory.
+    Minimal,
+    /// Model, git branch and working directory.
+    Git,
+    /// Model, git branch, working directory and context window usage.
+    Full,
+}
+
+impl StatusLinePreset {
+    pub const ALL: &'static [Self] = &[Self::Minimal, Self::Git, Self::Full];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Minimal => "minimal",
+            Self::Git => "git",
+            Self::Full => "full",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("status line preset", value, Self::ALL, |p| p.as_str())
+    }
+
+    /// Comma-separated segments passed to the script.
+    pub fn segments(self) -> &'static str {
+        match self {
+            Self::Minimal => "model,cwd",
+            Self::Git => "model,branch,cwd",
+            Self::Full => "model,branch,cwd,context",
+        }
+    }
+
+    /// The `statusLine.command`. Project settings use `$CLAUDE_PROJECT_DIR` so they stay portable.
+    pub fn command(self, scope: ConfigScope, settings_path: &Path) -> String {
+        let script = match scope {
+            ConfigScope::User => statusline_script_path(settings_path).display().to_string(),
+            _ => format!("$CLAUDE_PROJECT_DIR/.claude/{SCRIPT_REL_PATH}"),
+        };
+        format!("sh \"{script}\" {}", self.segments())
+    }
+}
+
+/// Where the bundled script lives: `.claude/prismctl/statusline/statusline.sh` next to `settings_path`.
+pub fn statusline_script_path(settings_path: &Path) -> PathBuf {
+    settings_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join(SCRIPT_REL_PATH)
+}
+
+pub fn read_claude_statusline(content: &str) -> Result<Option<ClaudeStatusLine>, String> {
+    if content.trim().is_empty() {
+        return Ok(None);
+    }
+    let obj = serde_json::from_str::<Value>(content).map_err(|e| format!("JSON 解析失败: {e}"))?;
+    let Some(line) = obj.get("statusLine") else {
+        return Ok(None);
+    };
+    Ok(line
+        .get("command")
+        .and_then(Value::as_str)
+        .map(|command| ClaudeStatusLine {
+            command: command.to_string(),
+            padding: line.get("padding").and_then(Value::as_u64),
+        }))
+}
+
+/// Set `statusLine` to a command, replacing any previous entry.
+pub fn set_claude_statusline(
+    content: &str,
+    command: &str,
+    padding: Option<u64>,
+) -> Result<String, String> {
+    if command.trim().is_empty() {
+        return Err("status line 命令不能为空".to_string());
+    }
+    let mut value = json!({ "type": "command", "command": command });
+    if let Some(padding) = padding {
+        value["padding"] = json!(padding);
+    }
+    json_text::upsert_value_at_path(content, &["statusLine"], value)
+}
+
+/// Plan installing the bundled script and pointing `statusLine` at it, as one change set.
+pub fn plan_statusline_preset(
+    scope: ConfigScope,
+    settings_path: &Path,
+    settings: &str,
+    preset: StatusLinePreset,
+) -> Result<ChangeSet, String> {
+    let next = set_claude_statusline(settings, &preset.command(scope, settings_path), None)?;
+    let script = statusline_script_path(settings_path);
+
+    let mut cs = ChangeSet::new();
+    if let Some(dir) = script.parent() {
+        cs.push(Change::CreateDirAll {
+            path: dir.to_path_buf(),
+        });
+    }
+    cs.push(Change::WriteFile {
+        path: script,
+        bytes: STATUSLINE_SCRIPT.as_bytes().to_vec(),
+        overwrite: true,
+    });
+    cs.push(Change::WriteFile {
+        path: settings_path.to_path_buf(),
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    Ok(cs)
+}
+
+/// Plan removing `statusLine` (and the bundled script when it was in use); `None` when unset.
+pub fn plan_clear_statusline(
+    settings_path: &Path,
+    settings: &str,
+) -> Result<Option<ChangeSet>, String> {
+    let Some(current) = read_claude_statusline(settings)? else {
+        return Ok(None);
+    };
+    let Some(next) = json_text::remove_value_at_path(settings, &["statusLine"])? else {
+        return Ok(None);
+    };
+
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: settings_path.to_path_buf(),
+        bytes: next.into_bytes(),
+        overwrite: true,
+    });
+    let script = statusline_script_path(settings_path);
+    if current.command.contains(SCRIPT_REL_PATH) && script.is_file() {
+        cs.push(Change::RemoveFile { path: script });
+    }
+    Ok(Some(cs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_plans_script_and_settings_then_clear_unsets() {
+        let settings_path = PathBuf::from("/h/.claude/settings.json");
+        let cs = plan_statusline_preset(
+            ConfigScope::User,
+            &settings_path,
+            r#"{"model": "opus"}"#,
+            StatusLinePreset::Full,
+        )
+        .expect("plan");
+        let settings = cs.written("/h/.claude/settings.json");
+        let line = read_claude_statusline(&settings)
+            .expect("read")
+            .expect("set");
+        assert_eq!(
+            line.command,
+            "sh \"/h/.claude/prismctl/statusline/statusline.sh\" model,branch,cwd,context"
+        );
+        assert!(settings.contains("\"model\""));
+
+        let cs = plan_clear_statusline(&settings_path, &settings)
+            .expect("clear")
+            .expect("changed");
+        assert_eq!(cs.iter().count(), 1, "script is not on disk in this test");
+        assert!(plan_clear_statusline(&settings_path, "{}")
+            .expect("noop")
+            .is_none());
+        assert!(set_claude_statusline("", " ", None).is_err());
+    }
+}
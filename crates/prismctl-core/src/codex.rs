@@ -1,4 +1,8 @@
+use crate::codex_settings::{ApprovalPolicy, ReasoningEffort};
 use crate::json_text;
+use crate::managed_block::{extract_managed_block, remove_managed_block, upsert_managed_block};
+use crate::mcp::{McpServerDefinition, McpTransport};
+use crate::templates::{self, TemplateLang};
 use crate::toml_text::{self, TomlScalar};
 
 #[derive(Debug, Clone)]
@@ -8,7 +12,8 @@ pub struct CodexProviderConfig {
     pub display_name: String,
     pub base_url: String,
     pub wire_api: String,
-    pub temp_env_key: String, // key name inside auth.json
+    pub temp_env_key: String,    // key name inside auth.json
+    pub env_key: Option<String>, // environment variable Codex reads the key from
     pub requires_openai_auth: bool,
     pub model: String,
 }
@@ -29,7 +34,7 @@ pub fn upsert_codex_provider_in_config_toml(
     }
 
     let header = format!("model_providers.{}", cfg.provider_id);
-    let kv = vec![
+    let mut kv = vec![
         (
             "name".to_string(),
             TomlScalar::Str(cfg.display_name.clone()),
@@ -52,6 +57,9 @@ pub fn upsert_codex_provider_in_config_toml(
         ),
         ("model".to_string(), TomlScalar::Str(cfg.model.clone())),
     ];
+    if let Some(env_key) = &cfg.env_key {
+        kv.push(("env_key".to_string(), TomlScalar::Str(env_key.clone())));
+    }
     toml_text::upsert_table_kv(&out, &header, &kv)
 }
 
@@ -64,9 +72,448 @@ pub fn upsert_codex_api_key_in_auth_json(
     json_text::upsert_string_property(content, temp_env_key, api_key)
 }
 
+/// Provider ids built into Codex itself (no `[model_providers.*]` table needed).
+pub const CODEX_BUILTIN_PROVIDERS: &[&str] = &["openai", "oss"];
+
+const PROVIDERS_TABLE_PREFIX: &str = "model_providers.";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A `[model_providers.<id>]` table as found in `config.toml`.
+pub struct CodexProviderEntry {
+    pub id: String,
+    pub name: Option<String>,
+    pub base_url: Option<String>,
+    pub wire_api: Option<String>,
+    pub model: Option<String>,
+    /// `temp_env_key`: the key name inside `auth.json` (Prismctl-managed).
+    pub auth_key: Option<String>,
+    /// `env_key`: the environment variable Codex reads the key from.
+    pub env_key: Option<String>,
+}
+
+pub fn validate_codex_provider_id(id: &str) -> Result<(), String> {
+    if !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "无效的 provider id: {id}（仅允许字母、数字、- 和 _）"
+        ))
+    }
+}
+
+/// Default `auth.json` key for a provider id, so each provider keeps its own API key.
+///
+/// `prismctl` keeps the historical `PRISMCTL_CODEX_API_KEY`.
+pub fn default_codex_env_key(provider_id: &str) -> String {
+    if provider_id == "prismctl" {
+        return "PRISMCTL_CODEX_API_KEY".to_string();
+    }
+    format!(
+        "PRISMCTL_{}_API_KEY",
+        provider_id.to_ascii_uppercase().replace('-', "_")
+    )
+}
+
+/// The root `model_provider` value, if set.
+pub fn active_codex_provider(content: &str) -> Option<String> {
+    toml_text::read_table_strings(content, "")
+        .into_iter()
+        .find(|(k, _)| k == "model_provider")
+        .map(|(_, v)| v)
+}
+
+/// All `[model_providers.*]` tables in file order.
+pub fn list_codex_providers(content: &str) -> Vec<CodexProviderEntry> {
+    toml_text::table_headers(content)
+        .into_iter()
+        .filter_map(|h| {
+            let id = h.strip_prefix(PROVIDERS_TABLE_PREFIX)?;
+            (!id.contains('.')).then(|| id.to_string())
+        })
+        .map(|id| {
+            let kv =
+                toml_text::read_table_strings(content, &format!("{PROVIDERS_TABLE_PREFIX}{id}"));
+            let get = |key: &str| kv.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+            CodexProviderEntry {
+                name: get("name"),
+                base_url: get("base_url"),
+                wire_api: get("wire_api"),
+                model: get("model"),
+                auth_key: get("temp_env_key"),
+                env_key: get("env_key"),
+                id,
+            }
+        })
+        .collect()
+}
+
+/// Point the root `model_provider` at `provider_id` (a configured table or a Codex built-in).
+pub fn use_codex_provider_in_config_toml(
+    content: &str,
+    provider_id: &str,
+) -> Result<String, String> {
+    let known = CODEX_BUILTIN_PROVIDERS.contains(&provider_id)
+        || list_codex_providers(content)
+            .iter()
+            .any(|p| p.id == provider_id);
+    if !known {
+        return Err(unknown_provider(content, provider_id));
+    }
+    Ok(toml_text::upsert_root_key(
+        content,
+        "model_provider",
+        TomlScalar::Str(provider_id.to_string()),
+    ))
+}
+
+/// Remove `[model_providers.<id>]` and its Prismctl-managed key from `auth.json`.
+///
+/// The active provider cannot be removed; switch to another one first. Returns the new
+/// `config.toml` and `auth.json` contents.
+pub fn remove_codex_provider(
+    config: &str,
+    auth: &str,
+    provider_id: &str,
+) -> Result<(String, String), String> {
+    if active_codex_provider(config).as_deref() == Some(provider_id) {
+        return Err(format!(
+            "provider {provider_id} 正在使用中，请先执行 `prismctl codex provider use <其他 id>`"
+        ));
+    }
+    let header = format!("{PROVIDERS_TABLE_PREFIX}{provider_id}");
+    let Some(next_config) = toml_text::remove_table(config, &header) else {
+        return Err(unknown_provider(config, provider_id));
+    };
+    // Only `temp_env_key` values live in auth.json; `env_key` names an environment variable.
+    let temp_key = toml_text::read_table_strings(config, &header)
+        .into_iter()
+        .find(|(k, _)| k == "temp_env_key")
+        .map(|(_, v)| v);
+    let next_auth = match temp_key {
+        Some(key) if !auth.trim().is_empty() => {
+            json_text::remove_value_at_path(auth, &[&key])?.unwrap_or_else(|| auth.to_string())
+        }
+        _ => auth.to_string(),
+    };
+    Ok((next_config, next_auth))
+}
+
+fn unknown_provider(content: &str, provider_id: &str) -> String {
+    let mut ids: Vec<String> = CODEX_BUILTIN_PROVIDERS
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    ids.extend(list_codex_providers(content).into_iter().map(|p| p.id));
+    format!("未知 provider: {}（可用: {}）", provider_id, ids.join(", "))
+}
+
+const PROFILES_TABLE_PREFIX: &str = "profiles.";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// A `[profiles.<name>]` table, selected with `codex --profile <name>`. `None` fields are
+/// left untouched on upsert.
+pub struct CodexProfileEntry {
+    pub name: String,
+    pub model: Option<String>,
+    pub model_provider: Option<String>,
+    pub approval_policy: Option<String>,
+    pub model_reasoning_effort: Option<String>,
+}
+
+impl CodexProfileEntry {
+    fn fields(&self) -> [(&'static str, &Option<String>); 4] {
+        [
+            ("model", &self.model),
+            ("model_provider", &self.model_provider),
+            ("approval_policy", &self.approval_policy),
+            ("model_reasoning_effort", &self.model_reasoning_effort),
+        ]
+    }
+}
+
+pub fn validate_codex_profile_name(name: &str) -> Result<(), String> {
+    validate_codex_provider_id(name)
+        .map_err(|_| format!("无效的 profile 名称: {name}（仅允许字母、数字、- 和 _）"))
+}
+
+/// The root `profile` value (Codex's default profile), if set.
+pub fn active_codex_profile(content: &str) -> Option<String> {
+    toml_text::read_table_strings(content, "")
+        .into_iter()
+        .find(|(k, _)| k == "profile")
+        .map(|(_, v)| v)
+}
+
+/// All `[profiles.*]` tables in file order.
+pub fn list_codex_profiles(content: &str) -> Vec<CodexProfileEntry> {
+    toml_text::table_headers(content)
+        .into_iter()
+        .filter_map(|h| {
+            let name = h.strip_prefix(PROFILES_TABLE_PREFIX)?;
+            (!name.contains('.')).then(|| name.to_string())
+        })
+        .map(|name| {
+            let kv =
+                toml_text::read_table_strings(content, &format!("{PROFILES_TABLE_PREFIX}{name}"));
+            let get = |key: &str| kv.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+            CodexProfileEntry {
+                model: get("model"),
+                model_provider: get("model_provider"),
+                approval_policy: get("approval_policy"),
+                model_reasoning_effort: get("model_reasoning_effort"),
+                name,
+            }
+        })
+        .collect()
+}
+
+/// Create or update `[profiles.<name>]`; optionally make it the root `profile`.
+pub fn upsert_codex_profile_in_config_toml(
+    content: &str,
+    profile: &CodexProfileEntry,
+    set_default: bool,
+) -> Result<String, String> {
+    validate_codex_profile_name(&profile.name)?;
+    if let Some(v) = &profile.approval_policy {
+        ApprovalPolicy::parse(v)?;
+    }
+    if let Some(v) = &profile.model_reasoning_effort {
+        ReasoningEffort::parse(v)?;
+    }
+
+    let mut out = content.to_string();
+    if set_default {
+        out = toml_text::upsert_root_key(&out, "profile", TomlScalar::Str(profile.name.clone()));
+    }
+    let kv: Vec<(String, TomlScalar)> = profile
+        .fields()
+        .into_iter()
+        .filter_map(|(k, v)| Some((k.to_string(), TomlScalar::Str(v.clone()?))))
+        .collect();
+    Ok(toml_text::upsert_table_kv(
+        &out,
+        &format!("{PROFILES_TABLE_PREFIX}{}", profile.name),
+        &kv,
+    ))
+}
+
+/// Point the root `profile` at an existing `[profiles.<name>]`.
+pub fn use_codex_profile_in_config_toml(content: &str, name: &str) -> Result<String, String> {
+    if !list_codex_profiles(content).iter().any(|p| p.name == name) {
+        return Err(unknown_profile(content, name));
+    }
+    Ok(toml_text::upsert_root_key(
+        content,
+        "profile",
+        TomlScalar::Str(name.to_string()),
+    ))
+}
+
+/// Remove `[profiles.<name>]`; clears the root `profile` too when it pointed at `name`.
+pub fn remove_codex_profile(content: &str, name: &str) -> Result<String, String> {
+    let Some(mut out) = toml_text::remove_table(content, &format!("{PROFILES_TABLE_PREFIX}{name}"))
+    else {
+        return Err(unknown_profile(content, name));
+    };
+    if active_codex_profile(&out).as_deref() == Some(name) {
+        out = toml_text::remove_root_key(&out, "profile").unwrap_or(out);
+    }
+    Ok(out)
+}
+
+fn unknown_profile(content: &str, name: &str) -> String {
+    let names: Vec<String> = list_codex_profiles(content)
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    if names.is_empty() {
+        return format!("未知 profile: {name}（config.toml 中还没有 [profiles.*]）");
+    }
+    format!("未知 profile: {}（可用: {}）", name, names.join(", "))
+}
+
+const MCP_TABLE_PREFIX: &str = "mcp_servers.";
+
+/// MCP server ids configured as `[mcp_servers.<id>]` in `config.toml`, in file order.
+pub fn list_codex_mcp_servers(content: &str) -> Vec<String> {
+    toml_text::table_headers(content)
+        .into_iter()
+        .filter_map(|h| {
+            let id = h.strip_prefix(MCP_TABLE_PREFIX)?;
+            (!id.contains('.')).then(|| id.to_string())
+        })
+        .collect()
+}
+
+/// Short description of a configured server (`url`, or `command args...`).
+pub fn describe_codex_mcp_server(content: &str, id: &str) -> Option<String> {
+    let kv = toml_text::read_table_raw(content, &format!("{MCP_TABLE_PREFIX}{id}"));
+    let get = |key: &str| kv.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+    match (get("url"), get("command")) {
+        (Some(url), _) => Some(url),
+        (None, Some(cmd)) => Some(match get("args") {
+            Some(args) => format!("{cmd} {args}"),
+            None => cmd,
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Write a built-in MCP server as `[mcp_servers.<id>]`, replacing any existing entry.
+///
+/// Stdio servers get `command`/`args` plus an `env` sub-table; remote servers get `url`, with
+/// `${VAR}` headers mapped to `env_http_headers` (Codex reads the value from that variable).
+pub fn upsert_codex_mcp_server_in_config_toml(
+    content: &str,
+    def: &McpServerDefinition,
+) -> Result<String, String> {
+    let header = format!("{MCP_TABLE_PREFIX}{}", def.id);
+    let mut out = toml_text::remove_table(content, &header).unwrap_or_else(|| content.to_string());
+    let str_kv = |pairs: &[(&str, &str)]| -> Vec<(String, TomlScalar)> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), TomlScalar::Str((*v).to_string())))
+            .collect()
+    };
+
+    match def.transport {
+        McpTransport::Http | McpTransport::Sse | McpTransport::StreamableHttp => {
+            let url = def
+                .url
+                .ok_or_else(|| format!("MCP server {} 缺少 url", def.id))?;
+            out = toml_text::upsert_table_kv(&out, &header, &str_kv(&[("url", url)]));
+            let (from_env, literal): (Vec<_>, Vec<_>) = def
+                .headers
+                .iter()
+                .map(
+                    |(k, v)| match v.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+                        Some(var) => (true, (*k, var)),
+                        None => (false, (*k, *v)),
+                    },
+                )
+                .partition(|(env, _)| *env);
+            for (table, pairs) in [("env_http_headers", from_env), ("http_headers", literal)] {
+                let pairs: Vec<(&str, &str)> = pairs.into_iter().map(|(_, kv)| kv).collect();
+                if !pairs.is_empty() {
+                    out = toml_text::upsert_table_kv(
+                        &out,
+                        &format!("{header}.{table}"),
+                        &str_kv(&pairs),
+                    );
+                }
+            }
+        }
+        McpTransport::Stdio => {
+            let cmd = def
+                .command
+                .ok_or_else(|| format!("MCP server {} 缺少 command", def.id))?;
+            let mut kv = str_kv(&[("command", cmd)]);
+            if !def.args.is_empty() {
+                kv.push((
+                    "args".to_string(),
+                    TomlScalar::StrArray(def.args.iter().map(|a| (*a).to_string()).collect()),
+                ));
+            }
+            out = toml_text::upsert_table_kv(&out, &header, &kv);
+            if !def.env.is_empty() {
+                out = toml_text::upsert_table_kv(&out, &format!("{header}.env"), &str_kv(def.env));
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Remove `[mcp_servers.<name>]` and its sub-tables; errors when the server is not configured.
+pub fn remove_codex_mcp_server_in_config_toml(content: &str, name: &str) -> Result<String, String> {
+    toml_text::remove_table(content, &format!("{MCP_TABLE_PREFIX}{name}"))
+        .ok_or_else(|| format!("config.toml 中未找到 MCP server: {name}"))
+}
+
+const PERSONA_START: &str = "<!-- prismctl:persona:start -->";
+const PERSONA_END: &str = "<!-- prismctl:persona:end -->";
+const PERSONA_NAME_PREFIX: &str = "<!-- persona: ";
+
+/// Put the `name` persona into the managed persona block of `AGENTS.md`, keeping everything else.
+///
+/// Files written by older versions (the whole file is a built-in template) are replaced.
+pub fn render_codex_persona(existing: &str, name: &str, template: &str) -> String {
+    let existing = if legacy_persona_name(existing).is_some() {
+        ""
+    } else {
+        existing
+    };
+    let block = format!(
+        "{PERSONA_NAME_PREFIX}{name} -->\n\n{}",
+        strip_front_matter(template).trim()
+    );
+    upsert_managed_block(existing, PERSONA_START, PERSONA_END, &block)
+}
+
+/// Whether writing a persona into `existing` should back the file up first: it has content but
+/// no persona block yet (hand-written instructions, or a file written by an older version).
+pub fn codex_persona_needs_backup(existing: &str) -> bool {
+    !existing.trim().is_empty()
+        && extract_managed_block(existing, PERSONA_START, PERSONA_END).is_none()
+}
+
+/// Name of the persona in `AGENTS.md`, if any.
+pub fn current_codex_persona(content: &str) -> Option<String> {
+    match extract_managed_block(content, PERSONA_START, PERSONA_END) {
+        Some(block) => block
+            .lines()
+            .next()
+            .and_then(|l| l.strip_prefix(PERSONA_NAME_PREFIX))
+            .and_then(|l| l.strip_suffix(" -->"))
+            .map(|n| n.trim().to_string()),
+        None => legacy_persona_name(content),
+    }
+}
+
+/// Remove the persona from `AGENTS.md`; `None` when there is none.
+pub fn clear_codex_persona(content: &str) -> Option<String> {
+    if legacy_persona_name(content).is_some() {
+        return Some(String::new());
+    }
+    remove_managed_block(content, PERSONA_START, PERSONA_END)
+}
+
+/// A file that is exactly one built-in template (how `agent use` used to write it).
+fn legacy_persona_name(content: &str) -> Option<String> {
+    let content = content.trim();
+    if content.is_empty() {
+        return None;
+    }
+    templates::codex_agent_names()
+        .iter()
+        .find(|name| {
+            [TemplateLang::ZhCn, TemplateLang::En]
+                .into_iter()
+                .any(|lang| {
+                    templates::codex_agent_template(name, lang).is_some_and(|t| t.trim() == content)
+                })
+        })
+        .map(|name| (*name).to_string())
+}
+
+/// Drop a leading YAML front matter (`---` ... `---`); it is only valid at the top of a file.
+fn strip_front_matter(template: &str) -> &str {
+    let Some(rest) = template.strip_prefix("---\n") else {
+        return template;
+    };
+    match rest.find("\n---\n") {
+        Some(end) => &rest[end + "\n---\n".len()..],
+        None => template,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mcp::builtin_mcp_server;
 
     #[test]
     fn inserts_provider_table() {
@@ -76,6 +523,7 @@ mod tests {
             base_url: "https://example.com".into(),
             wire_api: "openai".into(),
             temp_env_key: "PRISMCTL_CODEX_API_KEY".into(),
+            env_key: None,
             requires_openai_auth: false,
             model: "gpt-5".into(),
         };
@@ -84,6 +532,16 @@ mod tests {
         assert!(out.contains("model_provider = \"prismctl\""));
         assert!(out.contains("[model_providers.prismctl]"));
         assert!(out.contains("base_url = \"https://example.com\""));
+        assert!(!out.contains("\nenv_key"));
+
+        let corp = CodexProviderConfig {
+            env_key: Some("CORP_API_KEY".into()),
+            ..cfg
+        };
+        let out = upsert_codex_provider_in_config_toml(&out, &corp, false);
+        let entry = &list_codex_providers(&out)[0];
+        assert_eq!(entry.auth_key.as_deref(), Some("PRISMCTL_CODEX_API_KEY"));
+        assert_eq!(entry.env_key.as_deref(), Some("CORP_API_KEY"));
     }
 
     #[test]
@@ -92,4 +550,140 @@ mod tests {
             upsert_codex_api_key_in_auth_json("{}", "PRISMCTL_CODEX_API_KEY", "sk-x").expect("ok");
         assert!(out.contains("\"PRISMCTL_CODEX_API_KEY\": \"sk-x\""));
     }
+
+    #[test]
+    fn manages_multiple_providers() {
+        let provider = |id: &str| CodexProviderConfig {
+            provider_id: id.into(),
+            display_name: id.into(),
+            base_url: format!("https://{id}.example.com/v1"),
+            wire_api: "chat".into(),
+            temp_env_key: default_codex_env_key(id),
+            env_key: None,
+            requires_openai_auth: false,
+            model: "m".into(),
+        };
+        let config = upsert_codex_provider_in_config_toml("", &provider("work"), true);
+        let config = upsert_codex_provider_in_config_toml(&config, &provider("home-lab"), false);
+        let ids: Vec<String> = list_codex_providers(&config)
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(ids, vec!["work".to_string(), "home-lab".to_string()]);
+        assert_eq!(active_codex_provider(&config).as_deref(), Some("work"));
+        assert_eq!(
+            default_codex_env_key("home-lab"),
+            "PRISMCTL_HOME_LAB_API_KEY"
+        );
+
+        assert!(remove_codex_provider(&config, "", "work").is_err());
+        let config = use_codex_provider_in_config_toml(&config, "home-lab").expect("use");
+        assert!(use_codex_provider_in_config_toml(&config, "nope").is_err());
+
+        let auth = "{\"PRISMCTL_WORK_API_KEY\": \"a\", \"PRISMCTL_HOME_LAB_API_KEY\": \"b\"}";
+        let (config, auth) = remove_codex_provider(&config, auth, "work").expect("remove");
+        assert!(!config.contains("[model_providers.work]"));
+        assert!(!auth.contains("PRISMCTL_WORK_API_KEY"));
+        assert!(auth.contains("PRISMCTL_HOME_LAB_API_KEY"));
+    }
+
+    #[test]
+    fn manages_named_profiles() {
+        let fast = CodexProfileEntry {
+            name: "fast".into(),
+            model: Some("gpt-4.1-mini".into()),
+            model_reasoning_effort: Some("low".into()),
+            ..Default::default()
+        };
+        let config =
+            upsert_codex_profile_in_config_toml("model = \"x\"\n", &fast, true).expect("upsert");
+        assert!(config.contains(
+            "[profiles.fast]\nmodel = \"gpt-4.1-mini\"\nmodel_reasoning_effort = \"low\"\n"
+        ));
+        assert_eq!(active_codex_profile(&config).as_deref(), Some("fast"));
+
+        let update = CodexProfileEntry {
+            name: "fast".into(),
+            approval_policy: Some("never".into()),
+            ..Default::default()
+        };
+        let config = upsert_codex_profile_in_config_toml(&config, &update, false).expect("update");
+        let listed = list_codex_profiles(&config);
+        assert_eq!(listed[0].model.as_deref(), Some("gpt-4.1-mini"));
+        assert_eq!(listed[0].approval_policy.as_deref(), Some("never"));
+
+        let bad = CodexProfileEntry {
+            approval_policy: Some("sometimes".into()),
+            ..update
+        };
+        assert!(upsert_codex_profile_in_config_toml(&config, &bad, false).is_err());
+        assert!(use_codex_profile_in_config_toml(&config, "slow").is_err());
+
+        let config = remove_codex_profile(&config, "fast").expect("remove");
+        assert_eq!(config, "model = \"x\"\n");
+    }
+
+    #[test]
+    fn mcp_servers_render_as_toml_tables() {
+        let context7 = builtin_mcp_server("context7").expect("context7");
+        let websearch = builtin_mcp_server("open-websearch").expect("open-websearch");
+        let out = upsert_codex_mcp_server_in_config_toml("model = \"x\"\n", context7).expect("ok");
+        let out = upsert_codex_mcp_server_in_config_toml(&out, websearch).expect("ok");
+        assert!(out.contains(
+            "[mcp_servers.context7]\nurl = \"https://mcp.context7.com/mcp\"\n\n[mcp_servers.context7.env_http_headers]\nCONTEXT7_API_KEY = \"CONTEXT7_API_KEY\"\n"
+        ));
+        assert!(out.contains(
+            "[mcp_servers.open-websearch]\ncommand = \"npx\"\nargs = [\"-y\", \"open-websearch@latest\"]\n\n[mcp_servers.open-websearch.env]\nMODE = \"stdio\"\n"
+        ));
+        assert_eq!(
+            list_codex_mcp_servers(&out),
+            vec!["context7", "open-websearch"]
+        );
+
+        // Re-adding replaces the entry instead of duplicating it.
+        let again = upsert_codex_mcp_server_in_config_toml(&out, context7).expect("ok");
+        assert_eq!(again.matches("[mcp_servers.context7]").count(), 1);
+
+        let out = remove_codex_mcp_server_in_config_toml(&out, "context7").expect("remove");
+        assert!(!out.contains("context7"));
+        assert!(remove_codex_mcp_server_in_config_toml(&out, "context7").is_err());
+    }
+
+    #[test]
+    fn persona_block_preserves_project_instructions() {
+        let name = "prismctl-engineer-professional";
+        let tpl = templates::codex_agent_template(name, TemplateLang::En).expect("template");
+        let project = "# Project rules\n\nRun `cargo test` before committing.\n";
+
+        assert!(codex_persona_needs_backup(project));
+        assert!(!codex_persona_needs_backup("  \n"));
+        let out = render_codex_persona(project, name, tpl);
+        assert!(out.starts_with(project));
+        assert!(!codex_persona_needs_backup(&out));
+        assert!(out.contains(
+            "<!-- prismctl:persona:start -->\n<!-- persona: prismctl-engineer-professional -->\n"
+        ));
+        assert!(!out.contains("\n---\n"));
+        assert_eq!(current_codex_persona(&out).as_deref(), Some(name));
+
+        let other = "prismctl-rem-engineer";
+        let tpl2 = templates::codex_agent_template(other, TemplateLang::En).expect("template");
+        let out = render_codex_persona(&out, other, tpl2);
+        assert_eq!(out.matches("prismctl:persona:start").count(), 1);
+        assert_eq!(current_codex_persona(&out).as_deref(), Some(other));
+
+        assert_eq!(clear_codex_persona(&out).as_deref(), Some(project));
+        assert!(clear_codex_persona(project).is_none());
+
+        // A file fully written by an older `agent use` is recognized and replaced (after a backup).
+        assert!(codex_persona_needs_backup(tpl));
+        assert_eq!(current_codex_persona(tpl).as_deref(), Some(name));
+        assert_eq!(
+            render_codex_persona(tpl, other, tpl2)
+                .matches("persona:start")
+                .count(),
+            1
+        );
+        assert!(!render_codex_persona(tpl, other, tpl2).contains(&format!("name: {name}")));
+    }
 }
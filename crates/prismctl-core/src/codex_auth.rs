@@ -0,0 +1,230 @@
+use crate::codex::{self, CODEX_BUILTIN_PROVIDERS};
+use crate::json_text;
+use crate::redact::mask_secret;
+use crate::toml_text::{self, TomlScalar};
+use serde_json::Value;
+
+const OPENAI_API_KEY: &str = "OPENAI_API_KEY";
+/// Base URL prefix of the OpenAI API, the only endpoint that accepts ChatGPT login tokens.
+const OPENAI_BASE_URL: &str = "https://api.openai.com/";
+/// ChatGPT login state written by `codex login`.
+const CHATGPT_KEYS: &[&str] = &["tokens", "last_refresh"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How Codex authenticates, as recorded in `auth.json`.
+pub enum CodexAuthMode {
+    /// ChatGPT login tokens (`codex login`).
+    ChatGpt,
+    /// `OPENAI_API_KEY` stored in `auth.json`.
+    ApiKey,
+    /// Both ChatGPT tokens and an API key are present.
+    Mixed,
+    None,
+}
+
+impl CodexAuthMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ChatGpt => "chatgpt",
+            Self::ApiKey => "api-key",
+            Self::Mixed => "mixed",
+            Self::None => "none",
+        }
+    }
+
+    /// Parse a mode accepted by `codex auth use`.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "chatgpt" => Ok(Self::ChatGpt),
+            "api-key" => Ok(Self::ApiKey),
+            _ => Err(format!(
+                "不支持的 Codex 认证方式: {value}（可选: api-key, chatgpt）"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Redacted summary of Codex `auth.json`, safe to print.
+pub struct CodexAuthStatus {
+    pub mode: CodexAuthMode,
+    /// Masked `OPENAI_API_KEY` (last 4 characters only).
+    pub api_key: Option<String>,
+    /// Other string keys (e.g. Prismctl provider keys), names only.
+    pub provider_keys: Vec<String>,
+}
+
+/// Detect the auth mode recorded in Codex `auth.json` content.
+pub fn detect_codex_auth(auth: &str) -> Result<CodexAuthStatus, String> {
+    let obj = if auth.trim().is_empty() {
+        Value::Object(Default::default())
+    } else {
+        serde_json::from_str::<Value>(auth).map_err(|e| format!("JSON 解析失败: {e}"))?
+    };
+    let map = obj
+        .as_object()
+        .ok_or_else(|| "JSON 顶层不是对象".to_string())?;
+
+    let api_key = map
+        .get(OPENAI_API_KEY)
+        .and_then(Value::as_str)
+        .filter(|v| !v.is_empty());
+    let has_tokens = map.get("tokens").is_some_and(|v| !v.is_null());
+    let mode = match (has_tokens, api_key.is_some()) {
+        (true, true) => CodexAuthMode::Mixed,
+        (true, false) => CodexAuthMode::ChatGpt,
+        (false, true) => CodexAuthMode::ApiKey,
+        (false, false) => CodexAuthMode::None,
+    };
+    let provider_keys = map
+        .iter()
+        .filter(|(k, v)| k.as_str() != OPENAI_API_KEY && v.is_string())
+        .filter(|(k, _)| !CHATGPT_KEYS.contains(&k.as_str()))
+        .map(|(k, _)| k.clone())
+        .collect();
+
+    Ok(CodexAuthStatus {
+        mode,
+        api_key: api_key.map(mask_secret),
+        provider_keys,
+    })
+}
+
+/// Switch Codex to `mode`, returning the new `(config.toml, auth.json)` contents.
+///
+/// - `api-key`: stores `OPENAI_API_KEY` (from `api_key` or the existing one) and drops ChatGPT tokens.
+/// - `chatgpt`: clears `OPENAI_API_KEY` and keeps the tokens from `codex login`, which must exist.
+///
+/// The active custom provider gets a matching `requires_openai_auth`; built-in providers need none.
+/// `chatgpt` is refused while the active custom provider points at a non-OpenAI `base_url`, so
+/// ChatGPT tokens are never sent to a third-party gateway.
+pub fn use_codex_auth(
+    config: &str,
+    auth: &str,
+    mode: CodexAuthMode,
+    api_key: Option<&str>,
+) -> Result<(String, String), String> {
+    let status = detect_codex_auth(auth)?;
+    let custom_provider = codex::active_codex_provider(config)
+        .filter(|id| !CODEX_BUILTIN_PROVIDERS.contains(&id.as_str()))
+        .and_then(|id| {
+            codex::list_codex_providers(config)
+                .into_iter()
+                .find(|p| p.id == id)
+        });
+    if mode == CodexAuthMode::ChatGpt {
+        if let Some(p) = &custom_provider {
+            let base_url = p.base_url.as_deref().unwrap_or_default();
+            if !base_url.is_empty() && !base_url.starts_with(OPENAI_BASE_URL) {
+                return Err(format!(
+                    "当前 model_provider {} 指向 {base_url}，ChatGPT 登录仅适用于 OpenAI；请先运行 `prismctl codex provider use openai`，或改用 api-key",
+                    p.id
+                ));
+            }
+        }
+    }
+    let next_auth = match mode {
+        CodexAuthMode::ApiKey => {
+            let key = match api_key {
+                Some(k) => k.to_string(),
+                None => json_text::string_at_path(auth, &[OPENAI_API_KEY])?
+                    .filter(|v| !v.is_empty())
+                    .ok_or_else(|| {
+                        "auth.json 中没有 OPENAI_API_KEY，请提供 --api-key".to_string()
+                    })?,
+            };
+            let mut out =
+                json_text::upsert_value_at_path(auth, &[OPENAI_API_KEY], Value::String(key))?;
+            for k in CHATGPT_KEYS {
+                if let Some(next) = json_text::remove_value_at_path(&out, &[k])? {
+                    out = next;
+                }
+            }
+            out
+        }
+        CodexAuthMode::ChatGpt => {
+            if !matches!(status.mode, CodexAuthMode::ChatGpt | CodexAuthMode::Mixed) {
+                return Err("auth.json 中没有 ChatGPT 登录信息，请先运行 `codex login`".to_string());
+            }
+            json_text::upsert_value_at_path(auth, &[OPENAI_API_KEY], Value::Null)?
+        }
+        CodexAuthMode::Mixed | CodexAuthMode::None => {
+            return Err(format!("不支持切换到认证方式: {}", mode.as_str()));
+        }
+    };
+
+    let requires_openai_auth = mode == CodexAuthMode::ChatGpt;
+    let next_config = match custom_provider {
+        Some(p) => toml_text::upsert_table_kv(
+            config,
+            &format!("model_providers.{}", p.id),
+            &[(
+                "requires_openai_auth".to_string(),
+                TomlScalar::Bool(requires_openai_auth),
+            )],
+        ),
+        None => config.to_string(),
+    };
+    Ok((next_config, next_auth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHATGPT_AUTH: &str = r#"{
+  "OPENAI_API_KEY": null,
+  "PRISMCTL_CODEX_API_KEY": "sk-prismctl-1234567890",
+  "last_refresh": "2026-01-01T00:00:00Z",
+  "tokens": {"access_token": "a", "refresh_token": "r", "account_id": "acc"}
+}"#;
+
+    #[test]
+    fn detects_mode_without_leaking_secrets() {
+        let status = detect_codex_auth(CHATGPT_AUTH).expect("parse");
+        assert_eq!(status.mode, CodexAuthMode::ChatGpt);
+        assert_eq!(status.provider_keys, vec!["PRISMCTL_CODEX_API_KEY"]);
+
+        let status = detect_codex_auth(r#"{"OPENAI_API_KEY": "sk-abcdefgh1234"}"#).expect("parse");
+        assert_eq!(status.mode, CodexAuthMode::ApiKey);
+        assert_eq!(status.api_key.as_deref(), Some("****1234"));
+        assert_eq!(
+            detect_codex_auth("").expect("empty").mode,
+            CodexAuthMode::None
+        );
+    }
+
+    #[test]
+    fn switches_auth_and_provider_flag_together() {
+        let config = "model_provider = \"prismctl\"\n\n[model_providers.prismctl]\nrequires_openai_auth = false\n";
+        let (config, auth) =
+            use_codex_auth(config, CHATGPT_AUTH, CodexAuthMode::ChatGpt, None).expect("chatgpt");
+        assert!(config.contains("requires_openai_auth = true"));
+        assert!(auth.contains("\"tokens\""));
+
+        let (config, auth) =
+            use_codex_auth(&config, &auth, CodexAuthMode::ApiKey, Some("sk-new")).expect("api");
+        assert!(config.contains("requires_openai_auth = false"));
+        let status = detect_codex_auth(&auth).expect("parse");
+        assert_eq!(status.mode, CodexAuthMode::ApiKey);
+        assert!(!auth.contains("tokens") && !auth.contains("last_refresh"));
+        assert!(auth.contains("PRISMCTL_CODEX_API_KEY"));
+
+        let err = use_codex_auth("", &auth, CodexAuthMode::ChatGpt, None).expect_err("no tokens");
+        assert!(err.contains("codex login"));
+    }
+
+    #[test]
+    fn chatgpt_is_refused_for_third_party_providers() {
+        let gateway = "model_provider = \"gw\"\n\n[model_providers.gw]\nbase_url = \"https://gw.example.com/v1\"\nrequires_openai_auth = false\n";
+        let err = use_codex_auth(gateway, CHATGPT_AUTH, CodexAuthMode::ChatGpt, None)
+            .expect_err("third-party gateway");
+        assert!(err.contains("https://gw.example.com/v1"));
+        assert!(use_codex_auth(gateway, CHATGPT_AUTH, CodexAuthMode::ApiKey, Some("sk-x")).is_ok());
+
+        let openai = gateway.replace("https://gw.example.com/v1", "https://api.openai.com/v1");
+        let (config, _) =
+            use_codex_auth(&openai, CHATGPT_AUTH, CodexAuthMode::ChatGpt, None).expect("openai");
+        assert!(config.contains("requires_openai_auth = true"));
+    }
+}
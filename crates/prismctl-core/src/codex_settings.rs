@@ -0,0 +1,270 @@
+use crate::parse_choice;
+use crate::toml_text::{self, TomlScalar};
+
+const SANDBOX_TABLE: &str = "sandbox_workspace_write";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `approval_policy`: when Codex asks before running commands.
+pub enum ApprovalPolicy {
+    Untrusted,
+    OnFailure,
+    OnRequest,
+    Never,
+}
+
+impl ApprovalPolicy {
+    pub const ALL: &'static [Self] = &[
+        Self::Untrusted,
+        Self::OnFailure,
+        Self::OnRequest,
+        Self::Never,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Untrusted => "untrusted",
+            Self::OnFailure => "on-failure",
+            Self::OnRequest => "on-request",
+            Self::Never => "never",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("approval_policy", value, Self::ALL, |v| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `sandbox_mode`: what commands run by Codex may touch.
+pub enum SandboxMode {
+    ReadOnly,
+    WorkspaceWrite,
+    DangerFullAccess,
+}
+
+impl SandboxMode {
+    pub const ALL: &'static [Self] =
+        &[Self::ReadOnly, Self::WorkspaceWrite, Self::DangerFullAccess];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ReadOnly => "read-only",
+            Self::WorkspaceWrite => "workspace-write",
+            Self::DangerFullAccess => "danger-full-access",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("sandbox_mode", value, Self::ALL, |v| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `model_reasoning_effort` for reasoning models.
+pub enum ReasoningEffort {
+    Minimal,
+    Low,
+    Medium,
+    High,
+}
+
+impl ReasoningEffort {
+    pub const ALL: &'static [Self] = &[Self::Minimal, Self::Low, Self::Medium, Self::High];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Minimal => "minimal",
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("model_reasoning_effort", value, Self::ALL, |v| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `model_verbosity` for GPT-5 family models.
+pub enum Verbosity {
+    Low,
+    Medium,
+    High,
+}
+
+impl Verbosity {
+    pub const ALL: &'static [Self] = &[Self::Low, Self::Medium, Self::High];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        parse_choice("model_verbosity", value, Self::ALL, |v| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Codex behavior settings in `config.toml`; `None` fields are left untouched.
+pub struct CodexSettings {
+    pub approval_policy: Option<ApprovalPolicy>,
+    pub sandbox_mode: Option<SandboxMode>,
+    pub model_reasoning_effort: Option<ReasoningEffort>,
+    pub model_verbosity: Option<Verbosity>,
+    /// `[sandbox_workspace_write]` options (only used with `sandbox_mode = "workspace-write"`).
+    pub writable_roots: Option<Vec<String>>,
+    pub network_access: Option<bool>,
+    pub exclude_tmpdir_env_var: Option<bool>,
+    pub exclude_slash_tmp: Option<bool>,
+}
+
+impl CodexSettings {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn root_kv(&self) -> Vec<(&'static str, &'static str)> {
+        [
+            ("approval_policy", self.approval_policy.map(|v| v.as_str())),
+            ("sandbox_mode", self.sandbox_mode.map(|v| v.as_str())),
+            (
+                "model_reasoning_effort",
+                self.model_reasoning_effort.map(|v| v.as_str()),
+            ),
+            ("model_verbosity", self.model_verbosity.map(|v| v.as_str())),
+        ]
+        .into_iter()
+        .filter_map(|(k, v)| Some((k, v?)))
+        .collect()
+    }
+
+    fn sandbox_kv(&self) -> Vec<(String, TomlScalar)> {
+        let mut kv = Vec::new();
+        if let Some(roots) = &self.writable_roots {
+            kv.push((
+                "writable_roots".to_string(),
+                TomlScalar::StrArray(roots.clone()),
+            ));
+        }
+        let flags = [
+            ("network_access", self.network_access),
+            ("exclude_tmpdir_env_var", self.exclude_tmpdir_env_var),
+            ("exclude_slash_tmp", self.exclude_slash_tmp),
+        ];
+        for (k, v) in flags {
+            if let Some(v) = v {
+                kv.push((k.to_string(), TomlScalar::Bool(v)));
+            }
+        }
+        kv
+    }
+}
+
+/// Write `settings` into Codex `config.toml` content (as text).
+pub fn apply_codex_settings(content: &str, settings: &CodexSettings) -> String {
+    let mut out = content.to_string();
+    // New root keys are inserted at the top, so go in reverse to keep the documented order.
+    for (k, v) in settings.root_kv().into_iter().rev() {
+        out = toml_text::upsert_root_key(&out, k, TomlScalar::Str(v.to_string()));
+    }
+    let sandbox = settings.sandbox_kv();
+    if !sandbox.is_empty() {
+        out = toml_text::upsert_table_kv(&out, SANDBOX_TABLE, &sandbox);
+    }
+    out
+}
+
+/// Current values of the settings Prismctl manages, as `(key, raw TOML value)` in display order.
+///
+/// Sandbox options are reported as `sandbox_workspace_write.<key>`; unset keys are omitted.
+pub fn read_codex_settings(content: &str) -> Vec<(String, String)> {
+    let root = toml_text::read_table_raw(content, "");
+    let sandbox = toml_text::read_table_raw(content, SANDBOX_TABLE);
+    let mut out = Vec::new();
+    for key in [
+        "approval_policy",
+        "sandbox_mode",
+        "model_reasoning_effort",
+        "model_verbosity",
+    ] {
+        if let Some((_, v)) = root.iter().find(|(k, _)| k == key) {
+            out.push((key.to_string(), v.clone()));
+        }
+    }
+    for key in [
+        "writable_roots",
+        "network_access",
+        "exclude_tmpdir_env_var",
+        "exclude_slash_tmp",
+    ] {
+        if let Some((_, v)) = sandbox.iter().find(|(k, _)| k == key) {
+            out.push((format!("{SANDBOX_TABLE}.{key}"), v.clone()));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_unknown_values() {
+        assert_eq!(
+            SandboxMode::parse("workspace-write"),
+            Ok(SandboxMode::WorkspaceWrite)
+        );
+        let err = ApprovalPolicy::parse("sometimes").expect_err("invalid");
+        assert!(err.contains("untrusted, on-failure, on-request, never"));
+    }
+
+    #[test]
+    fn applies_and_reads_back_settings() {
+        let settings = CodexSettings {
+            approval_policy: Some(ApprovalPolicy::OnRequest),
+            sandbox_mode: Some(SandboxMode::WorkspaceWrite),
+            writable_roots: Some(vec!["/tmp/cache".into()]),
+            network_access: Some(true),
+            ..Default::default()
+        };
+        let out = apply_codex_settings("model = \"gpt-5\"\n", &settings);
+        assert!(out.contains("approval_policy = \"on-request\"\n"));
+        assert!(out.contains(
+            "[sandbox_workspace_write]\nwritable_roots = [\"/tmp/cache\"]\nnetwork_access = true\n"
+        ));
+        assert_eq!(
+            read_codex_settings(&out),
+            vec![
+                ("approval_policy".to_string(), "\"on-request\"".to_string()),
+                (
+                    "sandbox_mode".to_string(),
+                    "\"workspace-write\"".to_string()
+                ),
+                (
+                    "sandbox_workspace_write.writable_roots".to_string(),
+                    "[\"/tmp/cache\"]".to_string()
+                ),
+                (
+                    "sandbox_workspace_write.network_access".to_string(),
+                    "true".to_string()
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn reads_hand_written_multi_line_writable_roots() {
+        let content = "[sandbox_workspace_write]\nwritable_roots = [\n  \"/tmp/cache\", # build cache\n  \"~/.npm\",\n]\n";
+        assert_eq!(
+            read_codex_settings(content),
+            vec![(
+                "sandbox_workspace_write.writable_roots".to_string(),
+                "[\"/tmp/cache\", \"~/.npm\"]".to_string()
+            )]
+        );
+    }
+}
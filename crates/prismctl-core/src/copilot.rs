@@ -0,0 +1,101 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::managed_block::upsert_managed_block;
+use crate::templates::{self, TemplateLang};
+use std::path::Path;
+
+const BLOCK_START: &str = "<!-- prismctl:start -->";
+const BLOCK_END: &str = "<!-- prismctl:end -->";
+
+/// Plan GitHub Copilot project files: `.github/copilot-instructions.md` and
+/// `.github/prompts/prismctl-*.prompt.md`.
+///
+/// Both are written through a managed block, so hand-written instructions (and user edits to a
+/// prompt's frontmatter) survive updates. `read_existing` returns the current content of a path,
+/// if any.
+pub fn plan_copilot_files(
+    project_root: &Path,
+    lang: TemplateLang,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> ChangeSet {
+    let github_dir = project_root.join(".github");
+    let prompts_dir = github_dir.join("prompts");
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll {
+        path: prompts_dir.clone(),
+    });
+
+    let instructions_path = github_dir.join("copilot-instructions.md");
+    let existing = read_existing(&instructions_path).unwrap_or_default();
+    cs.push(Change::WriteFile {
+        path: instructions_path,
+        bytes: render_copilot_instructions(&existing, lang).into_bytes(),
+        overwrite: true,
+    });
+
+    for (name, contents) in templates::workflow_templates(lang) {
+        let path = prompts_dir.join(format!("prismctl-{name}.prompt.md"));
+        let next = render_copilot_prompt(read_existing(&path).as_deref(), contents);
+        cs.push(Change::WriteFile {
+            path,
+            bytes: next.into_bytes(),
+            overwrite: true,
+        });
+    }
+    cs
+}
+
+/// Render and upsert the Prismctl-managed block for `.github/copilot-instructions.md`.
+pub fn render_copilot_instructions(existing: &str, lang: TemplateLang) -> String {
+    let block = match lang {
+        TemplateLang::ZhCn => include_str!("../assets/copilot/zh-CN/copilot-instructions.md"),
+        TemplateLang::En => include_str!("../assets/copilot/en/copilot-instructions.md"),
+    };
+    upsert_managed_block(existing, BLOCK_START, BLOCK_END, block)
+}
+
+fn render_copilot_prompt(existing: Option<&str>, template: &str) -> String {
+    let (frontmatter, body) = templates::split_frontmatter(template);
+    if let Some(existing) = existing {
+        return upsert_managed_block(existing, BLOCK_START, BLOCK_END, body);
+    }
+
+    let description = frontmatter
+        .and_then(|f| templates::frontmatter_value(f, "description"))
+        .unwrap_or_default();
+    let header = format!(
+        "---\ndescription: '{}'\nmode: agent\n---\n\n",
+        description.replace('\'', "''")
+    );
+    upsert_managed_block(&header, BLOCK_START, BLOCK_END, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn plans_instructions_and_prompts() {
+        let root = PathBuf::from("/tmp/project");
+        let cs = plan_copilot_files(&root, TemplateLang::En, &|_| None);
+
+        let instructions = cs.written(".github/copilot-instructions.md");
+        assert!(instructions.contains(BLOCK_START));
+
+        let prompt = cs.written(".github/prompts/prismctl-git-commit.prompt.md");
+        assert!(prompt.starts_with("---\ndescription: 'Analyze changes"));
+        assert!(prompt.contains("mode: agent\n"));
+    }
+
+    #[test]
+    fn preserves_hand_written_instructions() {
+        let root = PathBuf::from("/tmp/project");
+        let cs = plan_copilot_files(&root, TemplateLang::En, &|_| {
+            Some("# Team rules\n\nUse tabs.\n".to_string())
+        });
+
+        let instructions = cs.written(".github/copilot-instructions.md");
+        assert!(instructions.starts_with("# Team rules\n\nUse tabs.\n"));
+        assert!(instructions.contains("Prompt files"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::managed_block::upsert_managed_block;
+use crate::templates::{self, TemplateLang};
+use std::path::Path;
+
+const BLOCK_START: &str = "<!-- prismctl:start -->";
+const BLOCK_END: &str = "<!-- prismctl:end -->";
+
+/// Plan Cursor project rules under `.cursor/rules/prismctl-*.mdc`.
+///
+/// Workflow/git templates become agent-requested rules (`alwaysApply: false`); the optional
+/// persona (a Codex agent name) becomes an always-applied rule. New files get frontmatter plus a
+/// managed block; existing files only have their managed block updated, so user edits survive.
+/// `read_existing` returns the current content of a path, if any.
+pub fn plan_cursor_rules(
+    project_root: &Path,
+    lang: TemplateLang,
+    persona: Option<&str>,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> Result<ChangeSet, String> {
+    let rules_dir = project_root.join(".cursor").join("rules");
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll {
+        path: rules_dir.clone(),
+    });
+
+    let mut rules: Vec<(String, &'static str, bool)> = templates::workflow_templates(lang)
+        .into_iter()
+        .map(|(name, contents)| (format!("prismctl-{name}.mdc"), contents, false))
+        .collect();
+    if let Some(name) = persona {
+        let contents = templates::codex_agent_template(name, lang)
+            .ok_or_else(|| format!("未知 agent: {name}"))?;
+        rules.push(("prismctl-persona.mdc".to_string(), contents, true));
+    }
+
+    for (file_name, contents, always_apply) in rules {
+        let path = rules_dir.join(file_name);
+        let next = render_cursor_rule(read_existing(&path).as_deref(), contents, always_apply);
+        cs.push(Change::WriteFile {
+            path,
+            bytes: next.into_bytes(),
+            overwrite: true,
+        });
+    }
+    Ok(cs)
+}
+
+fn render_cursor_rule(existing: Option<&str>, template: &str, always_apply: bool) -> String {
+    let (frontmatter, body) = templates::split_frontmatter(template);
+    if let Some(existing) = existing {
+        return upsert_managed_block(existing, BLOCK_START, BLOCK_END, body);
+    }
+
+    let description = frontmatter
+        .and_then(|f| templates::frontmatter_value(f, "description"))
+        .unwrap_or_default();
+    let header = format!(
+        "---\ndescription: {}\nglobs:\nalwaysApply: {}\n---\n\n",
+        templates::yaml_scalar(&description),
+        always_apply
+    );
+    upsert_managed_block(&header, BLOCK_START, BLOCK_END, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn new_rules_get_frontmatter() {
+        let root = PathBuf::from("/tmp/project");
+        let cs = plan_cursor_rules(
+            &root,
+            TemplateLang::En,
+            Some("prismctl-engineer-professional"),
+            &|_| None,
+        )
+        .expect("ok");
+
+        let commit = cs.written(".cursor/rules/prismctl-git-commit.mdc");
+        assert!(commit.starts_with("---\ndescription: Analyze changes"));
+        assert!(commit.contains("alwaysApply: false\n"));
+        assert!(commit.contains(BLOCK_START));
+
+        let persona = cs.written(".cursor/rules/prismctl-persona.mdc");
+        assert!(persona.contains("alwaysApply: true\n"));
+    }
+
+    #[test]
+    fn existing_rules_keep_user_content() {
+        let root = PathBuf::from("/tmp/project");
+        let existing =
+            "---\ndescription: mine\nglobs: src/**\nalwaysApply: true\n---\n\nMy notes\n";
+        let cs = plan_cursor_rules(&root, TemplateLang::En, None, &|_| {
+            Some(existing.to_string())
+        })
+        .expect("ok");
+
+        let workflow = cs.written(".cursor/rules/prismctl-workflow.mdc");
+        assert!(workflow.starts_with(existing));
+        assert!(workflow.contains(BLOCK_END));
+    }
+
+    #[test]
+    fn description_is_quoted_when_needed() {
+        let template = "---\ndescription: Fix: x\n---\n\nBody\n";
+        let out = render_cursor_rule(None, template, false);
+        assert!(out.starts_with("---\ndescription: \"Fix: x\"\nglobs:\n"));
+        assert_eq!(templates::yaml_scalar("\"hi\": x"), r#""\"hi\": x""#);
+        assert_eq!(templates::yaml_scalar("Analyze changes"), "Analyze changes");
+    }
+
+    #[test]
+    fn unknown_persona_is_rejected() {
+        let root = PathBuf::from("/tmp/project");
+        assert!(plan_cursor_rules(&root, TemplateLang::En, Some("nope"), &|_| None).is_err());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::claude_permissions::{split_tool_list, validate_permission_rule};
+use crate::paths::{PrismctlHome, Tool};
+use crate::templates;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Subdirectory holding Prismctl's bundled templates; never treated as user-authored.
+const RESERVED_NAMESPACE: &str = "prismctl";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A markdown slash-command flavor: Codex custom prompts or Claude Code commands.
+pub enum PromptKind {
+    /// `~/.codex/prompts/<name>.md` (`/prompts:<name>`).
+    CodexPrompt,
+    /// `~/.claude/commands/<name>.md` or `<project>/.claude/commands/<name>.md` (`/<name>`).
+    ClaudeCommand,
+}
+
+impl PromptKind {
+    /// Frontmatter keys Prismctl scaffolds and validates, in the order they are written.
+    pub fn known_keys(self) -> &'static [&'static str] {
+        match self {
+            Self::CodexPrompt => &["description", "argument-hint"],
+            Self::ClaudeCommand => &["description", "argument-hint", "allowed-tools"],
+        }
+    }
+
+    /// User-scope directory, or the project directory when `project_root` is set (Claude only).
+    pub fn dir(self, home: &PrismctlHome, project_root: Option<&Path>) -> PathBuf {
+        match (self, project_root) {
+            (Self::ClaudeCommand, Some(root)) => root.join(".claude").join("commands"),
+            (Self::ClaudeCommand, None) => home.tool_root(Tool::ClaudeCode).join("commands"),
+            (Self::CodexPrompt, _) => home.tool_root(Tool::Codex).join("prompts"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A user-authored prompt/command file found on disk.
+pub struct CustomPrompt {
+    pub name: String,
+    pub path: PathBuf,
+    /// Frontmatter `key: value` pairs in file order.
+    pub fields: Vec<(String, String)>,
+    /// Validation error, if the file's frontmatter is invalid.
+    pub error: Option<String>,
+}
+
+impl CustomPrompt {
+    pub fn field(&self, key: &str) -> Option<&str> {
+        field(&self.fields, key)
+    }
+}
+
+pub fn validate_prompt_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || name.starts_with('-')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("无效的名称: {name}（仅允许字母、数字、- 和 _）"));
+    }
+    if name == RESERVED_NAMESPACE {
+        return Err(format!("名称 {name} 为 Prismctl 内置模板保留"));
+    }
+    Ok(())
+}
+
+/// Validate the frontmatter fields Prismctl knows about; unknown keys are left alone.
+pub fn validate_prompt_fields(kind: PromptKind, fields: &[(String, String)]) -> Result<(), String> {
+    for (key, value) in fields {
+        if value.contains('\n') {
+            return Err(format!("frontmatter 字段 {key} 不能包含换行"));
+        }
+        match key.as_str() {
+            "description" if value.trim().is_empty() => {
+                return Err("frontmatter 字段 description 不能为空".to_string());
+            }
+            "allowed-tools" if kind == PromptKind::CodexPrompt => {
+                return Err("Codex prompts 不支持 allowed-tools".to_string());
+            }
+            "allowed-tools" => {
+                for rule in split_tool_list(value) {
+                    validate_permission_rule(rule)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    if field(fields, "description").is_none() {
+        return Err("frontmatter 缺少 description".to_string());
+    }
+    Ok(())
+}
+
+/// Split `content` into frontmatter fields and body. Files without frontmatter have no fields.
+pub fn parse_prompt(content: &str) -> Result<(Vec<(String, String)>, String), String> {
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return Ok((Vec::new(), content.to_string()));
+    };
+    let (front, body) = match rest.find("\n---\n") {
+        Some(i) => (&rest[..i], &rest[i + 5..]),
+        None => match rest.strip_suffix("\n---") {
+            Some(front) => (front, ""),
+            None => return Err("frontmatter 缺少结束标记 ---".to_string()),
+        },
+    };
+    let mut fields = Vec::new();
+    for line in front.lines().filter(|l| !l.trim().is_empty()) {
+        let (key, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("无法解析的 frontmatter 行: {line}"))?;
+        fields.push((key.trim().to_string(), unquote(value.trim())));
+    }
+    Ok((fields, body.to_string()))
+}
+
+/// Decode a quoted YAML scalar (the inverse of [`templates::yaml_scalar`]); plain values pass through.
+fn unquote(value: &str) -> String {
+    if let Some(inner) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        return inner.replace("''", "'");
+    }
+    let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+        return value.to_string();
+    };
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Frontmatter plus `body`; values are quoted when a plain YAML scalar would be misread.
+pub fn render_prompt(fields: &[(String, String)], body: &str) -> String {
+    let mut out = String::from("---\n");
+    for (k, v) in fields {
+        out.push_str(&format!("{k}: {}\n", templates::yaml_scalar(v)));
+    }
+    out.push_str("---\n");
+    out.push_str(body);
+    out
+}
+
+/// A new prompt file with the given frontmatter and a placeholder body.
+pub fn scaffold_prompt(kind: PromptKind, fields: &[(String, String)]) -> Result<String, String> {
+    validate_prompt_fields(kind, fields)?;
+    let body = "\nDescribe what this command should do.\n\nArguments: $ARGUMENTS\n";
+    Ok(render_prompt(&ordered(kind, fields), body))
+}
+
+/// Update frontmatter fields in existing `content`, keeping other keys and the body.
+///
+/// An empty value in `patch` removes that key.
+pub fn edit_prompt(
+    kind: PromptKind,
+    content: &str,
+    patch: &[(String, String)],
+) -> Result<String, String> {
+    let (mut fields, body) = parse_prompt(content)?;
+    for (key, value) in patch {
+        if value.is_empty() {
+            fields.retain(|(k, _)| k != key);
+            continue;
+        }
+        match fields.iter_mut().find(|(k, _)| k == key) {
+            Some((_, v)) => *v = value.clone(),
+            None => fields.push((key.clone(), value.clone())),
+        }
+    }
+    validate_prompt_fields(kind, &fields)?;
+    let body = if body.is_empty() { "\n" } else { &body };
+    Ok(render_prompt(&ordered(kind, &fields), body))
+}
+
+/// Known keys first (in scaffold order), then any others as found.
+fn ordered(kind: PromptKind, fields: &[(String, String)]) -> Vec<(String, String)> {
+    let known = kind.known_keys();
+    let mut out: Vec<(String, String)> = known
+        .iter()
+        .filter_map(|k| field(fields, k).map(|v| (k.to_string(), v.to_string())))
+        .collect();
+    out.extend(
+        fields
+            .iter()
+            .filter(|(k, _)| !known.contains(&k.as_str()))
+            .cloned(),
+    );
+    out
+}
+
+fn field<'a>(fields: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// User-authored `*.md` files directly under `dir` (sorted by name).
+pub fn list_custom_prompts(kind: PromptKind, dir: &Path) -> Vec<CustomPrompt> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut out: Vec<CustomPrompt> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|e| e == "md"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            let content = fs::read_to_string(&path).unwrap_or_default();
+            let (fields, error) = match parse_prompt(&content) {
+                Ok((fields, _)) => {
+                    let error = validate_prompt_fields(kind, &fields).err();
+                    (fields, error)
+                }
+                Err(e) => (Vec::new(), Some(e)),
+            };
+            Some(CustomPrompt {
+                name,
+                path,
+                fields,
+                error,
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| a.name.cmp(&b.name));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kv(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn scaffold_validates_and_round_trips() {
+        let fields = kv(&[
+            (
+                "allowed-tools",
+                "Bash(git add:*), Read, mcp__my-server__search",
+            ),
+            ("description", "Commit staged changes"),
+        ]);
+        let content = scaffold_prompt(PromptKind::ClaudeCommand, &fields).expect("scaffold");
+        assert!(content.starts_with(
+            "---\ndescription: Commit staged changes\nallowed-tools: Bash(git add:*), Read, mcp__my-server__search\n---\n"
+        ));
+        let (parsed, body) = parse_prompt(&content).expect("parse");
+        assert_eq!(parsed.len(), 2);
+        assert!(body.contains("$ARGUMENTS"));
+
+        assert!(scaffold_prompt(PromptKind::CodexPrompt, &fields).is_err());
+        let bad = kv(&[("description", "x"), ("allowed-tools", "Bash(")]);
+        assert!(scaffold_prompt(PromptKind::ClaudeCommand, &bad).is_err());
+        assert!(scaffold_prompt(PromptKind::ClaudeCommand, &kv(&[("model", "x")])).is_err());
+    }
+
+    #[test]
+    fn values_are_quoted_as_yaml() {
+        let fields = kv(&[
+            ("description", "Fix: x"),
+            ("argument-hint", "\"quoted\" #1"),
+        ]);
+        let content = scaffold_prompt(PromptKind::CodexPrompt, &fields).expect("scaffold");
+        assert!(content.starts_with(
+            "---\ndescription: \"Fix: x\"\nargument-hint: \"\\\"quoted\\\" #1\"\n---\n"
+        ));
+        let (parsed, _) = parse_prompt(&content).expect("parse");
+        assert_eq!(parsed, fields);
+    }
+
+    #[test]
+    fn edit_keeps_unknown_keys_and_body() {
+        let content = "---\ndescription: Old\nmodel: claude-sonnet-4-5\n---\n\nBody $ARGUMENTS\n";
+        let out = edit_prompt(
+            PromptKind::ClaudeCommand,
+            content,
+            &kv(&[("description", "New"), ("argument-hint", "[file]")]),
+        )
+        .expect("edit");
+        assert_eq!(
+            out,
+            "---\ndescription: New\nargument-hint: \"[file]\"\nmodel: claude-sonnet-4-5\n---\n\nBody $ARGUMENTS\n"
+        );
+        let (fields, _) = parse_prompt(&out).expect("parse");
+        assert_eq!(field(&fields, "argument-hint"), Some("[file]"));
+        assert!(validate_prompt_name("prismctl").is_err());
+        assert!(validate_prompt_name("../x").is_err());
+    }
+}
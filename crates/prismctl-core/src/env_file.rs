@@ -0,0 +1,110 @@
+use crate::managed_block::{extract_managed_block, upsert_managed_block};
+use std::collections::BTreeMap;
+
+/// Start marker of the Prismctl-managed block in `.env` files.
+pub const ENV_BLOCK_START: &str = "# prismctl:start";
+/// End marker of the Prismctl-managed block in `.env` files.
+pub const ENV_BLOCK_END: &str = "# prismctl:end";
+
+/// Parse `KEY=value` lines (comments and blank lines are skipped; surrounding quotes removed).
+pub fn parse_env_block(block: &str) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    for raw in block.lines() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((k, v)) = line.split_once('=') else {
+            continue;
+        };
+        let key = k.trim().to_string();
+        let value = v.trim().trim_matches('"').to_string();
+        if !key.is_empty() {
+            out.insert(key, value);
+        }
+    }
+    out
+}
+
+/// Quote a value when it contains whitespace, `#` or `"`.
+pub fn format_env_value(value: &str) -> String {
+    let needs_quotes = value
+        .chars()
+        .any(|c| c.is_whitespace() || c == '#' || c == '"');
+    if !needs_quotes {
+        return value.to_string();
+    }
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+/// Render `owned_keys` (in that order) from `kv`; other keys stay outside the managed block.
+pub fn format_env_block(kv: &BTreeMap<String, String>, owned_keys: &[&str]) -> String {
+    let mut out = String::new();
+    for k in owned_keys {
+        let Some(v) = kv.get(*k) else {
+            continue;
+        };
+        out.push_str(k);
+        out.push('=');
+        out.push_str(&format_env_value(v));
+        out.push('\n');
+    }
+    out
+}
+
+/// Read the current managed-block values from a `.env` file.
+pub fn read_managed_env(existing: &str) -> BTreeMap<String, String> {
+    parse_env_block(
+        &extract_managed_block(existing, ENV_BLOCK_START, ENV_BLOCK_END).unwrap_or_default(),
+    )
+}
+
+/// Set/unset keys inside the managed block of a `.env` file, preserving everything outside it.
+pub fn upsert_managed_env(
+    existing: &str,
+    owned_keys: &[&str],
+    set: &[(&str, String)],
+    unset: &[&str],
+) -> String {
+    let mut kv = read_managed_env(existing);
+    for (k, v) in set {
+        kv.insert((*k).to_string(), v.clone());
+    }
+    for k in unset {
+        kv.remove(*k);
+    }
+    let block = format_env_block(&kv, owned_keys);
+    upsert_managed_block(existing, ENV_BLOCK_START, ENV_BLOCK_END, &block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upsert_managed_env_keeps_user_lines() {
+        let existing = "FOO=1\n";
+        let out = upsert_managed_env(
+            existing,
+            &["A", "B"],
+            &[("B", "two words".to_string()), ("A", "1".to_string())],
+            &[],
+        );
+        assert!(out.starts_with("FOO=1\n"));
+        assert!(out.contains("A=1\nB=\"two words\"\n"));
+
+        let out = upsert_managed_env(&out, &["A", "B"], &[], &["A"]);
+        assert!(!out.contains("A=1"));
+        assert_eq!(
+            read_managed_env(&out).get("B").map(String::as_str),
+            Some("two words")
+        );
+    }
+
+    #[test]
+    fn unowned_keys_are_not_rendered() {
+        let out = upsert_managed_env("", &["A"], &[("X", "1".to_string())], &[]);
+        assert!(!out.contains("X=1"));
+    }
+}
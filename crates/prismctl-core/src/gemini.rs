@@ -1,6 +1,166 @@
+use crate::env_file;
 use serde_json::Value;
 use std::collections::BTreeMap;
 
+/// Keys Prismctl owns inside Gemini CLI's `.env` managed block.
+pub const GEMINI_ENV_KEYS: &[&str] = &[
+    "GEMINI_API_KEY",
+    "GOOGLE_API_KEY",
+    "GOOGLE_GENAI_USE_VERTEXAI",
+    "GOOGLE_CLOUD_PROJECT",
+    "GOOGLE_CLOUD_LOCATION",
+    "GOOGLE_GEMINI_BASE_URL",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How Gemini CLI authenticates; each mode owns a subset of [`GEMINI_ENV_KEYS`].
+pub enum GeminiAuthMode {
+    /// Gemini API key (`GEMINI_API_KEY`).
+    ApiKey,
+    /// Vertex AI: `GOOGLE_CLOUD_PROJECT` + `GOOGLE_CLOUD_LOCATION`, or an express-mode `GOOGLE_API_KEY`.
+    VertexAi,
+    /// Gemini API key against a custom endpoint (`GOOGLE_GEMINI_BASE_URL`).
+    BaseUrl,
+}
+
+impl GeminiAuthMode {
+    pub fn id(&self) -> &'static str {
+        match self {
+            GeminiAuthMode::ApiKey => "api-key",
+            GeminiAuthMode::VertexAi => "vertex-ai",
+            GeminiAuthMode::BaseUrl => "base-url",
+        }
+    }
+
+    fn keys(&self) -> &'static [&'static str] {
+        match self {
+            GeminiAuthMode::ApiKey => &["GEMINI_API_KEY"],
+            GeminiAuthMode::VertexAi => &[
+                "GOOGLE_API_KEY",
+                "GOOGLE_GENAI_USE_VERTEXAI",
+                "GOOGLE_CLOUD_PROJECT",
+                "GOOGLE_CLOUD_LOCATION",
+            ],
+            GeminiAuthMode::BaseUrl => &["GEMINI_API_KEY", "GOOGLE_GEMINI_BASE_URL"],
+        }
+    }
+}
+
+pub fn parse_gemini_auth_mode(value: &str) -> Result<GeminiAuthMode, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "api-key" => Ok(GeminiAuthMode::ApiKey),
+        "vertex-ai" | "vertex" => Ok(GeminiAuthMode::VertexAi),
+        "base-url" => Ok(GeminiAuthMode::BaseUrl),
+        _ => Err(format!(
+            "未知 Gemini 认证方式: {}（可用: api-key, vertex-ai, base-url）",
+            value
+        )),
+    }
+}
+
+/// Infer the auth mode from the current managed block (defaults to `ApiKey`).
+pub fn detect_gemini_auth_mode(existing_env: &str) -> GeminiAuthMode {
+    let kv = env_file::read_managed_env(existing_env);
+    if kv.get("GOOGLE_GENAI_USE_VERTEXAI").map(String::as_str) == Some("true") {
+        GeminiAuthMode::VertexAi
+    } else if kv.contains_key("GOOGLE_GEMINI_BASE_URL") {
+        GeminiAuthMode::BaseUrl
+    } else {
+        GeminiAuthMode::ApiKey
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+/// Values to write into Gemini CLI's `.env`; `None` keeps the current value.
+pub struct GeminiEnvPatch {
+    /// Target mode; `None` keeps the mode detected from the existing block.
+    pub mode: Option<GeminiAuthMode>,
+    /// `GEMINI_API_KEY`, or `GOOGLE_API_KEY` in Vertex AI mode.
+    pub api_key: Option<String>,
+    pub project: Option<String>,
+    pub location: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl GeminiEnvPatch {
+    pub fn is_empty(&self) -> bool {
+        self.mode.is_none()
+            && self.api_key.is_none()
+            && self.project.is_none()
+            && self.location.is_none()
+            && self.base_url.is_none()
+    }
+}
+
+/// Upsert the Prismctl-managed block of a Gemini CLI `.env` file.
+///
+/// Keys belonging to other auth modes are removed from the block, so switching modes never
+/// leaves conflicting credentials behind. Returns the new content and the effective mode.
+pub fn upsert_gemini_env(
+    existing: &str,
+    patch: &GeminiEnvPatch,
+) -> Result<(String, GeminiAuthMode), String> {
+    let mode = patch
+        .mode
+        .unwrap_or_else(|| detect_gemini_auth_mode(existing));
+
+    let mut set: Vec<(&str, String)> = Vec::new();
+    if let Some(v) = &patch.api_key {
+        let key = match mode {
+            GeminiAuthMode::VertexAi => "GOOGLE_API_KEY",
+            _ => "GEMINI_API_KEY",
+        };
+        set.push((key, v.trim().to_string()));
+    }
+    if let Some(v) = &patch.project {
+        set.push(("GOOGLE_CLOUD_PROJECT", v.trim().to_string()));
+    }
+    if let Some(v) = &patch.location {
+        set.push(("GOOGLE_CLOUD_LOCATION", v.trim().to_string()));
+    }
+    if let Some(v) = &patch.base_url {
+        set.push(("GOOGLE_GEMINI_BASE_URL", v.trim().to_string()));
+    }
+    if mode == GeminiAuthMode::VertexAi {
+        set.push(("GOOGLE_GENAI_USE_VERTEXAI", "true".to_string()));
+    }
+    if let Some((k, _)) = set.iter().find(|(k, _)| !mode.keys().contains(k)) {
+        return Err(format!("{} 不适用于 Gemini 认证方式 {}", k, mode.id()));
+    }
+
+    let unset: Vec<&str> = GEMINI_ENV_KEYS
+        .iter()
+        .copied()
+        .filter(|k| !mode.keys().contains(k))
+        .collect();
+    let next = env_file::upsert_managed_env(existing, GEMINI_ENV_KEYS, &set, &unset);
+
+    let kv = env_file::read_managed_env(&next);
+    let complete = match mode {
+        GeminiAuthMode::ApiKey => kv.contains_key("GEMINI_API_KEY"),
+        GeminiAuthMode::VertexAi => {
+            kv.contains_key("GOOGLE_API_KEY")
+                || (kv.contains_key("GOOGLE_CLOUD_PROJECT")
+                    && kv.contains_key("GOOGLE_CLOUD_LOCATION"))
+        }
+        GeminiAuthMode::BaseUrl => {
+            kv.contains_key("GEMINI_API_KEY") && kv.contains_key("GOOGLE_GEMINI_BASE_URL")
+        }
+    };
+    if !complete {
+        return Err(format!(
+            "Gemini 认证方式 {} 缺少必需的值（需要: {}）",
+            mode.id(),
+            match mode {
+                GeminiAuthMode::ApiKey => "--api-key",
+                GeminiAuthMode::VertexAi => "--project + --location，或 --api-key",
+                GeminiAuthMode::BaseUrl => "--api-key + --base-url",
+            }
+        ));
+    }
+    Ok((next, mode))
+}
+
 pub fn set_gemini_model_name_in_settings_json(
     existing: &str,
     model_name: &str,
@@ -31,6 +191,11 @@ pub fn set_gemini_model_name_in_settings_json(
     Ok(to_stable_pretty_json(&root))
 }
 
+/// Read `model.name` from `settings.json` content.
+pub fn read_gemini_model_name(content: &str) -> Result<Option<String>, String> {
+    crate::json_text::string_at_path(content, &["model", "name"])
+}
+
 fn to_stable_pretty_json(v: &Value) -> String {
     // Keep deterministic output for tests.
     fn normalize(v: &Value) -> Value {
@@ -57,6 +222,68 @@ fn to_stable_pretty_json(v: &Value) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn switching_auth_mode_drops_conflicting_keys() {
+        let existing = "FOO=1\n# prismctl:start\nGEMINI_API_KEY=k\n# prismctl:end\n";
+        let (out, mode) = upsert_gemini_env(
+            existing,
+            &GeminiEnvPatch {
+                mode: Some(GeminiAuthMode::VertexAi),
+                project: Some("my-proj".into()),
+                location: Some("us-central1".into()),
+                ..Default::default()
+            },
+        )
+        .expect("vertex");
+        assert_eq!(mode, GeminiAuthMode::VertexAi);
+        assert!(out.starts_with("FOO=1\n"));
+        assert!(!out.contains("GEMINI_API_KEY"));
+        assert!(out.contains("GOOGLE_GENAI_USE_VERTEXAI=true\n"));
+
+        // Mode is detected from the block when omitted.
+        assert_eq!(detect_gemini_auth_mode(&out), GeminiAuthMode::VertexAi);
+        let (out, _) = upsert_gemini_env(
+            &out,
+            &GeminiEnvPatch {
+                location: Some("europe-west4".into()),
+                ..Default::default()
+            },
+        )
+        .expect("update");
+        assert!(out.contains("GOOGLE_CLOUD_LOCATION=europe-west4\n"));
+
+        let (out, _) = upsert_gemini_env(
+            &out,
+            &GeminiEnvPatch {
+                mode: Some(GeminiAuthMode::BaseUrl),
+                api_key: Some("k2".into()),
+                base_url: Some("https://gw.example".into()),
+                ..Default::default()
+            },
+        )
+        .expect("base-url");
+        assert!(!out.contains("GOOGLE_CLOUD_PROJECT"));
+        assert!(out.contains("GEMINI_API_KEY=k2\nGOOGLE_GEMINI_BASE_URL=https://gw.example\n"));
+    }
+
+    #[test]
+    fn rejects_incomplete_or_mismatched_values() {
+        let vertex_only_project = GeminiEnvPatch {
+            mode: Some(GeminiAuthMode::VertexAi),
+            project: Some("p".into()),
+            ..Default::default()
+        };
+        assert!(upsert_gemini_env("", &vertex_only_project).is_err());
+
+        let base_url_in_api_key_mode = GeminiEnvPatch {
+            api_key: Some("k".into()),
+            base_url: Some("https://x".into()),
+            mode: Some(GeminiAuthMode::ApiKey),
+            ..Default::default()
+        };
+        assert!(upsert_gemini_env("", &base_url_in_api_key_mode).is_err());
+    }
+
     #[test]
     fn sets_model_name_in_empty_settings() {
         let out = set_gemini_model_name_in_settings_json("", "gemini-2.5-pro").expect("ok");
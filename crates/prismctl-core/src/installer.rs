@@ -1,4 +1,5 @@
 use crate::changeset::{Change, ChangeSet};
+use crate::tools::{BrewPackage, ToolAdapter};
 use std::env;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,46 +17,21 @@ pub enum InstallAction {
     Upgrade,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// External tool to install/upgrade.
-pub enum ToolInstallTarget {
-    Codex,
-    ClaudeCode,
-    GeminiCli,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum BrewPkgKind {
-    Formula,
-    Cask,
-}
-
-fn npm_package_name(tool: ToolInstallTarget) -> &'static str {
-    match tool {
-        ToolInstallTarget::Codex => "@openai/codex",
-        ToolInstallTarget::ClaudeCode => "@anthropic-ai/claude-code",
-        ToolInstallTarget::GeminiCli => "@google/gemini-cli",
-    }
-}
-
-fn brew_package(tool: ToolInstallTarget) -> (&'static str, BrewPkgKind) {
-    match tool {
-        ToolInstallTarget::Codex => ("codex", BrewPkgKind::Cask),
-        ToolInstallTarget::ClaudeCode => ("claude-code", BrewPkgKind::Cask),
-        ToolInstallTarget::GeminiCli => ("gemini-cli", BrewPkgKind::Formula),
-    }
-}
-
 /// Plan an install/upgrade action using either npm or brew, depending on `method`.
+///
+/// Tools without a Homebrew package always fall back to npm.
 pub fn plan_install(
-    tool: ToolInstallTarget,
+    tool: &dyn ToolAdapter,
     method: InstallMethod,
     action: InstallAction,
 ) -> ChangeSet {
     let resolved = resolve_method(method);
     match resolved {
         InstallMethod::Npm => plan_npm(tool, action),
-        InstallMethod::Brew => plan_brew(tool, action),
+        InstallMethod::Brew => match tool.brew_package() {
+            Some(pkg) => plan_brew(pkg, action),
+            None => plan_npm(tool, action),
+        },
         InstallMethod::Auto => unreachable!("auto resolved"),
     }
 }
@@ -74,8 +50,8 @@ fn resolve_method(method: InstallMethod) -> InstallMethod {
     }
 }
 
-fn plan_npm(tool: ToolInstallTarget, action: InstallAction) -> ChangeSet {
-    let pkg = npm_package_name(tool);
+fn plan_npm(tool: &dyn ToolAdapter, action: InstallAction) -> ChangeSet {
+    let pkg = tool.npm_package();
     let mut cs = ChangeSet::new();
     let args: Vec<String> = match action {
         InstallAction::Install | InstallAction::Upgrade => vec![
@@ -93,8 +69,7 @@ fn plan_npm(tool: ToolInstallTarget, action: InstallAction) -> ChangeSet {
     cs
 }
 
-fn plan_brew(tool: ToolInstallTarget, action: InstallAction) -> ChangeSet {
-    let (name, kind) = brew_package(tool);
+fn plan_brew(pkg: BrewPackage, action: InstallAction) -> ChangeSet {
     let mut cs = ChangeSet::new();
 
     let (verb, mut args) = match action {
@@ -103,11 +78,10 @@ fn plan_brew(tool: ToolInstallTarget, action: InstallAction) -> ChangeSet {
     };
 
     args.push(verb.to_string());
-    match kind {
-        BrewPkgKind::Cask => args.push("--cask".to_string()),
-        BrewPkgKind::Formula => {}
+    if pkg.cask {
+        args.push("--cask".to_string());
     }
-    args.push(name.to_string());
+    args.push(pkg.name.to_string());
 
     cs.push(Change::RunCommand {
         program: "brew".to_string(),
@@ -121,14 +95,23 @@ fn plan_brew(tool: ToolInstallTarget, action: InstallAction) -> ChangeSet {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tools::find_tool;
 
     #[test]
     fn plan_contains_one_command() {
-        let cs = plan_install(
-            ToolInstallTarget::Codex,
-            InstallMethod::Npm,
-            InstallAction::Install,
-        );
+        let codex = find_tool("codex").expect("codex");
+        let cs = plan_install(codex, InstallMethod::Npm, InstallAction::Install);
         assert_eq!(cs.iter().count(), 1);
     }
+
+    #[test]
+    fn brew_uses_cask_flag_from_adapter() {
+        let codex = find_tool("codex").expect("codex");
+        let cs = plan_install(codex, InstallMethod::Brew, InstallAction::Upgrade);
+        let Some(Change::RunCommand { program, args, .. }) = cs.iter().next() else {
+            panic!("expected command");
+        };
+        assert_eq!(program, "brew");
+        assert_eq!(args, &["upgrade", "--cask", "codex"]);
+    }
 }
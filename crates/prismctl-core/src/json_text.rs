@@ -47,7 +47,90 @@ pub fn upsert_string_map_entry(
     Ok(to_stable_pretty_json(&obj))
 }
 
-fn to_stable_pretty_json(v: &Value) -> String {
+/// Upsert `value` at a nested object path (e.g. `["mcp", "context7"]`), creating parents.
+pub fn upsert_value_at_path(content: &str, path: &[&str], value: Value) -> Result<String, String> {
+    let (last, parents) = path
+        .split_last()
+        .ok_or_else(|| "JSON 路径为空".to_string())?;
+    let mut obj = parse_object(content)?;
+
+    let mut cur = obj
+        .as_object_mut()
+        .ok_or_else(|| "JSON 顶层不是对象".to_string())?;
+    for key in parents {
+        cur = cur
+            .entry((*key).to_string())
+            .or_insert_with(|| Value::Object(Default::default()))
+            .as_object_mut()
+            .ok_or_else(|| format!("JSON 字段 {key} 不是对象"))?;
+    }
+    cur.insert((*last).to_string(), value);
+
+    Ok(to_stable_pretty_json(&obj))
+}
+
+/// Remove the value at a nested object path. Returns `None` when the path does not exist.
+pub fn remove_value_at_path(content: &str, path: &[&str]) -> Result<Option<String>, String> {
+    let (last, parents) = path
+        .split_last()
+        .ok_or_else(|| "JSON 路径为空".to_string())?;
+    let mut obj = parse_object(content)?;
+
+    let mut cur = obj
+        .as_object_mut()
+        .ok_or_else(|| "JSON 顶层不是对象".to_string())?;
+    for key in parents {
+        match cur.get_mut(*key).and_then(Value::as_object_mut) {
+            Some(next) => cur = next,
+            None => return Ok(None),
+        }
+    }
+    if cur.remove(*last).is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(to_stable_pretty_json(&obj)))
+}
+
+/// Object keys at a nested path (sorted). Missing paths yield an empty list.
+pub fn object_keys_at_path(content: &str, path: &[&str]) -> Result<Vec<String>, String> {
+    let obj = parse_object(content)?;
+    let mut cur = &obj;
+    for key in path {
+        match cur.get(*key) {
+            Some(next) => cur = next,
+            None => return Ok(Vec::new()),
+        }
+    }
+    let mut keys: Vec<String> = cur
+        .as_object()
+        .map(|m| m.keys().cloned().collect())
+        .unwrap_or_default();
+    keys.sort();
+    Ok(keys)
+}
+
+/// String value at a nested object path; missing paths and non-strings yield `None`.
+pub fn string_at_path(content: &str, path: &[&str]) -> Result<Option<String>, String> {
+    let obj = parse_object(content)?;
+    let mut cur = &obj;
+    for key in path {
+        match cur.get(*key) {
+            Some(next) => cur = next,
+            None => return Ok(None),
+        }
+    }
+    Ok(cur.as_str().map(str::to_string))
+}
+
+fn parse_object(content: &str) -> Result<Value, String> {
+    if content.trim().is_empty() {
+        return Ok(Value::Object(Default::default()));
+    }
+    serde_json::from_str::<Value>(content).map_err(|e| format!("JSON 解析失败: {e}"))
+}
+
+pub(crate) fn to_stable_pretty_json(v: &Value) -> String {
     // serde_json preserves insertion order, but we want deterministic output for tests.
     // Convert objects to BTreeMap recursively.
     fn normalize(v: &Value) -> Value {
@@ -87,4 +170,23 @@ mod tests {
         assert!(out.contains("\"auth\""));
         assert!(out.contains("\"KEY\": \"VAL\""));
     }
+
+    #[test]
+    fn path_helpers_round_trip() {
+        let out = upsert_value_at_path("", &["mcp", "demo"], Value::Bool(true)).expect("ok");
+        assert_eq!(
+            object_keys_at_path(&out, &["mcp"]).expect("ok"),
+            vec!["demo"]
+        );
+
+        let removed = remove_value_at_path(&out, &["mcp", "demo"])
+            .expect("ok")
+            .expect("present");
+        assert!(object_keys_at_path(&removed, &["mcp"])
+            .expect("ok")
+            .is_empty());
+        assert!(remove_value_at_path(&removed, &["mcp", "demo"])
+            .expect("ok")
+            .is_none());
+    }
 }
@@ -1,14 +1,46 @@
 pub mod changeset;
 pub mod claude;
+pub mod claude_agents;
+pub mod claude_hooks;
+pub mod claude_permissions;
+pub mod claude_statusline;
 pub mod codex;
+pub mod codex_auth;
+pub mod codex_settings;
+pub mod copilot;
+pub mod cursor;
+pub mod custom_prompt;
+pub mod env_file;
 pub mod gemini;
 pub mod installer;
 pub mod json_text;
 pub mod managed_block;
 pub mod mcp;
+pub mod opencode;
 pub mod paths;
+pub mod profile;
 pub mod project;
+pub mod provider_api;
 pub mod providers;
+pub mod qwen;
+pub mod redact;
 pub mod skill;
 pub mod templates;
 pub mod toml_text;
+pub mod tools;
+
+/// Find the variant of `all` whose `as_str` is `value`, or list the accepted values for `field`.
+pub(crate) fn parse_choice<T: Copy>(
+    field: &str,
+    value: &str,
+    all: &[T],
+    as_str: fn(T) -> &'static str,
+) -> Result<T, String> {
+    all.iter()
+        .copied()
+        .find(|v| as_str(*v) == value)
+        .ok_or_else(|| {
+            let choices: Vec<&str> = all.iter().map(|v| as_str(*v)).collect();
+            format!("{field} 不支持: {value}（可选: {}）", choices.join(", "))
+        })
+}
@@ -79,6 +79,27 @@ pub fn extract_managed_block(
     Some(block.to_string())
 }
 
+/// Remove the block delimited by `start_marker` and `end_marker` (markers included).
+///
+/// Blank lines left around the removed block are collapsed. Returns `None` when no block exists.
+pub fn remove_managed_block(content: &str, start_marker: &str, end_marker: &str) -> Option<String> {
+    let s = content.find(start_marker)?;
+    let e = content[s..].find(end_marker)? + s + end_marker.len();
+
+    let before = content[..s].trim_end_matches(['\n', ' ']);
+    let after = content[e..].trim_start_matches(['\n', ' ']);
+    let mut out = String::with_capacity(content.len());
+    out.push_str(before);
+    if !before.is_empty() && !after.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str(after);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    Some(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +125,16 @@ mod tests {
             extract_managed_block(input, "# prismctl:start", "# prismctl:end").expect("block");
         assert_eq!(block, "x=1");
     }
+
+    #[test]
+    fn remove_drops_block_and_keeps_surroundings() {
+        let input = "a\n\n<!-- s -->\nx\n<!-- e -->\n\nb\n";
+        let out = remove_managed_block(input, "<!-- s -->", "<!-- e -->").expect("removed");
+        assert_eq!(out, "a\n\nb\n");
+        assert_eq!(
+            remove_managed_block("<!-- s -->\nx\n<!-- e -->\n", "<!-- s -->", "<!-- e -->"),
+            Some(String::new())
+        );
+        assert!(remove_managed_block(&out, "<!-- s -->", "<!-- e -->").is_none());
+    }
 }
@@ -247,6 +247,26 @@ pub fn plan_gemini_mcp_add(
     scope: GeminiMcpScope,
     server_id: &str,
     project_cwd: Option<PathBuf>,
+) -> Result<ChangeSet, String> {
+    plan_gemini_family_mcp_add("gemini", home, scope, server_id, project_cwd)
+}
+
+/// Plan to add a built-in MCP server via Qwen Code (`qwen mcp add`, same flags as Gemini CLI).
+pub fn plan_qwen_mcp_add(
+    home: &PrismctlHome,
+    scope: GeminiMcpScope,
+    server_id: &str,
+    project_cwd: Option<PathBuf>,
+) -> Result<ChangeSet, String> {
+    plan_gemini_family_mcp_add("qwen", home, scope, server_id, project_cwd)
+}
+
+fn plan_gemini_family_mcp_add(
+    program: &str,
+    home: &PrismctlHome,
+    scope: GeminiMcpScope,
+    server_id: &str,
+    project_cwd: Option<PathBuf>,
 ) -> Result<ChangeSet, String> {
     let def = builtin_mcp_server(server_id)
         .ok_or_else(|| format!("未知 MCP server: {}（内置）", server_id))?;
@@ -295,7 +315,7 @@ pub fn plan_gemini_mcp_add(
 
     let mut cs = ChangeSet::new();
     cs.push(Change::RunCommand {
-        program: "gemini".to_string(),
+        program: program.to_string(),
         args,
         cwd: project_cwd,
         env: gemini_cli_env_for_home(home),
@@ -308,6 +328,25 @@ pub fn plan_gemini_mcp_remove(
     scope: GeminiMcpScope,
     server_name: &str,
     project_cwd: Option<PathBuf>,
+) -> ChangeSet {
+    plan_gemini_family_mcp_remove("gemini", home, scope, server_name, project_cwd)
+}
+
+pub fn plan_qwen_mcp_remove(
+    home: &PrismctlHome,
+    scope: GeminiMcpScope,
+    server_name: &str,
+    project_cwd: Option<PathBuf>,
+) -> ChangeSet {
+    plan_gemini_family_mcp_remove("qwen", home, scope, server_name, project_cwd)
+}
+
+fn plan_gemini_family_mcp_remove(
+    program: &str,
+    home: &PrismctlHome,
+    scope: GeminiMcpScope,
+    server_name: &str,
+    project_cwd: Option<PathBuf>,
 ) -> ChangeSet {
     let args = vec![
         "mcp".to_string(),
@@ -318,7 +357,7 @@ pub fn plan_gemini_mcp_remove(
     ];
     let mut cs = ChangeSet::new();
     cs.push(Change::RunCommand {
-        program: "gemini".to_string(),
+        program: program.to_string(),
         args,
         cwd: project_cwd,
         env: gemini_cli_env_for_home(home),
@@ -374,3 +413,20 @@ fn gemini_cli_env_for_home(home: &PrismctlHome) -> Vec<(String, String)> {
     envs.push(("USERPROFILE".to_string(), home_dir));
     envs
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qwen_mcp_add_runs_qwen_cli() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-home"));
+        let cs = plan_qwen_mcp_add(&home, GeminiMcpScope::User, "playwright", None).expect("ok");
+        let Some(Change::RunCommand { program, args, .. }) = cs.iter().next() else {
+            panic!("expected command");
+        };
+        assert_eq!(program, "qwen");
+        assert_eq!(&args[..2], &["mcp", "add"]);
+        assert!(args.contains(&"playwright".to_string()));
+    }
+}
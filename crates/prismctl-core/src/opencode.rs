@@ -0,0 +1,214 @@
+use crate::json_text::{object_keys_at_path, remove_value_at_path, upsert_value_at_path};
+use crate::mcp::{McpServerDefinition, McpTransport};
+use serde_json::{json, Map, Value};
+
+/// Provider id Prismctl owns inside `opencode.json`.
+pub const OPENCODE_PROVIDER_ID: &str = "prismctl";
+
+#[derive(Debug, Clone)]
+/// An OpenAI-compatible provider written into OpenCode's `opencode.json`.
+pub struct OpenCodeProviderConfig {
+    pub base_url: String,
+    pub model: String,
+    /// Written as `options.apiKey`; `None` keeps whatever is already configured.
+    pub api_key: Option<String>,
+}
+
+/// Upsert `provider.prismctl` and point the root `model` at it.
+pub fn upsert_opencode_provider_in_config_json(
+    existing: &str,
+    cfg: &OpenCodeProviderConfig,
+) -> Result<String, String> {
+    let base_url = cfg.base_url.trim();
+    let model = cfg.model.trim();
+    if base_url.is_empty() {
+        return Err("base_url 不能为空".to_string());
+    }
+    if model.is_empty() {
+        return Err("model 不能为空".to_string());
+    }
+
+    let mut options = Map::new();
+    options.insert("baseURL".to_string(), Value::String(base_url.to_string()));
+    match cfg.api_key.as_deref().map(str::trim) {
+        Some(key) if !key.is_empty() => {
+            options.insert("apiKey".to_string(), Value::String(key.to_string()));
+        }
+        _ => {
+            // Preserve an existing key when the caller does not provide one.
+            if let Some(key) = existing_api_key(existing)? {
+                options.insert("apiKey".to_string(), Value::String(key));
+            }
+        }
+    }
+
+    let mut models = Map::new();
+    models.insert(model.to_string(), json!({ "name": model }));
+
+    let provider = json!({
+        "npm": "@ai-sdk/openai-compatible",
+        "name": "Prismctl",
+        "options": Value::Object(options),
+        "models": Value::Object(models),
+    });
+
+    let out = upsert_value_at_path(existing, &["provider", OPENCODE_PROVIDER_ID], provider)?;
+    let out = upsert_value_at_path(
+        &out,
+        &["model"],
+        Value::String(format!("{OPENCODE_PROVIDER_ID}/{model}")),
+    )?;
+    if object_keys_at_path(&out, &[])?
+        .iter()
+        .any(|k| k == "$schema")
+    {
+        return Ok(out);
+    }
+    upsert_value_at_path(
+        &out,
+        &["$schema"],
+        Value::String("https://opencode.ai/config.json".to_string()),
+    )
+}
+
+fn existing_api_key(existing: &str) -> Result<Option<String>, String> {
+    if existing.trim().is_empty() {
+        return Ok(None);
+    }
+    let v: Value = serde_json::from_str(existing).map_err(|e| format!("JSON 解析失败: {e}"))?;
+    Ok(
+        v.pointer(&format!("/provider/{OPENCODE_PROVIDER_ID}/options/apiKey"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    )
+}
+
+/// MCP server names configured in `opencode.json` (sorted).
+pub fn list_opencode_mcp_servers(existing: &str) -> Result<Vec<String>, String> {
+    object_keys_at_path(existing, &["mcp"])
+}
+
+/// Upsert a built-in MCP server under `mcp.<id>`.
+pub fn upsert_opencode_mcp_server_in_config_json(
+    existing: &str,
+    def: &McpServerDefinition,
+) -> Result<String, String> {
+    upsert_value_at_path(existing, &["mcp", def.id], opencode_mcp_entry(def)?)
+}
+
+/// Remove `mcp.<name>`; errors when the server is not configured.
+pub fn remove_opencode_mcp_server_in_config_json(
+    existing: &str,
+    name: &str,
+) -> Result<String, String> {
+    remove_value_at_path(existing, &["mcp", name])?
+        .ok_or_else(|| format!("opencode.json 中未找到 MCP server: {name}"))
+}
+
+fn opencode_mcp_entry(def: &McpServerDefinition) -> Result<Value, String> {
+    match def.transport {
+        McpTransport::Http | McpTransport::Sse | McpTransport::StreamableHttp => {
+            let url = def
+                .url
+                .ok_or_else(|| format!("MCP server {} 缺少 url", def.id))?;
+            let headers: Map<String, Value> = def
+                .headers
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), Value::String(to_opencode_env_ref(v))))
+                .collect();
+            Ok(json!({
+                "type": "remote",
+                "url": url,
+                "headers": Value::Object(headers),
+                "enabled": true,
+            }))
+        }
+        McpTransport::Stdio => {
+            let cmd = def
+                .command
+                .ok_or_else(|| format!("MCP server {} 缺少 command", def.id))?;
+            let mut command = vec![Value::String(cmd.to_string())];
+            command.extend(def.args.iter().map(|a| Value::String((*a).to_string())));
+            let environment: Map<String, Value> = def
+                .env
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), Value::String(to_opencode_env_ref(v))))
+                .collect();
+            Ok(json!({
+                "type": "local",
+                "command": command,
+                "environment": Value::Object(environment),
+                "enabled": true,
+            }))
+        }
+    }
+}
+
+/// Catalog values use `${VAR}`; OpenCode expects `{env:VAR}`.
+fn to_opencode_env_ref(value: &str) -> String {
+    match value.strip_prefix("${").and_then(|v| v.strip_suffix('}')) {
+        Some(var) => format!("{{env:{var}}}"),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::builtin_mcp_server;
+
+    #[test]
+    fn provider_sets_model_and_schema() {
+        let cfg = OpenCodeProviderConfig {
+            base_url: "https://api.example.com/v1".to_string(),
+            model: "m1".to_string(),
+            api_key: Some("sk-test".to_string()),
+        };
+        let out = upsert_opencode_provider_in_config_json("", &cfg).expect("ok");
+        let v: Value = serde_json::from_str(&out).expect("json");
+        assert_eq!(v["model"], "prismctl/m1");
+        assert_eq!(v["$schema"], "https://opencode.ai/config.json");
+        assert_eq!(
+            v["provider"]["prismctl"]["options"]["baseURL"],
+            "https://api.example.com/v1"
+        );
+
+        // Re-running without a key keeps the existing one.
+        let cfg2 = OpenCodeProviderConfig {
+            api_key: None,
+            model: "m2".to_string(),
+            ..cfg
+        };
+        let out2 = upsert_opencode_provider_in_config_json(&out, &cfg2).expect("ok");
+        let v2: Value = serde_json::from_str(&out2).expect("json");
+        assert_eq!(v2["provider"]["prismctl"]["options"]["apiKey"], "sk-test");
+        assert_eq!(v2["model"], "prismctl/m2");
+    }
+
+    #[test]
+    fn mcp_entries_round_trip() {
+        let context7 = builtin_mcp_server("context7").expect("context7");
+        let playwright = builtin_mcp_server("playwright").expect("playwright");
+        let out = upsert_opencode_mcp_server_in_config_json("", context7).expect("ok");
+        let out = upsert_opencode_mcp_server_in_config_json(&out, playwright).expect("ok");
+
+        let v: Value = serde_json::from_str(&out).expect("json");
+        assert_eq!(v["mcp"]["context7"]["type"], "remote");
+        assert_eq!(
+            v["mcp"]["context7"]["headers"]["CONTEXT7_API_KEY"],
+            "{env:CONTEXT7_API_KEY}"
+        );
+        assert_eq!(v["mcp"]["playwright"]["command"][0], "npx");
+        assert_eq!(
+            list_opencode_mcp_servers(&out).expect("ok"),
+            vec!["context7", "playwright"]
+        );
+
+        let out = remove_opencode_mcp_server_in_config_json(&out, "context7").expect("ok");
+        assert_eq!(
+            list_opencode_mcp_servers(&out).expect("ok"),
+            vec!["playwright"]
+        );
+        assert!(remove_opencode_mcp_server_in_config_json(&out, "context7").is_err());
+    }
+}
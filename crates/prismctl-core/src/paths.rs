@@ -7,6 +7,29 @@ pub enum Tool {
     Codex,
     ClaudeCode,
     GeminiCli,
+    OpenCode,
+    QwenCode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which copy of a tool's config a command reads/writes (`--scope`).
+pub enum ConfigScope {
+    /// Under the user's home.
+    User,
+    /// Inside the project, shared with the team via git.
+    Project,
+    /// Inside the project but personal (e.g. Claude Code's `settings.local.json`).
+    Local,
+}
+
+impl ConfigScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Project => "project",
+            Self::Local => "local",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +80,8 @@ impl PrismctlHome {
             Tool::Codex => self.home_dir.join(".codex"),
             Tool::ClaudeCode => self.home_dir.join(".claude"),
             Tool::GeminiCli => self.home_dir.join(".gemini"),
+            Tool::OpenCode => self.home_dir.join(".config").join("opencode"),
+            Tool::QwenCode => self.home_dir.join(".qwen"),
         }
     }
 
@@ -85,6 +110,14 @@ mod tests {
             home.tool_root(Tool::GeminiCli),
             PathBuf::from("/tmp/prismctl-home/.gemini")
         );
+        assert_eq!(
+            home.tool_root(Tool::OpenCode),
+            PathBuf::from("/tmp/prismctl-home/.config/opencode")
+        );
+        assert_eq!(
+            home.tool_root(Tool::QwenCode),
+            PathBuf::from("/tmp/prismctl-home/.qwen")
+        );
         assert_eq!(
             home.claude_settings_path(),
             PathBuf::from("/tmp/prismctl-home/.claude/settings.json")
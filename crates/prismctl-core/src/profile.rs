@@ -0,0 +1,701 @@
+use crate::changeset::{Change, ChangeSet};
+use crate::claude::{self, ClaudeEnvPatch};
+use crate::codex::{self, CodexProviderConfig};
+use crate::env_file;
+use crate::gemini::{self, GeminiAuthMode, GeminiEnvPatch};
+use crate::json_text;
+use crate::paths::{PrismctlHome, Tool};
+use crate::providers;
+use crate::redact;
+use crate::toml_text::{self, TomlScalar};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_PROFILE_PROVIDER_ID: &str = "prismctl";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Codex provider settings captured by a profile (`[codex]`).
+pub struct CodexProfile {
+    /// `[model_providers.<id>]` table written and made default (defaults to `prismctl`).
+    pub provider: Option<String>,
+    pub base_url: Option<String>,
+    pub wire_api: Option<String>,
+    pub model: Option<String>,
+    /// Key name inside `auth.json` (defaults to the provider's, e.g. `PRISMCTL_CODEX_API_KEY`).
+    pub env_key: Option<String>,
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// Gemini CLI `.env` values plus `model.name` captured by a profile (`[gemini]`).
+pub struct GeminiProfile {
+    pub env: GeminiEnvPatch,
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// A named set of credentials/models for Codex, Claude Code and Gemini CLI.
+///
+/// Stored as `~/.prismctl/profiles/<name>.toml`; tools without a section are left untouched.
+pub struct Profile {
+    pub codex: Option<CodexProfile>,
+    pub claude: Option<ClaudeEnvPatch>,
+    pub gemini: Option<GeminiProfile>,
+}
+
+impl Profile {
+    /// Flatten into `("section.key", value)` pairs in file order; unset values are skipped.
+    pub fn entries(&self) -> Vec<(String, String)> {
+        let mut out = Vec::new();
+        let mut push = |section: &str, key: &str, value: &Option<String>| {
+            if let Some(v) = value {
+                out.push((format!("{section}.{key}"), v.clone()));
+            }
+        };
+        if let Some(c) = &self.codex {
+            push("codex", "provider", &c.provider);
+            push("codex", "base_url", &c.base_url);
+            push("codex", "wire_api", &c.wire_api);
+            push("codex", "model", &c.model);
+            push("codex", "env_key", &c.env_key);
+            push("codex", "api_key", &c.api_key);
+        }
+        if let Some(c) = &self.claude {
+            push("claude", "auth_token", &c.auth_token);
+            push("claude", "base_url", &c.base_url);
+            push("claude", "model", &c.model);
+            push("claude", "haiku_model", &c.default_haiku_model);
+            push("claude", "sonnet_model", &c.default_sonnet_model);
+            push("claude", "opus_model", &c.default_opus_model);
+            push("claude", "small_fast_model", &c.small_fast_model);
+            push("claude", "api_timeout_ms", &c.api_timeout_ms);
+            push("claude", "max_output_tokens", &c.max_output_tokens);
+            push("claude", "disable_telemetry", &c.disable_telemetry);
+        }
+        if let Some(g) = &self.gemini {
+            push("gemini", "mode", &g.env.mode.map(|m| m.id().to_string()));
+            push("gemini", "api_key", &g.env.api_key);
+            push("gemini", "project", &g.env.project);
+            push("gemini", "location", &g.env.location);
+            push("gemini", "base_url", &g.env.base_url);
+            push("gemini", "model", &g.model);
+        }
+        out
+    }
+
+    /// Sections present in the profile (`codex`, `claude`, `gemini`).
+    fn sections(&self) -> Vec<&'static str> {
+        let mut out = Vec::new();
+        if self.codex.is_some() {
+            out.push("codex");
+        }
+        if self.claude.is_some() {
+            out.push("claude");
+        }
+        if self.gemini.is_some() {
+            out.push("gemini");
+        }
+        out
+    }
+
+    fn set(&mut self, section: &str, key: &str, value: String) -> Result<(), String> {
+        let v = Some(value.clone());
+        match section {
+            "codex" => {
+                let c = self.codex.get_or_insert_with(Default::default);
+                match key {
+                    "provider" => c.provider = v,
+                    "base_url" => c.base_url = v,
+                    "wire_api" => c.wire_api = v,
+                    "model" => c.model = v,
+                    "env_key" => c.env_key = v,
+                    "api_key" => c.api_key = v,
+                    _ => return Err(unknown_key(section, key)),
+                }
+            }
+            "claude" => {
+                let c = self.claude.get_or_insert_with(Default::default);
+                match key {
+                    "auth_token" => c.auth_token = v,
+                    "base_url" => c.base_url = v,
+                    "model" => c.model = v,
+                    "haiku_model" => c.default_haiku_model = v,
+                    "sonnet_model" => c.default_sonnet_model = v,
+                    "opus_model" => c.default_opus_model = v,
+                    "small_fast_model" => c.small_fast_model = v,
+                    "api_timeout_ms" => c.api_timeout_ms = v,
+                    "max_output_tokens" => c.max_output_tokens = v,
+                    "disable_telemetry" => c.disable_telemetry = v,
+                    _ => return Err(unknown_key(section, key)),
+                }
+            }
+            "gemini" => {
+                let g = self.gemini.get_or_insert_with(Default::default);
+                match key {
+                    "mode" => g.env.mode = Some(gemini::parse_gemini_auth_mode(&value)?),
+                    "api_key" => g.env.api_key = v,
+                    "project" => g.env.project = v,
+                    "location" => g.env.location = v,
+                    "base_url" => g.env.base_url = v,
+                    "model" => g.model = v,
+                    _ => return Err(unknown_key(section, key)),
+                }
+            }
+            _ => {
+                return Err(format!(
+                    "未知 profile 段: [{section}]（可用: codex, claude, gemini）"
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unknown_key(section: &str, key: &str) -> String {
+    format!("未知 profile 字段: {section}.{key}")
+}
+
+/// Parse a profile file (`[codex]`, `[claude]`, `[gemini]` tables with string values).
+pub fn parse_profile(content: &str) -> Result<Profile, String> {
+    let mut profile = Profile::default();
+    for (section, kv) in toml_text::read_string_tables(content)? {
+        for (key, value) in kv {
+            profile.set(&section, &key, value)?;
+        }
+    }
+    Ok(profile)
+}
+
+/// Render a profile in the format read by [`parse_profile`].
+pub fn render_profile(profile: &Profile) -> String {
+    let mut sections: Vec<(String, Vec<(String, TomlScalar)>)> = Vec::new();
+    for (full_key, value) in profile.entries() {
+        let (section, key) = full_key.split_once('.').expect("section.key");
+        if sections.last().map(|(s, _)| s.as_str()) != Some(section) {
+            sections.push((section.to_string(), Vec::new()));
+        }
+        if let Some((_, kv)) = sections.last_mut() {
+            kv.push((key.to_string(), TomlScalar::Str(value)));
+        }
+    }
+    let mut out = String::from("# Prismctl profile (contains credentials; keep private)\n");
+    for (section, kv) in sections {
+        out.push('\n');
+        out.push_str(&toml_text::upsert_table_kv("", &section, &kv));
+    }
+    out
+}
+
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "无效的 profile 名称: {name}（仅允许字母、数字、- 和 _）"
+        ))
+    }
+}
+
+/// Directory holding profile files (`~/.prismctl/profiles`).
+pub fn profiles_dir(home: &PrismctlHome) -> PathBuf {
+    home.home_dir().join(".prismctl").join("profiles")
+}
+
+pub fn profile_path(home: &PrismctlHome, name: &str) -> PathBuf {
+    profiles_dir(home).join(format!("{name}.toml"))
+}
+
+/// Names of saved profiles, sorted.
+pub fn list_profiles(home: &PrismctlHome) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(profiles_dir(home)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            if path.extension().and_then(|s| s.to_str()) != Some("toml") {
+                return None;
+            }
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .map(str::to_string)
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn load_profile(home: &PrismctlHome, name: &str) -> Result<Profile, String> {
+    validate_profile_name(name)?;
+    let path = profile_path(home, name);
+    let content = fs::read_to_string(&path)
+        .map_err(|_| format!("profile 不存在: {name}（{}）", path.display()))?;
+    parse_profile(&content).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Plan writing `profile` as `~/.prismctl/profiles/<name>.toml`.
+pub fn plan_save_profile(
+    home: &PrismctlHome,
+    name: &str,
+    profile: &Profile,
+) -> Result<ChangeSet, String> {
+    validate_profile_name(name)?;
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll {
+        path: profiles_dir(home),
+    });
+    cs.push(Change::WriteFile {
+        path: profile_path(home, name),
+        bytes: render_profile(profile).into_bytes(),
+        overwrite: true,
+    });
+    Ok(cs)
+}
+
+/// Plan one combined change set applying `profile` to all tools it covers.
+///
+/// Codex gets the profile's provider (`prismctl` by default, made default) plus the API key in
+/// `auth.json`; Claude Code gets its `settings.json` env; Gemini CLI gets the user `.env` managed
+/// block and `model.name`. Each covered tool ends up with exactly the profile's values: managed
+/// keys the profile leaves out are unset, so switching profiles never keeps the previous
+/// profile's credentials. Claude tuning keys (timeout, output tokens, telemetry) are only
+/// replaced when the profile sets them.
+/// `read_existing` returns the current content of a path, if any.
+pub fn plan_profile_use(
+    home: &PrismctlHome,
+    profile: &Profile,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> Result<ChangeSet, String> {
+    let read = |p: &Path| read_existing(p).unwrap_or_default();
+    let mut cs = ChangeSet::new();
+    let write = |cs: &mut ChangeSet, path: PathBuf, content: String| {
+        if let Some(parent) = path.parent() {
+            let planned = cs
+                .iter()
+                .any(|c| matches!(c, Change::CreateDirAll { path } if path == parent));
+            if !planned {
+                cs.push(Change::CreateDirAll {
+                    path: parent.to_path_buf(),
+                });
+            }
+        }
+        cs.push(Change::WriteFile {
+            path,
+            bytes: content.into_bytes(),
+            overwrite: true,
+        });
+    };
+
+    if let Some(c) = &profile.codex {
+        let root = home.tool_root(Tool::Codex);
+        let resolved = providers::resolve_codex_provider(
+            None,
+            c.base_url.clone(),
+            c.wire_api.clone(),
+            c.model.clone(),
+        );
+        let provider_id = c
+            .provider
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PROFILE_PROVIDER_ID.to_string());
+        codex::validate_codex_provider_id(&provider_id)?;
+        let env_key = c
+            .env_key
+            .clone()
+            .unwrap_or_else(|| codex::default_codex_env_key(&provider_id));
+        let display_name = match provider_id.as_str() {
+            DEFAULT_PROFILE_PROVIDER_ID => "Prismctl".to_string(),
+            id => id.to_string(),
+        };
+        let cfg = CodexProviderConfig {
+            provider_id,
+            display_name,
+            base_url: resolved.base_url,
+            wire_api: resolved.wire_api,
+            temp_env_key: env_key.clone(),
+            env_key: None,
+            requires_openai_auth: false,
+            model: resolved.model,
+        };
+        let config_path = root.join("config.toml");
+        let next = codex::upsert_codex_provider_in_config_toml(&read(&config_path), &cfg, true);
+        write(&mut cs, config_path, next);
+        let auth_path = root.join("auth.json");
+        let auth = read(&auth_path);
+        match &c.api_key {
+            Some(key) => {
+                let next = codex::upsert_codex_api_key_in_auth_json(&auth, &env_key, key)?;
+                write(&mut cs, auth_path, next);
+            }
+            None => {
+                if let Some(next) = json_text::remove_value_at_path(&auth, &[&env_key])? {
+                    write(&mut cs, auth_path, next);
+                }
+            }
+        }
+    }
+
+    if let Some(patch) = &profile.claude {
+        let path = home.claude_settings_path();
+        let existing = read(&path);
+        let patch = &keep_claude_tuning(patch, &claude::read_claude_env_patch(&existing)?);
+        let unset: Vec<String> = patch
+            .fields()
+            .iter()
+            .filter(|(_, v)| v.is_none())
+            .map(|(k, _)| k.to_string())
+            .collect();
+        let (cleared, _) = claude::unset_claude_env_keys(&existing, &unset)?;
+        let next = claude::apply_claude_env_patch_to_settings_json(&cleared, patch)?;
+        write(&mut cs, path, next);
+    }
+
+    if let Some(g) = &profile.gemini {
+        let root = home.tool_root(Tool::GeminiCli);
+        let env_path = root.join(".env");
+        let existing = read(&env_path);
+        let mut next = existing.clone();
+        if !env_file::read_managed_env(&existing).is_empty() {
+            next = env_file::upsert_managed_env(
+                &existing,
+                gemini::GEMINI_ENV_KEYS,
+                &[],
+                gemini::GEMINI_ENV_KEYS,
+            );
+        }
+        if !g.env.is_empty() {
+            next = gemini::upsert_gemini_env(&next, &g.env)?.0;
+        }
+        if next != existing {
+            write(&mut cs, env_path, next);
+        }
+
+        let settings_path = root.join("settings.json");
+        let settings = read(&settings_path);
+        match &g.model {
+            Some(model) => {
+                let next = gemini::set_gemini_model_name_in_settings_json(&settings, model)?;
+                write(&mut cs, settings_path, next);
+            }
+            None => {
+                if let Some(next) = json_text::remove_value_at_path(&settings, &["model", "name"])?
+                {
+                    write(&mut cs, settings_path, next);
+                }
+            }
+        }
+    }
+    Ok(cs)
+}
+
+/// Read the current Codex provider, Claude env and Gemini env/model into a profile.
+///
+/// Codex is captured from the default `model_provider` table; tools with nothing configured
+/// are left out.
+pub fn capture_profile(
+    home: &PrismctlHome,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> Result<Profile, String> {
+    let read = |p: &Path| read_existing(p).unwrap_or_default();
+    let mut profile = Profile::default();
+
+    let codex_root = home.tool_root(Tool::Codex);
+    let config = read(&codex_root.join("config.toml"));
+    let root_kv: BTreeMap<String, String> = toml_text::read_table_strings(&config, "")
+        .into_iter()
+        .collect();
+    if let Some(provider_id) = root_kv.get("model_provider") {
+        let kv: BTreeMap<String, String> =
+            toml_text::read_table_strings(&config, &format!("model_providers.{provider_id}"))
+                .into_iter()
+                .collect();
+        if !kv.is_empty() {
+            let env_key = kv
+                .get("temp_env_key")
+                .or_else(|| kv.get("env_key"))
+                .cloned();
+            let api_key = env_key.as_deref().and_then(|k| {
+                json_text::string_at_path(&read(&codex_root.join("auth.json")), &[k])
+                    .ok()
+                    .flatten()
+            });
+            profile.codex = Some(CodexProfile {
+                provider: Some(provider_id.clone()),
+                base_url: kv.get("base_url").cloned(),
+                wire_api: kv.get("wire_api").cloned(),
+                model: kv.get("model").or_else(|| root_kv.get("model")).cloned(),
+                env_key,
+                api_key,
+            });
+        }
+    }
+
+    let settings = read(&home.claude_settings_path());
+    let patch = claude::read_claude_env_patch(&settings)?;
+    if !patch.is_empty() {
+        profile.claude = Some(patch);
+    }
+
+    let gemini_root = home.tool_root(Tool::GeminiCli);
+    let env = read(&gemini_root.join(".env"));
+    let kv = env_file::read_managed_env(&env);
+    let model = gemini::read_gemini_model_name(&read(&gemini_root.join("settings.json")))?;
+    if !kv.is_empty() || model.is_some() {
+        let mut patch = GeminiEnvPatch::default();
+        if !kv.is_empty() {
+            let mode = gemini::detect_gemini_auth_mode(&env);
+            let key_name = match mode {
+                GeminiAuthMode::VertexAi => "GOOGLE_API_KEY",
+                _ => "GEMINI_API_KEY",
+            };
+            patch = GeminiEnvPatch {
+                mode: Some(mode),
+                api_key: kv.get(key_name).cloned(),
+                project: kv.get("GOOGLE_CLOUD_PROJECT").cloned(),
+                location: kv.get("GOOGLE_CLOUD_LOCATION").cloned(),
+                base_url: kv.get("GOOGLE_GEMINI_BASE_URL").cloned(),
+            };
+        }
+        profile.gemini = Some(GeminiProfile { env: patch, model });
+    }
+    Ok(profile)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// One profile value that differs from the current state.
+pub struct ProfileDiffEntry {
+    pub key: String,
+    pub current: Option<String>,
+    /// `None` when applying the profile unsets the key.
+    pub target: Option<String>,
+}
+
+/// What [`capture_profile`] reads back after applying `profile`: Codex provider defaults and the
+/// default Gemini auth mode filled in.
+fn effective_profile(profile: &Profile) -> Profile {
+    let mut out = profile.clone();
+    if let Some(c) = &mut out.codex {
+        let resolved = providers::resolve_codex_provider(
+            None,
+            c.base_url.clone(),
+            c.wire_api.clone(),
+            c.model.clone(),
+        );
+        c.base_url = Some(resolved.base_url);
+        c.wire_api = Some(resolved.wire_api);
+        c.model = Some(resolved.model);
+        let provider = c
+            .provider
+            .get_or_insert_with(|| DEFAULT_PROFILE_PROVIDER_ID.to_string());
+        let env_key = codex::default_codex_env_key(provider);
+        c.env_key.get_or_insert(env_key);
+    }
+    if let Some(g) = &mut out.gemini {
+        if !g.env.is_empty() && g.env.mode.is_none() {
+            g.env.mode = Some(gemini::detect_gemini_auth_mode(""));
+        }
+    }
+    out
+}
+
+/// Values `use` would change: keys `target` sets to a different value, and keys of the sections
+/// `target` covers that it would unset. Sections `target` doesn't cover are not compared.
+pub fn diff_profile(current: &Profile, target: &Profile) -> Vec<ProfileDiffEntry> {
+    let mut target = effective_profile(target);
+    if let (Some(t), Some(c)) = (&mut target.claude, &current.claude) {
+        *t = keep_claude_tuning(t, c);
+    }
+    let sections = target.sections();
+    let current: BTreeMap<String, String> = current
+        .entries()
+        .into_iter()
+        .filter(|(k, _)| {
+            sections
+                .iter()
+                .any(|s| k.split_once('.').map(|(p, _)| p) == Some(*s))
+        })
+        .collect();
+    let target: Vec<(String, String)> = target.entries();
+    let mut out: Vec<ProfileDiffEntry> = target
+        .iter()
+        .filter(|(k, v)| current.get(k) != Some(v))
+        .map(|(key, value)| ProfileDiffEntry {
+            key: key.clone(),
+            current: current.get(key).cloned(),
+            target: Some(value.clone()),
+        })
+        .collect();
+    out.extend(
+        current
+            .into_iter()
+            .filter(|(k, _)| !target.iter().any(|(t, _)| t == k))
+            .map(|(key, value)| ProfileDiffEntry {
+                key,
+                current: Some(value),
+                target: None,
+            }),
+    );
+    out
+}
+
+/// `patch` with `current`'s tuning values (`API_TIMEOUT_MS`, `CLAUDE_CODE_MAX_OUTPUT_TOKENS`,
+/// `DISABLE_TELEMETRY`) where it leaves them out: a profile owns the provider and model keys.
+fn keep_claude_tuning(patch: &ClaudeEnvPatch, current: &ClaudeEnvPatch) -> ClaudeEnvPatch {
+    let mut out = patch.clone();
+    for (slot, value) in [
+        (&mut out.api_timeout_ms, &current.api_timeout_ms),
+        (&mut out.max_output_tokens, &current.max_output_tokens),
+        (&mut out.disable_telemetry, &current.disable_telemetry),
+    ] {
+        if slot.is_none() {
+            *slot = value.clone();
+        }
+    }
+    out
+}
+
+/// Whether the current configuration is exactly `target`: nothing to change, and no configured
+/// tool outside the sections `target` covers.
+pub fn is_active_profile(current: &Profile, target: &Profile) -> bool {
+    let sections = target.sections();
+    !target.entries().is_empty()
+        && current.sections().iter().all(|s| sections.contains(s))
+        && diff_profile(current, target).is_empty()
+}
+
+/// Mask credentials (`*.api_key`, `*.auth_token`) for display, keeping the last 4 characters.
+pub fn display_profile_value(key: &str, value: &str) -> String {
+    if key.ends_with("api_key") || key.ends_with("auth_token") {
+        redact::mask_secret(value)
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    const SAMPLE: &str = r#"
+[codex]
+base_url = "https://gw.example.com/v1"
+model = "gpt-5"
+api_key = "sk-work-123456789"
+
+[claude]
+auth_token = "tok-work-abcdefgh"
+base_url = "https://gw.example.com/anthropic"
+api_timeout_ms = "600000"
+
+[gemini]
+mode = "api-key"
+api_key = "gm-work-key"
+model = "gemini-2.5-pro"
+"#;
+
+    #[test]
+    fn parse_render_roundtrip() {
+        let profile = parse_profile(SAMPLE).expect("parse");
+        let reparsed = parse_profile(&render_profile(&profile)).expect("reparse");
+        assert_eq!(profile.entries(), reparsed.entries());
+        assert!(parse_profile("[codex]\nfoo = \"x\"\n").is_err());
+        assert!(parse_profile("[gemini]\nmode = \"oauth\"\n").is_err());
+    }
+
+    /// Plan `profile` against `files` and apply the writes to it.
+    fn use_profile(
+        home: &PrismctlHome,
+        profile: &Profile,
+        files: &RefCell<BTreeMap<PathBuf, String>>,
+    ) -> Profile {
+        let cs =
+            plan_profile_use(home, profile, &|p| files.borrow().get(p).cloned()).expect("plan");
+        for c in cs.iter() {
+            if let Change::WriteFile { path, bytes, .. } = c {
+                files
+                    .borrow_mut()
+                    .insert(path.clone(), String::from_utf8(bytes.clone()).unwrap());
+            }
+        }
+        capture_profile(home, &|p| files.borrow().get(p).cloned()).expect("capture")
+    }
+
+    #[test]
+    fn use_then_capture_restores_profile() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-profile-test"));
+        let profile = parse_profile(SAMPLE).expect("parse");
+        let files = RefCell::new(BTreeMap::new());
+        let captured = use_profile(&home, &profile, &files);
+        assert_eq!(files.borrow().len(), 5);
+        assert!(diff_profile(&captured, &profile).is_empty());
+        assert!(is_active_profile(&captured, &profile));
+    }
+
+    #[test]
+    fn switching_profiles_unsets_keys_the_new_one_leaves_out() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-profile-test"));
+        let work = parse_profile(SAMPLE).expect("parse");
+        let personal = parse_profile(
+            "[codex]\nprovider = \"personal\"\nmodel = \"gpt-5-mini\"\n\n[claude]\nauth_token = \"tok-personal-12345\"\n\n[gemini]\nmode = \"api-key\"\napi_key = \"gm-personal-key\"\n",
+        )
+        .expect("parse");
+        let files = RefCell::new(BTreeMap::new());
+        let on_work = use_profile(&home, &work, &files);
+
+        let diff = diff_profile(&on_work, &personal);
+        assert!(diff
+            .iter()
+            .any(|d| d.key == "claude.base_url" && d.target.is_none()));
+        assert!(diff
+            .iter()
+            .any(|d| d.key == "gemini.model" && d.target.is_none()));
+        assert!(!diff.iter().any(|d| d.key == "claude.api_timeout_ms"));
+
+        let on_personal = use_profile(&home, &personal, &files);
+        let codex = on_personal.codex.as_ref().expect("codex");
+        assert_eq!(codex.provider.as_deref(), Some("personal"));
+        assert_eq!(codex.env_key.as_deref(), Some("PRISMCTL_PERSONAL_API_KEY"));
+        assert_eq!(codex.base_url.as_deref(), Some("https://api.openai.com/v1"));
+        assert_eq!(codex.api_key, None);
+        let claude = on_personal.claude.as_ref().expect("claude");
+        assert_eq!(claude.auth_token.as_deref(), Some("tok-personal-12345"));
+        assert_eq!(claude.base_url, None);
+        assert_eq!(claude.api_timeout_ms.as_deref(), Some("600000"));
+        let gemini = on_personal.gemini.as_ref().expect("gemini");
+        assert_eq!(gemini.env.api_key.as_deref(), Some("gm-personal-key"));
+        assert_eq!(gemini.model, None);
+
+        assert!(diff_profile(&on_personal, &personal).is_empty());
+        assert!(is_active_profile(&on_personal, &personal));
+        assert!(!is_active_profile(&on_personal, &work));
+        let codex_only = parse_profile("[codex]\nmodel = \"gpt-5-mini\"\n").expect("parse");
+        assert!(!is_active_profile(&on_personal, &codex_only));
+    }
+
+    #[test]
+    fn diff_reports_changed_values_and_masks_secrets() {
+        let current = parse_profile("[codex]\nmodel = \"gpt-5\"\n").unwrap();
+        let target = parse_profile(SAMPLE).unwrap();
+        let diff = diff_profile(&current, &target);
+        assert!(diff.iter().all(|d| d.key != "codex.model"));
+        assert!(diff
+            .iter()
+            .any(|d| d.key == "codex.base_url" && d.current.is_none()));
+        assert!(diff_profile(&target, &current)
+            .iter()
+            .any(|d| d.key == "codex.api_key" && d.target.is_none()));
+        assert_eq!(
+            display_profile_value("codex.api_key", "sk-work-123456789"),
+            "****6789"
+        );
+        assert_eq!(display_profile_value("codex.model", "gpt-5"), "gpt-5");
+    }
+}
@@ -1,7 +1,10 @@
 use crate::changeset::{Change, ChangeSet};
 use crate::managed_block::upsert_managed_block;
 use crate::templates::TemplateLang;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
+
+const INSTRUCTIONS_START: &str = "<!-- prismctl:instructions:start -->";
+const INSTRUCTIONS_END: &str = "<!-- prismctl:instructions:end -->";
 
 /// Plan project initialization: create `.prismctl/plan/` directories and manage `.gemini/GEMINI.md`.
 pub fn plan_project_init(
@@ -44,6 +47,35 @@ pub fn plan_project_init(
     cs
 }
 
+/// Plan the project-level Qwen Code context file (`<project>/QWEN.md`) via a managed block.
+pub fn plan_project_qwen_md(
+    project_root: &Path,
+    lang: TemplateLang,
+    existing_qwen_md: &str,
+) -> ChangeSet {
+    let mut cs = ChangeSet::new();
+    cs.push(Change::WriteFile {
+        path: project_root.join("QWEN.md"),
+        bytes: render_project_qwen_md(existing_qwen_md, lang).into_bytes(),
+        overwrite: true,
+    });
+    cs
+}
+
+/// Render and upsert the Prismctl-managed block for a project-level `QWEN.md`.
+pub fn render_project_qwen_md(existing: &str, lang: TemplateLang) -> String {
+    let block = match lang {
+        TemplateLang::ZhCn => include_str!("../assets/qwen/zh-CN/PROJECT_QWEN.md"),
+        TemplateLang::En => include_str!("../assets/qwen/en/PROJECT_QWEN.md"),
+    };
+    upsert_managed_block(
+        existing,
+        "<!-- prismctl:start -->",
+        "<!-- prismctl:end -->",
+        block,
+    )
+}
+
 /// Render and upsert the Prismctl-managed block for a project-level `.gemini/GEMINI.md`.
 pub fn render_project_gemini_md(existing: &str, lang: TemplateLang) -> String {
     let block = match lang {
@@ -58,6 +90,116 @@ pub fn render_project_gemini_md(existing: &str, lang: TemplateLang) -> String {
     )
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How synced instructions are embedded into each target file.
+pub enum InstructionsSyncMode {
+    /// Copy the source content into a managed block.
+    Block,
+    /// Write an `@<path>` import line (inside a managed block) pointing at the source.
+    Import,
+}
+
+/// Result of planning `project instructions sync`.
+pub struct InstructionsSyncPlan {
+    /// Writes for targets that are out of date.
+    pub changeset: ChangeSet,
+    /// Targets (absolute paths) whose managed block differs from the source.
+    pub drifted: Vec<PathBuf>,
+}
+
+/// Default source: `.prismctl/instructions.md` if present, otherwise `AGENTS.md`.
+pub fn default_instructions_source(project_root: &Path) -> PathBuf {
+    let canonical = project_root.join(".prismctl").join("instructions.md");
+    if canonical.exists() {
+        canonical
+    } else {
+        project_root.join("AGENTS.md")
+    }
+}
+
+/// Files kept in sync with the instructions source (`AGENTS.md`, `CLAUDE.md`, `.gemini/GEMINI.md`).
+pub fn instructions_sync_targets(project_root: &Path) -> Vec<PathBuf> {
+    vec![
+        project_root.join("AGENTS.md"),
+        project_root.join("CLAUDE.md"),
+        project_root.join(".gemini").join("GEMINI.md"),
+    ]
+}
+
+/// Plan syncing one canonical instructions file into the other tools' project memory files.
+///
+/// `source` must live under `project_root`; a target equal to the source is skipped. Codex has no
+/// `@` import syntax, so `AGENTS.md` always gets the source content as a block.
+/// `read_existing` returns the current content of a path, if any.
+pub fn plan_instructions_sync(
+    project_root: &Path,
+    source: &Path,
+    source_content: &str,
+    mode: InstructionsSyncMode,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> Result<InstructionsSyncPlan, String> {
+    let source_rel = source
+        .strip_prefix(project_root)
+        .map_err(|_| format!("指令源文件必须位于项目目录内: {}", source.display()))?;
+
+    let mut changeset = ChangeSet::new();
+    let mut drifted = Vec::new();
+    for target in instructions_sync_targets(project_root) {
+        if target == source {
+            continue;
+        }
+        let existing = read_existing(&target).unwrap_or_default();
+        let target_mode = if target.ends_with("AGENTS.md") {
+            InstructionsSyncMode::Block
+        } else {
+            mode
+        };
+        let block = match target_mode {
+            InstructionsSyncMode::Block => source_content.to_string(),
+            InstructionsSyncMode::Import => {
+                let target_rel = target.strip_prefix(project_root).unwrap_or(&target);
+                format!("@{}\n", import_path(target_rel, source_rel))
+            }
+        };
+        let next = upsert_managed_block(&existing, INSTRUCTIONS_START, INSTRUCTIONS_END, &block);
+        if next == existing {
+            continue;
+        }
+
+        if let Some(parent) = target.parent() {
+            changeset.push(Change::CreateDirAll {
+                path: parent.to_path_buf(),
+            });
+        }
+        changeset.push(Change::WriteFile {
+            path: target.clone(),
+            bytes: next.into_bytes(),
+            overwrite: true,
+        });
+        drifted.push(target);
+    }
+    Ok(InstructionsSyncPlan { changeset, drifted })
+}
+
+/// `source_rel` as seen from the directory containing `target_rel` (both project-relative).
+fn import_path(target_rel: &Path, source_rel: &Path) -> String {
+    let depth = target_rel
+        .parent()
+        .map(|p| {
+            p.components()
+                .filter(|c| matches!(c, Component::Normal(_)))
+                .count()
+        })
+        .unwrap_or(0);
+    let mut parts: Vec<String> = vec!["..".to_string(); depth];
+    parts.extend(
+        source_rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
+
 fn render_prismctl_plan_readme(lang: TemplateLang) -> &'static str {
     match lang {
         TemplateLang::ZhCn => include_str!("../assets/project/zh-CN/PRISMCTL_PLAN_README.md"),
@@ -103,6 +245,83 @@ mod tests {
         assert!(out.contains("<!-- prismctl:end -->"));
     }
 
+    #[test]
+    fn instructions_sync_imports_relative_to_target() {
+        let root = PathBuf::from("/tmp/project");
+        let plan = plan_instructions_sync(
+            &root,
+            &root.join("AGENTS.md"),
+            "# Rules\n",
+            InstructionsSyncMode::Import,
+            &|_| None,
+        )
+        .expect("plan");
+
+        assert_eq!(plan.drifted.len(), 2);
+        let contents: Vec<String> = plan
+            .changeset
+            .iter()
+            .filter_map(|c| match c {
+                Change::WriteFile { bytes, .. } => Some(String::from_utf8(bytes.clone()).unwrap()),
+                _ => None,
+            })
+            .collect();
+        assert!(contents[0].contains("\n@AGENTS.md\n"));
+        assert!(contents[1].contains("\n@../AGENTS.md\n"));
+    }
+
+    #[test]
+    fn instructions_sync_copies_into_agents_md() {
+        let root = PathBuf::from("/tmp/project");
+        let plan = plan_instructions_sync(
+            &root,
+            &root.join(".prismctl/instructions.md"),
+            "# Rules\n",
+            InstructionsSyncMode::Import,
+            &|_| Some("# Codex notes\n".to_string()),
+        )
+        .expect("plan");
+
+        assert_eq!(plan.drifted.len(), 3);
+        let agents = plan.changeset.written("AGENTS.md");
+        assert!(agents.starts_with("# Codex notes\n"));
+        assert!(agents.contains("# Rules\n"));
+        assert!(!agents.contains('@'));
+        let claude = plan.changeset.written("CLAUDE.md");
+        assert!(claude.contains("\n@.prismctl/instructions.md\n"));
+    }
+
+    #[test]
+    fn instructions_sync_reports_no_drift_when_up_to_date() {
+        let root = PathBuf::from("/tmp/project");
+        let source = root.join(".prismctl/instructions.md");
+        let synced = upsert_managed_block(
+            "# Mine\n",
+            INSTRUCTIONS_START,
+            INSTRUCTIONS_END,
+            "# Rules\n",
+        );
+        let plan = plan_instructions_sync(
+            &root,
+            &source,
+            "# Rules\n",
+            InstructionsSyncMode::Block,
+            &|_| Some(synced.clone()),
+        )
+        .expect("plan");
+
+        assert!(plan.drifted.is_empty());
+        assert!(plan.changeset.is_empty());
+        assert!(plan_instructions_sync(
+            &root,
+            Path::new("/elsewhere/AGENTS.md"),
+            "",
+            InstructionsSyncMode::Block,
+            &|_| None,
+        )
+        .is_err());
+    }
+
     #[test]
     fn project_init_writes_expected_paths() {
         let root = unique_root();
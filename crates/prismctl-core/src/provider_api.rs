@@ -0,0 +1,680 @@
+use crate::claude;
+use crate::env_file;
+use crate::gemini::{self, GeminiAuthMode};
+use crate::json_text;
+use crate::paths::{ConfigScope, PrismctlHome, Tool};
+use crate::toml_text;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::Path;
+use std::time::Instant;
+
+const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const ANTHROPIC_BASE_URL: &str = "https://api.anthropic.com";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const ANTHROPIC_PROBE_MODEL: &str = "claude-haiku-4-5";
+const GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com";
+const VERTEX_BASE_URL: &str = "https://aiplatform.googleapis.com";
+const GEMINI_PROBE_MODEL: &str = "gemini-2.5-flash";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A minimal HTTP request; credentials are always sent as headers, never in the URL.
+pub struct HttpRequest {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Status and body of a completed request (including non-2xx responses).
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Failures before an HTTP status was received.
+pub enum TransportError {
+    Timeout,
+    Dns,
+    ConnectionRefused,
+    Tls,
+    Other(String),
+}
+
+/// Sends requests for [`probe_endpoint`]; the CLI provides the real implementation so the core
+/// crate stays free of network dependencies.
+pub trait HttpClient {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, TransportError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Wire protocol spoken by the configured endpoint.
+pub enum ApiFlavor {
+    /// OpenAI-compatible (`Authorization: Bearer`, `/models`).
+    OpenAi,
+    /// Anthropic Messages API (`x-api-key`, `/v1/messages`).
+    Anthropic,
+    /// Gemini API (`x-goog-api-key`, `/v1beta/models`).
+    Gemini,
+    /// Vertex AI express mode (`GOOGLE_API_KEY`).
+    VertexAi,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the key is sent; Claude Code sends `ANTHROPIC_AUTH_TOKEN` as a bearer token and
+/// `ANTHROPIC_API_KEY` as `x-api-key`, and gateways often accept only one of them.
+pub enum AuthHeader {
+    /// `Authorization: Bearer <key>`.
+    Bearer,
+    /// `x-api-key: <key>`.
+    XApiKey,
+    /// `x-goog-api-key: <key>`.
+    XGoogApiKey,
+}
+
+impl AuthHeader {
+    fn header(self, key: &str) -> (String, String) {
+        match self {
+            AuthHeader::Bearer => ("Authorization".to_string(), format!("Bearer {key}")),
+            AuthHeader::XApiKey => ("x-api-key".to_string(), key.to_string()),
+            AuthHeader::XGoogApiKey => ("x-goog-api-key".to_string(), key.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The effective endpoint a tool would talk to, resolved from its config files.
+pub struct Endpoint {
+    pub flavor: ApiFlavor,
+    pub base_url: String,
+    pub api_key: Option<String>,
+    pub auth_header: AuthHeader,
+    pub model: Option<String>,
+}
+
+/// Resolve the endpoint Codex, Claude Code or Gemini CLI is configured to use.
+///
+/// Reads `config.toml`/`auth.json`, `settings.json` or `.env` under HOME (via `read_existing`);
+/// Claude Code settings are layered user < project < local under `project_root`. Credentials fall
+/// back to the process environment like the tools themselves do.
+pub fn resolve_endpoint(
+    home: &PrismctlHome,
+    project_root: &Path,
+    tool: Tool,
+    read_existing: &dyn Fn(&Path) -> Option<String>,
+) -> Result<Endpoint, String> {
+    let read = |p: &Path| read_existing(p).unwrap_or_default();
+    match tool {
+        Tool::Codex => {
+            let root = home.tool_root(Tool::Codex);
+            let config = read(&root.join("config.toml"));
+            let root_kv: BTreeMap<String, String> = toml_text::read_table_strings(&config, "")
+                .into_iter()
+                .collect();
+            let auth = read(&root.join("auth.json"));
+            let auth_value = |key: &str| json_text::string_at_path(&auth, &[key]).ok().flatten();
+
+            let provider = root_kv
+                .get("model_provider")
+                .map(String::as_str)
+                .unwrap_or("openai");
+            let kv: BTreeMap<String, String> =
+                toml_text::read_table_strings(&config, &format!("model_providers.{provider}"))
+                    .into_iter()
+                    .collect();
+            if kv.is_empty() && provider != "openai" {
+                return Err(format!("config.toml 中未找到 [model_providers.{provider}]"));
+            }
+            // A key stored by Prismctl in auth.json wins over Codex's `env_key` lookup.
+            let api_key = match (kv.get("temp_env_key"), kv.get("env_key")) {
+                (None, None) if kv.is_empty() => {
+                    auth_value("OPENAI_API_KEY").or_else(|| env_value("OPENAI_API_KEY"))
+                }
+                (auth_key, env_key) => auth_key
+                    .and_then(|k| auth_value(k))
+                    .or_else(|| env_key.and_then(|k| env_value(k))),
+            };
+            Ok(Endpoint {
+                flavor: ApiFlavor::OpenAi,
+                base_url: kv
+                    .get("base_url")
+                    .cloned()
+                    .unwrap_or_else(|| OPENAI_BASE_URL.to_string()),
+                api_key,
+                auth_header: AuthHeader::Bearer,
+                model: kv.get("model").or_else(|| root_kv.get("model")).cloned(),
+            })
+        }
+        Tool::ClaudeCode => {
+            // Highest precedence first, as Claude Code merges them.
+            let layers: Vec<String> = [ConfigScope::Local, ConfigScope::Project, ConfigScope::User]
+                .into_iter()
+                .map(|scope| read(&claude::claude_settings_path(scope, home, project_root)))
+                .collect();
+            let get = |key: &str| {
+                layers
+                    .iter()
+                    .find_map(|settings| {
+                        json_text::string_at_path(settings, &["env", key])
+                            .ok()
+                            .flatten()
+                    })
+                    .or_else(|| env_value(key))
+            };
+            let (api_key, auth_header) = match get("ANTHROPIC_AUTH_TOKEN") {
+                Some(token) => (Some(token), AuthHeader::Bearer),
+                None => (get("ANTHROPIC_API_KEY"), AuthHeader::XApiKey),
+            };
+            Ok(Endpoint {
+                flavor: ApiFlavor::Anthropic,
+                base_url: get("ANTHROPIC_BASE_URL")
+                    .unwrap_or_else(|| ANTHROPIC_BASE_URL.to_string()),
+                api_key,
+                auth_header,
+                model: get("ANTHROPIC_MODEL"),
+            })
+        }
+        Tool::GeminiCli => {
+            let root = home.tool_root(Tool::GeminiCli);
+            let env_content = read(&root.join(".env"));
+            let kv = env_file::parse_env_block(&env_content);
+            let get = |key: &str| kv.get(key).cloned().or_else(|| env_value(key));
+            let model = gemini::read_gemini_model_name(&read(&root.join("settings.json")))?;
+            let endpoint = match gemini::detect_gemini_auth_mode(&env_content) {
+                GeminiAuthMode::VertexAi => Endpoint {
+                    flavor: ApiFlavor::VertexAi,
+                    base_url: VERTEX_BASE_URL.to_string(),
+                    api_key: get("GOOGLE_API_KEY"),
+                    auth_header: AuthHeader::XGoogApiKey,
+                    model,
+                },
+                _ => Endpoint {
+                    flavor: ApiFlavor::Gemini,
+                    base_url: get("GOOGLE_GEMINI_BASE_URL")
+                        .unwrap_or_else(|| GEMINI_BASE_URL.to_string()),
+                    api_key: get("GEMINI_API_KEY"),
+                    auth_header: AuthHeader::XGoogApiKey,
+                    model,
+                },
+            };
+            Ok(endpoint)
+        }
+        _ => Err("provider 命令仅支持 codex、claude、gemini".to_string()),
+    }
+}
+
+fn env_value(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|v| !v.trim().is_empty())
+}
+
+fn join_url(base: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+/// Build the cheapest authenticated request for an endpoint: a models list for OpenAI-compatible
+/// and Gemini APIs, a one-token completion for Anthropic and Vertex AI.
+///
+/// Returns `None` when a required credential is missing (OpenAI-compatible endpoints such as
+/// Ollama may run without a key).
+pub fn test_request(endpoint: &Endpoint) -> Option<HttpRequest> {
+    let key = endpoint.api_key.clone();
+    let request = match endpoint.flavor {
+        ApiFlavor::OpenAi => HttpRequest {
+            method: "GET",
+            url: join_url(&endpoint.base_url, "models"),
+            headers: key
+                .map(|k| vec![endpoint.auth_header.header(&k)])
+                .unwrap_or_default(),
+            body: None,
+        },
+        ApiFlavor::Anthropic => {
+            let key = key?;
+            let model = endpoint
+                .model
+                .clone()
+                .unwrap_or_else(|| ANTHROPIC_PROBE_MODEL.to_string());
+            HttpRequest {
+                method: "POST",
+                url: join_url(&endpoint.base_url, "v1/messages"),
+                headers: vec![
+                    endpoint.auth_header.header(&key),
+                    (
+                        "anthropic-version".to_string(),
+                        ANTHROPIC_VERSION.to_string(),
+                    ),
+                    ("Content-Type".to_string(), "application/json".to_string()),
+                ],
+                body: Some(
+                    json!({
+                        "model": model,
+                        "max_tokens": 1,
+                        "messages": [{"role": "user", "content": "ping"}],
+                    })
+                    .to_string(),
+                ),
+            }
+        }
+        ApiFlavor::Gemini => HttpRequest {
+            method: "GET",
+            url: join_url(&endpoint.base_url, "v1beta/models?pageSize=1"),
+            headers: vec![endpoint.auth_header.header(&key?)],
+            body: None,
+        },
+        ApiFlavor::VertexAi => {
+            let model = endpoint
+                .model
+                .clone()
+                .unwrap_or_else(|| GEMINI_PROBE_MODEL.to_string());
+            HttpRequest {
+                method: "POST",
+                url: join_url(
+                    &endpoint.base_url,
+                    &format!("v1/publishers/google/models/{model}:generateContent"),
+                ),
+                headers: vec![
+                    endpoint.auth_header.header(&key?),
+                    ("Content-Type".to_string(), "application/json".to_string()),
+                ],
+                body: Some(
+                    json!({
+                        "contents": [{"role": "user", "parts": [{"text": "ping"}]}],
+                        "generationConfig": {"maxOutputTokens": 1},
+                    })
+                    .to_string(),
+                ),
+            }
+        }
+    };
+    Some(request)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Classified result of a connectivity probe.
+pub enum ProbeOutcome {
+    Ok,
+    /// 401: missing or invalid key.
+    Unauthorized,
+    /// 403: key valid but not allowed (region, plan, disabled API).
+    Forbidden,
+    /// 404: wrong base URL/path or unknown model.
+    NotFound,
+    /// 400/422: the endpoint rejected the request (often an unknown model).
+    BadRequest,
+    RateLimited,
+    ServerError,
+    UnexpectedStatus,
+    Timeout,
+    Dns,
+    ConnectionRefused,
+    Tls,
+    Network,
+    /// No credential configured; no request was sent.
+    MissingCredentials,
+    /// The endpoint type does not support this operation; no request was sent.
+    Unsupported,
+}
+
+impl ProbeOutcome {
+    pub fn id(&self) -> &'static str {
+        match self {
+            ProbeOutcome::Ok => "ok",
+            ProbeOutcome::Unauthorized => "unauthorized",
+            ProbeOutcome::Forbidden => "forbidden",
+            ProbeOutcome::NotFound => "not-found",
+            ProbeOutcome::BadRequest => "bad-request",
+            ProbeOutcome::RateLimited => "rate-limited",
+            ProbeOutcome::ServerError => "server-error",
+            ProbeOutcome::UnexpectedStatus => "unexpected-status",
+            ProbeOutcome::Timeout => "timeout",
+            ProbeOutcome::Dns => "dns",
+            ProbeOutcome::ConnectionRefused => "connection-refused",
+            ProbeOutcome::Tls => "tls",
+            ProbeOutcome::Network => "network",
+            ProbeOutcome::MissingCredentials => "missing-credentials",
+            ProbeOutcome::Unsupported => "unsupported",
+        }
+    }
+}
+
+pub fn classify_status(status: u16) -> ProbeOutcome {
+    match status {
+        200..=299 => ProbeOutcome::Ok,
+        401 => ProbeOutcome::Unauthorized,
+        403 => ProbeOutcome::Forbidden,
+        404 => ProbeOutcome::NotFound,
+        400 | 422 => ProbeOutcome::BadRequest,
+        429 => ProbeOutcome::RateLimited,
+        500..=599 => ProbeOutcome::ServerError,
+        _ => ProbeOutcome::UnexpectedStatus,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Result of [`probe_endpoint`].
+pub struct ProbeReport {
+    /// Requested URL (never contains credentials).
+    pub url: String,
+    pub status: Option<u16>,
+    pub latency_ms: u128,
+    pub outcome: ProbeOutcome,
+    /// Error message from the response body or transport layer, if any.
+    pub detail: Option<String>,
+}
+
+/// Send [`test_request`] for `endpoint` and classify the result.
+pub fn probe_endpoint(client: &dyn HttpClient, endpoint: &Endpoint) -> ProbeReport {
+    let Some(request) = test_request(endpoint) else {
+        return not_sent(endpoint, ProbeOutcome::MissingCredentials);
+    };
+    execute(client, request).0
+}
+
+/// Build the request listing available models: `/models` for OpenAI-compatible endpoints,
+/// `/api/tags` for Ollama, `/v1/models` for Anthropic and `/v1beta/models` for Gemini.
+///
+/// Returns `None` when a required credential is missing.
+pub fn models_request(endpoint: &Endpoint) -> Option<HttpRequest> {
+    if endpoint.flavor == ApiFlavor::OpenAi && is_ollama_base_url(&endpoint.base_url) {
+        let root = endpoint
+            .base_url
+            .trim_end_matches('/')
+            .trim_end_matches("/v1");
+        return Some(HttpRequest {
+            method: "GET",
+            url: join_url(root, "api/tags"),
+            headers: Vec::new(),
+            body: None,
+        });
+    }
+    let mut request = test_request(endpoint)?;
+    match endpoint.flavor {
+        ApiFlavor::Anthropic => {
+            request.method = "GET";
+            request.url = join_url(&endpoint.base_url, "v1/models?limit=1000");
+            request.headers.retain(|(k, _)| k != "Content-Type");
+            request.body = None;
+        }
+        ApiFlavor::Gemini => {
+            request.url = join_url(&endpoint.base_url, "v1beta/models?pageSize=1000");
+        }
+        ApiFlavor::OpenAi | ApiFlavor::VertexAi => {}
+    }
+    Some(request)
+}
+
+/// Ollama's default port; its native model list lives at `/api/tags` outside the `/v1` prefix.
+fn is_ollama_base_url(base_url: &str) -> bool {
+    base_url
+        .split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .is_some_and(|host| host.ends_with(":11434"))
+}
+
+/// Model ids from a models-list response (`data[].id`, `models[].name`), sorted and deduplicated.
+pub fn parse_model_ids(body: &str) -> Vec<String> {
+    let Ok(v) = serde_json::from_str::<Value>(body) else {
+        return Vec::new();
+    };
+    let mut ids: Vec<String> = match (v.get("data"), v.get("models")) {
+        (Some(Value::Array(items)), _) => items
+            .iter()
+            .filter_map(|m| m.get("id").and_then(Value::as_str))
+            .map(str::to_string)
+            .collect(),
+        (_, Some(Value::Array(items))) => items
+            .iter()
+            .filter_map(|m| m.get("name").and_then(Value::as_str))
+            .map(|name| name.trim_start_matches("models/").to_string())
+            .collect(),
+        _ => Vec::new(),
+    };
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// List models served by `endpoint`; the error carries the classified failure.
+///
+/// Vertex AI is not supported (its model catalog is not keyed by API key).
+pub fn list_models(
+    client: &dyn HttpClient,
+    endpoint: &Endpoint,
+) -> Result<Vec<String>, ProbeReport> {
+    if endpoint.flavor == ApiFlavor::VertexAi {
+        return Err(not_sent(endpoint, ProbeOutcome::Unsupported));
+    }
+    let Some(request) = models_request(endpoint) else {
+        return Err(not_sent(endpoint, ProbeOutcome::MissingCredentials));
+    };
+    match execute(client, request) {
+        (report, Some(body)) if report.outcome == ProbeOutcome::Ok => Ok(parse_model_ids(&body)),
+        (report, _) => Err(report),
+    }
+}
+
+fn not_sent(endpoint: &Endpoint, outcome: ProbeOutcome) -> ProbeReport {
+    ProbeReport {
+        url: endpoint.base_url.clone(),
+        status: None,
+        latency_ms: 0,
+        outcome,
+        detail: None,
+    }
+}
+
+/// Send `request` and classify the result; the body is returned for successful responses.
+fn execute(client: &dyn HttpClient, request: HttpRequest) -> (ProbeReport, Option<String>) {
+    let started = Instant::now();
+    let result = client.send(&request);
+    let latency_ms = started.elapsed().as_millis();
+    let (status, outcome, detail, body) = match result {
+        Ok(resp) => {
+            let outcome = classify_status(resp.status);
+            if outcome == ProbeOutcome::Ok {
+                (Some(resp.status), outcome, None, Some(resp.body))
+            } else {
+                (Some(resp.status), outcome, error_message(&resp.body), None)
+            }
+        }
+        Err(e) => {
+            let (outcome, detail) = match e {
+                TransportError::Timeout => (ProbeOutcome::Timeout, None),
+                TransportError::Dns => (ProbeOutcome::Dns, None),
+                TransportError::ConnectionRefused => (ProbeOutcome::ConnectionRefused, None),
+                TransportError::Tls => (ProbeOutcome::Tls, None),
+                TransportError::Other(msg) => (ProbeOutcome::Network, Some(msg)),
+            };
+            (None, outcome, detail, None)
+        }
+    };
+    let report = ProbeReport {
+        url: request.url,
+        status,
+        latency_ms,
+        outcome,
+        detail,
+    };
+    (report, body)
+}
+
+/// Extract a short error message (`error.message`, `message` or `error`) from a response body.
+fn error_message(body: &str) -> Option<String> {
+    let message = match serde_json::from_str::<Value>(body) {
+        Ok(v) => v
+            .pointer("/error/message")
+            .or_else(|| v.get("message"))
+            .or_else(|| v.get("error"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        Err(_) => Some(body.trim().to_string()).filter(|s| !s.is_empty()),
+    }?;
+    Some(message.chars().take(200).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+
+    struct StubClient {
+        result: Result<HttpResponse, TransportError>,
+        seen: RefCell<Vec<HttpRequest>>,
+    }
+
+    impl HttpClient for StubClient {
+        fn send(&self, request: &HttpRequest) -> Result<HttpResponse, TransportError> {
+            self.seen.borrow_mut().push(request.clone());
+            self.result.clone()
+        }
+    }
+
+    fn stub(result: Result<HttpResponse, TransportError>) -> StubClient {
+        StubClient {
+            result,
+            seen: RefCell::new(Vec::new()),
+        }
+    }
+
+    #[test]
+    fn resolves_codex_provider_key_from_auth_json() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-probe"));
+        let read = |p: &Path| {
+            match p.file_name()?.to_str()? {
+            "config.toml" => Some(
+                "model_provider = \"prismctl\"\n\n[model_providers.prismctl]\nbase_url = \"http://gw/v1/\"\ntemp_env_key = \"K\"\nrequires_openai_auth = false\nmodel = \"m\"\n"
+                    .to_string(),
+            ),
+            "auth.json" => Some("{\"K\": \"sk-1\"}".to_string()),
+            _ => None,
+        }
+        };
+        let ep = resolve_endpoint(&home, Path::new("/work"), Tool::Codex, &read).expect("resolve");
+        assert_eq!(ep.api_key.as_deref(), Some("sk-1"));
+        assert_eq!(ep.model.as_deref(), Some("m"));
+
+        let req = test_request(&ep).expect("request");
+        assert_eq!(req.url, "http://gw/v1/models");
+        assert_eq!(
+            req.headers,
+            vec![("Authorization".to_string(), "Bearer sk-1".to_string())]
+        );
+    }
+
+    #[test]
+    fn resolves_claude_settings_across_scopes() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-probe"));
+        let read = |p: &Path| match p.to_str()? {
+            "/work/.claude/settings.local.json" => {
+                Some("{\"env\": {\"ANTHROPIC_API_KEY\": \"sk-local\"}}".to_string())
+            }
+            "/work/.claude/settings.json" => Some(
+                "{\"env\": {\"ANTHROPIC_BASE_URL\": \"http://gw\", \"ANTHROPIC_MODEL\": \"p\"}}"
+                    .to_string(),
+            ),
+            _ if p.starts_with("/tmp/prismctl-probe") => {
+                Some("{\"env\": {\"ANTHROPIC_MODEL\": \"u\"}}".to_string())
+            }
+            _ => None,
+        };
+        let ep =
+            resolve_endpoint(&home, Path::new("/work"), Tool::ClaudeCode, &read).expect("resolve");
+        assert_eq!(ep.base_url, "http://gw");
+        assert_eq!(ep.model.as_deref(), Some("p"));
+        assert_eq!(ep.api_key.as_deref(), Some("sk-local"));
+        assert_eq!(ep.auth_header, AuthHeader::XApiKey);
+    }
+
+    #[test]
+    fn probe_classifies_status_and_transport_errors() {
+        let ep = Endpoint {
+            flavor: ApiFlavor::Anthropic,
+            base_url: "http://gw".to_string(),
+            api_key: Some("tok".to_string()),
+            auth_header: AuthHeader::Bearer,
+            model: None,
+        };
+        let client = stub(Ok(HttpResponse {
+            status: 401,
+            body: "{\"error\":{\"message\":\"invalid x-api-key\"}}".to_string(),
+        }));
+        let report = probe_endpoint(&client, &ep);
+        assert_eq!(report.outcome, ProbeOutcome::Unauthorized);
+        assert_eq!(report.status, Some(401));
+        assert_eq!(report.detail.as_deref(), Some("invalid x-api-key"));
+        let seen = &client.seen.borrow()[0];
+        assert_eq!(seen.url, "http://gw/v1/messages");
+        assert!(seen
+            .headers
+            .contains(&("Authorization".to_string(), "Bearer tok".to_string())));
+        assert!(!seen.headers.iter().any(|(k, _)| k == "x-api-key"));
+
+        let api_key_ep = Endpoint {
+            auth_header: AuthHeader::XApiKey,
+            ..ep.clone()
+        };
+        let request = test_request(&api_key_ep).expect("request");
+        assert!(request
+            .headers
+            .contains(&("x-api-key".to_string(), "tok".to_string())));
+        assert!(!request.headers.iter().any(|(k, _)| k == "Authorization"));
+
+        let report = probe_endpoint(&stub(Err(TransportError::Dns)), &ep);
+        assert_eq!(report.outcome, ProbeOutcome::Dns);
+
+        let missing = Endpoint {
+            api_key: None,
+            ..ep
+        };
+        let client = stub(Err(TransportError::Timeout));
+        assert_eq!(
+            probe_endpoint(&client, &missing).outcome,
+            ProbeOutcome::MissingCredentials
+        );
+        assert!(client.seen.borrow().is_empty());
+    }
+
+    #[test]
+    fn lists_models_from_openai_and_ollama_shapes() {
+        let ep = Endpoint {
+            flavor: ApiFlavor::OpenAi,
+            base_url: "http://localhost:11434/v1".to_string(),
+            api_key: None,
+            auth_header: AuthHeader::Bearer,
+            model: None,
+        };
+        let client = stub(Ok(HttpResponse {
+            status: 200,
+            body: "{\"models\":[{\"name\":\"qwen3:8b\"},{\"name\":\"llama3\"}]}".to_string(),
+        }));
+        assert_eq!(
+            list_models(&client, &ep).expect("models"),
+            vec!["llama3".to_string(), "qwen3:8b".to_string()]
+        );
+        assert_eq!(
+            client.seen.borrow()[0].url,
+            "http://localhost:11434/api/tags"
+        );
+
+        assert_eq!(
+            parse_model_ids("{\"data\":[{\"id\":\"gpt-5\"},{\"id\":\"gpt-4.1\"}]}"),
+            vec!["gpt-4.1".to_string(), "gpt-5".to_string()]
+        );
+        assert_eq!(
+            parse_model_ids("{\"models\":[{\"name\":\"models/gemini-2.5-pro\"}]}"),
+            vec!["gemini-2.5-pro".to_string()]
+        );
+
+        let unreachable = stub(Err(TransportError::ConnectionRefused));
+        let err = list_models(&unreachable, &ep).expect_err("refused");
+        assert_eq!(err.outcome, ProbeOutcome::ConnectionRefused);
+    }
+}
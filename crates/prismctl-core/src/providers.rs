@@ -1,86 +1,288 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-/// Supported provider presets for Codex configuration.
-pub enum Provider {
-    OpenRouter,
-    DeepSeek,
-    Ollama,
-    Volcengine,
-    SiliconFlow,
+use crate::paths::PrismctlHome;
+use crate::toml_text;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of user/project provider preset files (under `.prismctl/`).
+pub const PROVIDERS_FILE: &str = "providers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// An OpenAI-compatible provider preset used by Codex/OpenCode configuration.
+pub struct ProviderPreset {
+    pub id: String,
+    pub name: String,
+    pub base_url: String,
+    pub wire_api: String,
+    pub default_model: String,
+    /// Environment variable Codex reads the API key from (`env_key` in `[model_providers.*]`).
+    /// `None` keeps the key in `auth.json` under Prismctl's per-provider name.
+    pub env_key: Option<String>,
 }
 
-impl Provider {
-    pub fn id(&self) -> &'static str {
-        match self {
-            Provider::OpenRouter => "openrouter",
-            Provider::DeepSeek => "deepseek",
-            Provider::Ollama => "ollama",
-            Provider::Volcengine => "volcengine",
-            Provider::SiliconFlow => "siliconflow",
-        }
+fn builtin(id: &str, name: &str, base_url: &str, default_model: &str) -> ProviderPreset {
+    ProviderPreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        base_url: base_url.to_string(),
+        wire_api: "chat".to_string(),
+        default_model: default_model.to_string(),
+        env_key: None,
     }
 }
 
-pub fn parse_provider_id(id: &str) -> Result<Provider, String> {
-    match id.trim().to_ascii_lowercase().as_str() {
-        "openrouter" => Ok(Provider::OpenRouter),
-        "deepseek" => Ok(Provider::DeepSeek),
-        "ollama" => Ok(Provider::Ollama),
-        "volcengine" => Ok(Provider::Volcengine),
-        "siliconflow" => Ok(Provider::SiliconFlow),
-        _ => Err(format!(
-            "未知 provider: {}（可用: {}）",
-            id,
-            list_provider_ids().join(", ")
-        )),
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Built-in presets merged with user (`~/.prismctl/providers.toml`) and project
+/// (`<project>/.prismctl/providers.toml`) presets. Later sources override earlier ones by id.
+pub struct ProviderCatalog {
+    presets: Vec<ProviderPreset>,
+}
+
+impl ProviderCatalog {
+    /// Only the presets compiled into Prismctl.
+    pub fn builtin() -> Self {
+        Self {
+            presets: vec![
+                builtin(
+                    "openrouter",
+                    "OpenRouter",
+                    "https://openrouter.ai/api/v1",
+                    "google/gemini-2.5-pro-preview",
+                ),
+                builtin(
+                    "deepseek",
+                    "DeepSeek",
+                    "https://api.deepseek.com/v1",
+                    "deepseek-chat",
+                ),
+                builtin(
+                    "ollama",
+                    "Ollama",
+                    "http://localhost:11434/v1",
+                    "qwen2.5-coder:latest",
+                ),
+                builtin(
+                    "volcengine",
+                    "Volcengine Ark",
+                    "https://ark.cn-beijing.volces.com/api/v3",
+                    "deepseek-v3-250324",
+                ),
+                builtin(
+                    "siliconflow",
+                    "SiliconFlow",
+                    "https://api.siliconflow.cn/v1",
+                    "moonshotai/Kimi-K2-Instruct",
+                ),
+            ],
+        }
+    }
+
+    /// Built-ins plus user and (optionally) project preset files; missing files are skipped.
+    pub fn load(home: &PrismctlHome, project_root: Option<&Path>) -> Result<Self, String> {
+        let mut catalog = Self::builtin();
+        let mut files = vec![user_providers_path(home)];
+        if let Some(root) = project_root {
+            files.push(root.join(".prismctl").join(PROVIDERS_FILE));
+        }
+        for path in files {
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            catalog
+                .merge_toml(&content)
+                .map_err(|e| format!("{}: {e}", path.display()))?;
+        }
+        Ok(catalog)
+    }
+
+    /// Merge `[providers.<id>]` tables; `base_url` is required for new ids.
+    pub fn merge_toml(&mut self, content: &str) -> Result<(), String> {
+        for (header, kv) in toml_text::read_string_tables(content)? {
+            let Some(id) = header.strip_prefix("providers.") else {
+                continue;
+            };
+            let id = id.trim().to_ascii_lowercase();
+            let get = |key: &str| kv.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+
+            let base = self.presets.iter().find(|p| p.id == id).cloned();
+            let base_url = get("base_url")
+                .or_else(|| base.as_ref().map(|p| p.base_url.clone()))
+                .ok_or_else(|| format!("provider {id} 缺少 base_url"))?;
+            let preset = ProviderPreset {
+                name: get("name")
+                    .or_else(|| base.as_ref().map(|p| p.name.clone()))
+                    .unwrap_or_else(|| id.clone()),
+                base_url,
+                wire_api: get("wire_api")
+                    .or_else(|| base.as_ref().map(|p| p.wire_api.clone()))
+                    .unwrap_or_else(|| "chat".to_string()),
+                default_model: get("default_model")
+                    .or_else(|| base.as_ref().map(|p| p.default_model.clone()))
+                    .unwrap_or_default(),
+                env_key: get("env_key").or_else(|| base.as_ref().and_then(|p| p.env_key.clone())),
+                id,
+            };
+
+            match self.presets.iter_mut().find(|p| p.id == preset.id) {
+                Some(slot) => *slot = preset,
+                None => self.presets.push(preset),
+            }
+        }
+        Ok(())
+    }
+
+    /// All presets in display order (built-ins first).
+    pub fn presets(&self) -> &[ProviderPreset] {
+        &self.presets
+    }
+
+    /// List all provider IDs.
+    pub fn list_provider_ids(&self) -> Vec<&str> {
+        self.presets.iter().map(|p| p.id.as_str()).collect()
+    }
+
+    /// Look up a preset by id (case-insensitive).
+    pub fn parse_provider_id(&self, id: &str) -> Result<&ProviderPreset, String> {
+        let want = id.trim().to_ascii_lowercase();
+        self.presets.iter().find(|p| p.id == want).ok_or_else(|| {
+            format!(
+                "未知 provider: {}（可用: {}）",
+                id,
+                self.list_provider_ids().join(", ")
+            )
+        })
     }
 }
 
-/// List all supported provider IDs.
-pub fn list_provider_ids() -> Vec<&'static str> {
-    vec![
-        "openrouter",
-        "deepseek",
-        "ollama",
-        "volcengine",
-        "siliconflow",
-    ]
+/// Path of the user-level provider presets file (`~/.prismctl/providers.toml`).
+pub fn user_providers_path(home: &PrismctlHome) -> PathBuf {
+    home.home_dir().join(".prismctl").join(PROVIDERS_FILE)
 }
 
-pub struct CodexProviderPreset {
+/// OpenAI-compatible preset for Qwen Code (`OPENAI_BASE_URL` / `OPENAI_MODEL`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QwenProviderPreset {
+    pub id: &'static str,
     pub base_url: &'static str,
-    pub wire_api: &'static str,
     pub default_model: &'static str,
 }
 
-/// Return the Codex preset values for a provider.
-pub fn codex_preset(provider: Provider) -> CodexProviderPreset {
-    match provider {
-        Provider::OpenRouter => CodexProviderPreset {
-            base_url: "https://openrouter.ai/api/v1",
-            wire_api: "chat",
-            default_model: "google/gemini-2.5-pro-preview",
-        },
-        Provider::DeepSeek => CodexProviderPreset {
-            base_url: "https://api.deepseek.com/v1",
-            wire_api: "chat",
-            default_model: "deepseek-chat",
-        },
-        Provider::Ollama => CodexProviderPreset {
-            base_url: "http://localhost:11434/v1",
-            wire_api: "chat",
-            default_model: "qwen2.5-coder:latest",
-        },
-        Provider::Volcengine => CodexProviderPreset {
-            base_url: "https://ark.cn-beijing.volces.com/api/v3",
-            wire_api: "chat",
-            default_model: "deepseek-v3-250324",
-        },
-        Provider::SiliconFlow => CodexProviderPreset {
-            base_url: "https://api.siliconflow.cn/v1",
-            wire_api: "chat",
-            default_model: "moonshotai/Kimi-K2-Instruct",
-        },
-    }
+static QWEN_PRESETS: &[QwenProviderPreset] = &[
+    QwenProviderPreset {
+        id: "dashscope",
+        base_url: "https://dashscope.aliyuncs.com/compatible-mode/v1",
+        default_model: "qwen3-coder-plus",
+    },
+    QwenProviderPreset {
+        id: "siliconflow",
+        base_url: "https://api.siliconflow.cn/v1",
+        default_model: "Qwen/Qwen3-Coder-480B-A35B-Instruct",
+    },
+];
+
+/// List Qwen Code provider preset IDs.
+pub fn list_qwen_provider_ids() -> Vec<&'static str> {
+    QWEN_PRESETS.iter().map(|p| p.id).collect()
+}
+
+/// Look up a Qwen Code preset by id (case-insensitive).
+pub fn qwen_preset(id: &str) -> Result<QwenProviderPreset, String> {
+    let id = id.trim().to_ascii_lowercase();
+    QWEN_PRESETS
+        .iter()
+        .copied()
+        .find(|p| p.id == id)
+        .ok_or_else(|| {
+            format!(
+                "未知 provider: {}（可用: {}）",
+                id,
+                list_qwen_provider_ids().join(", ")
+            )
+        })
+}
+
+/// Anthropic-compatible preset for Claude Code (`ANTHROPIC_BASE_URL` plus model mappings).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaudeProviderPreset {
+    pub id: &'static str,
+    pub aliases: &'static [&'static str],
+    pub base_url: &'static str,
+    /// Default opus/sonnet/haiku models; `None` leaves model selection to the endpoint.
+    pub opus_model: Option<&'static str>,
+    pub sonnet_model: Option<&'static str>,
+    pub haiku_model: Option<&'static str>,
+}
+
+static CLAUDE_PRESETS: &[ClaudeProviderPreset] = &[
+    ClaudeProviderPreset {
+        id: "deepseek",
+        aliases: &[],
+        base_url: "https://api.deepseek.com/anthropic",
+        opus_model: Some("deepseek-reasoner"),
+        sonnet_model: Some("deepseek-chat"),
+        haiku_model: Some("deepseek-chat"),
+    },
+    ClaudeProviderPreset {
+        id: "moonshot",
+        aliases: &["kimi"],
+        base_url: "https://api.moonshot.cn/anthropic",
+        opus_model: Some("kimi-k2-0905-preview"),
+        sonnet_model: Some("kimi-k2-0905-preview"),
+        haiku_model: Some("kimi-k2-turbo-preview"),
+    },
+    ClaudeProviderPreset {
+        id: "zhipu",
+        aliases: &["glm"],
+        base_url: "https://open.bigmodel.cn/api/anthropic",
+        opus_model: Some("glm-4.6"),
+        sonnet_model: Some("glm-4.6"),
+        haiku_model: Some("glm-4.5-air"),
+    },
+    ClaudeProviderPreset {
+        id: "siliconflow",
+        aliases: &[],
+        base_url: "https://api.siliconflow.cn/",
+        opus_model: Some("moonshotai/Kimi-K2-Instruct-0905"),
+        sonnet_model: Some("moonshotai/Kimi-K2-Instruct-0905"),
+        haiku_model: Some("Qwen/Qwen3-Coder-30B-A3B-Instruct"),
+    },
+    ClaudeProviderPreset {
+        id: "openrouter",
+        aliases: &[],
+        base_url: "https://openrouter.ai/api",
+        opus_model: Some("anthropic/claude-opus-4.1"),
+        sonnet_model: Some("anthropic/claude-sonnet-4.5"),
+        haiku_model: Some("anthropic/claude-3.5-haiku"),
+    },
+    ClaudeProviderPreset {
+        // Local Anthropic-compatible proxy (e.g. claude-code-router); the proxy routes models.
+        id: "local",
+        aliases: &[],
+        base_url: "http://127.0.0.1:3456",
+        opus_model: None,
+        sonnet_model: None,
+        haiku_model: None,
+    },
+];
+
+/// List Claude Code provider preset IDs.
+pub fn list_claude_provider_ids() -> Vec<&'static str> {
+    CLAUDE_PRESETS.iter().map(|p| p.id).collect()
+}
+
+/// Look up a Claude Code preset by id or alias (case-insensitive).
+pub fn claude_preset(id: &str) -> Result<ClaudeProviderPreset, String> {
+    let id = id.trim().to_ascii_lowercase();
+    CLAUDE_PRESETS
+        .iter()
+        .copied()
+        .find(|p| p.id == id || p.aliases.contains(&id.as_str()))
+        .ok_or_else(|| {
+            format!(
+                "未知 provider: {}（可用: {}）",
+                id,
+                list_claude_provider_ids().join(", ")
+            )
+        })
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -92,21 +294,23 @@ pub struct ResolvedCodexProvider {
 
 /// Resolve a Codex provider config using an optional preset plus optional explicit overrides.
 pub fn resolve_codex_provider(
-    provider: Option<Provider>,
+    preset: Option<&ProviderPreset>,
     base_url: Option<String>,
     wire_api: Option<String>,
     model: Option<String>,
 ) -> ResolvedCodexProvider {
-    let preset = provider.map(codex_preset);
-
     let base_url = base_url
-        .or_else(|| preset.as_ref().map(|p| p.base_url.to_string()))
+        .or_else(|| preset.map(|p| p.base_url.clone()))
         .unwrap_or_else(|| "https://api.openai.com/v1".to_string());
     let wire_api = wire_api
-        .or_else(|| preset.as_ref().map(|p| p.wire_api.to_string()))
+        .or_else(|| preset.map(|p| p.wire_api.clone()))
         .unwrap_or_else(|| "openai".to_string());
     let model = model
-        .or_else(|| preset.as_ref().map(|p| p.default_model.to_string()))
+        .or_else(|| {
+            preset
+                .map(|p| p.default_model.clone())
+                .filter(|m| !m.is_empty())
+        })
         .unwrap_or_else(|| "gpt-5".to_string());
 
     ResolvedCodexProvider {
@@ -122,18 +326,46 @@ mod tests {
 
     #[test]
     fn parse_provider_id_accepts_known_ids() {
+        let catalog = ProviderCatalog::builtin();
+        assert_eq!(
+            catalog.parse_provider_id("openrouter").unwrap().id,
+            "openrouter"
+        );
         assert_eq!(
-            parse_provider_id("openrouter").unwrap(),
-            Provider::OpenRouter
+            catalog.parse_provider_id("DeepSeek").unwrap().id,
+            "deepseek"
         );
-        assert_eq!(parse_provider_id("DeepSeek").unwrap(), Provider::DeepSeek);
-        assert_eq!(parse_provider_id("OLLAMA").unwrap(), Provider::Ollama);
+        assert_eq!(catalog.parse_provider_id("OLLAMA").unwrap().id, "ollama");
+    }
+
+    #[test]
+    fn merge_toml_adds_and_overrides_presets() {
+        let mut catalog = ProviderCatalog::builtin();
+        catalog
+            .merge_toml(
+                "[providers.corp]\nname = \"Corp Gateway\"\nbase_url = \"https://llm.corp/v1\"\nwire_api = \"responses\"\ndefault_model = \"gpt-5\"\nenv_key = \"CORP_API_KEY\"\n\n[providers.deepseek]\ndefault_model = \"deepseek-reasoner\"\n",
+            )
+            .expect("merge");
+
+        assert_eq!(catalog.list_provider_ids().last(), Some(&"corp"));
+        let corp = catalog.parse_provider_id("corp").unwrap();
+        assert_eq!(corp.wire_api, "responses");
+        assert_eq!(corp.env_key.as_deref(), Some("CORP_API_KEY"));
+
+        let deepseek = catalog.parse_provider_id("deepseek").unwrap();
+        assert_eq!(deepseek.base_url, "https://api.deepseek.com/v1");
+        assert_eq!(deepseek.default_model, "deepseek-reasoner");
+
+        assert!(catalog
+            .merge_toml("[providers.nourl]\nname = \"x\"\n")
+            .is_err());
     }
 
     #[test]
     fn resolve_codex_provider_prefers_explicit_values_over_preset() {
+        let catalog = ProviderCatalog::builtin();
         let resolved = resolve_codex_provider(
-            Some(Provider::OpenRouter),
+            catalog.parse_provider_id("openrouter").ok(),
             Some("https://example.com/v1".into()),
             Some("openai".into()),
             Some("gpt-5".into()),
@@ -145,9 +377,29 @@ mod tests {
 
     #[test]
     fn resolve_codex_provider_uses_preset_when_fields_missing() {
-        let resolved = resolve_codex_provider(Some(Provider::DeepSeek), None, None, None);
+        let catalog = ProviderCatalog::builtin();
+        let resolved =
+            resolve_codex_provider(catalog.parse_provider_id("deepseek").ok(), None, None, None);
         assert_eq!(resolved.base_url, "https://api.deepseek.com/v1");
         assert_eq!(resolved.wire_api, "chat");
         assert_eq!(resolved.model, "deepseek-chat");
     }
+
+    #[test]
+    fn claude_preset_lookup_accepts_aliases() {
+        let kimi = claude_preset("Kimi").unwrap();
+        assert_eq!(kimi.id, "moonshot");
+        assert_eq!(kimi.base_url, "https://api.moonshot.cn/anthropic");
+        assert_eq!(claude_preset("local").unwrap().sonnet_model, None);
+        assert!(claude_preset("ollama").is_err());
+    }
+
+    #[test]
+    fn qwen_preset_lookup() {
+        assert_eq!(
+            qwen_preset("DashScope").unwrap().default_model,
+            "qwen3-coder-plus"
+        );
+        assert!(qwen_preset("openrouter").is_err());
+    }
 }
@@ -0,0 +1,62 @@
+use crate::env_file;
+use crate::providers::QwenProviderPreset;
+
+/// Keys Prismctl owns inside Qwen Code's `.env` managed block.
+pub const QWEN_ENV_KEYS: &[&str] = &["OPENAI_API_KEY", "OPENAI_BASE_URL", "OPENAI_MODEL"];
+
+#[derive(Debug, Clone, Default)]
+/// Values to write into Qwen Code's `.env`; `None` keeps the current value.
+pub struct QwenEnvPatch {
+    pub api_key: Option<String>,
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+}
+
+impl QwenEnvPatch {
+    /// Fill `base_url`/`model` from a preset unless given explicitly.
+    pub fn with_preset(mut self, preset: QwenProviderPreset) -> Self {
+        self.base_url
+            .get_or_insert_with(|| preset.base_url.to_string());
+        self.model
+            .get_or_insert_with(|| preset.default_model.to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.api_key.is_none() && self.base_url.is_none() && self.model.is_none()
+    }
+}
+
+/// Upsert the Prismctl-managed block of a Qwen Code `.env` file.
+pub fn upsert_qwen_env(existing: &str, patch: &QwenEnvPatch) -> String {
+    let mut set: Vec<(&str, String)> = Vec::new();
+    if let Some(v) = &patch.api_key {
+        set.push(("OPENAI_API_KEY", v.trim().to_string()));
+    }
+    if let Some(v) = &patch.base_url {
+        set.push(("OPENAI_BASE_URL", v.trim().to_string()));
+    }
+    if let Some(v) = &patch.model {
+        set.push(("OPENAI_MODEL", v.trim().to_string()));
+    }
+    env_file::upsert_managed_env(existing, QWEN_ENV_KEYS, &set, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::qwen_preset;
+
+    #[test]
+    fn preset_fills_missing_values_only() {
+        let patch = QwenEnvPatch {
+            model: Some("qwen-max".to_string()),
+            ..Default::default()
+        }
+        .with_preset(qwen_preset("dashscope").unwrap());
+        let out = upsert_qwen_env("", &patch);
+        assert!(out.contains("OPENAI_BASE_URL=https://dashscope.aliyuncs.com/compatible-mode/v1\n"));
+        assert!(out.contains("OPENAI_MODEL=qwen-max\n"));
+        assert!(!out.contains("OPENAI_API_KEY"));
+    }
+}
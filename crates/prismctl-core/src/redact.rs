@@ -0,0 +1,21 @@
+/// Mask a secret for display, keeping only the last 4 characters (`****` for short values).
+pub fn mask_secret(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_only_the_tail() {
+        assert_eq!(mask_secret("sk-work-123456789"), "****6789");
+        assert_eq!(mask_secret("short"), "****");
+        assert_eq!(mask_secret("密钥密钥密钥密钥密钥"), "****密钥密钥");
+    }
+}
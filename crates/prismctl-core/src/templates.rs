@@ -81,6 +81,47 @@ pub fn codex_agent_template(name: &str, lang: TemplateLang) -> Option<&'static s
     }
 }
 
+/// Names of built-in Claude Code subagent templates.
+pub fn claude_agent_names() -> &'static [&'static str] {
+    &[
+        "planner",
+        "ui-ux-designer",
+        "init-architect",
+        "get-current-datetime",
+    ]
+}
+
+/// Return the embedded subagent template content for a given name and language.
+pub fn claude_agent_template(name: &str, lang: TemplateLang) -> Option<&'static str> {
+    match (name, lang) {
+        ("planner", TemplateLang::ZhCn) => Some(include_str!(
+            "../assets/claude/agents/prismctl/zh-CN/plan/planner.md"
+        )),
+        ("ui-ux-designer", TemplateLang::ZhCn) => Some(include_str!(
+            "../assets/claude/agents/prismctl/zh-CN/plan/ui-ux-designer.md"
+        )),
+        ("init-architect", TemplateLang::ZhCn) => Some(include_str!(
+            "../assets/claude/agents/prismctl/zh-CN/common/init-architect.md"
+        )),
+        ("get-current-datetime", TemplateLang::ZhCn) => Some(include_str!(
+            "../assets/claude/agents/prismctl/zh-CN/common/get-current-datetime.md"
+        )),
+        ("planner", TemplateLang::En) => Some(include_str!(
+            "../assets/claude/agents/prismctl/en/plan/planner.md"
+        )),
+        ("ui-ux-designer", TemplateLang::En) => Some(include_str!(
+            "../assets/claude/agents/prismctl/en/plan/ui-ux-designer.md"
+        )),
+        ("init-architect", TemplateLang::En) => Some(include_str!(
+            "../assets/claude/agents/prismctl/en/common/init-architect.md"
+        )),
+        ("get-current-datetime", TemplateLang::En) => Some(include_str!(
+            "../assets/claude/agents/prismctl/en/common/get-current-datetime.md"
+        )),
+        _ => None,
+    }
+}
+
 /// Plan changes to write Claude Code templates into the resolved HOME.
 pub fn plan_templates_claude(home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
     let root = home.tool_root(Tool::ClaudeCode);
@@ -115,6 +156,153 @@ pub fn plan_templates_gemini_with_existing(
     cs
 }
 
+/// Plan changes to write the Qwen Code context file (`~/.qwen/QWEN.md`) via a managed block.
+pub fn plan_templates_qwen_with_existing(
+    home: &PrismctlHome,
+    existing_qwen_md: &str,
+    lang: TemplateLang,
+) -> ChangeSet {
+    let root = home.tool_root(Tool::QwenCode);
+    let mut cs = ChangeSet::new();
+    cs.push(Change::CreateDirAll { path: root.clone() });
+    cs.push(Change::WriteFile {
+        path: root.join("QWEN.md"),
+        bytes: render_qwen_context(existing_qwen_md, lang).into_bytes(),
+        overwrite: true,
+    });
+    cs
+}
+
+/// Plan changes to write OpenCode commands/agents into the resolved HOME.
+///
+/// Reuses the Claude command/agent templates, converting frontmatter to OpenCode's format.
+pub fn plan_templates_opencode(home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+    let root = home.tool_root(Tool::OpenCode);
+    let command_dir = root.join("command").join("prismctl");
+    let agent_dir = root.join("agent").join("prismctl");
+
+    let mut cs = ChangeSet::new();
+    let mut created_dirs: HashSet<PathBuf> = HashSet::new();
+    // Relative root: we only need the layout of the Claude templates, not their location.
+    for f in claude_files(PathBuf::new(), lang) {
+        let (dir, is_agent) = if f.path.starts_with("commands") {
+            (&command_dir, false)
+        } else if f.path.starts_with("agents") {
+            (&agent_dir, true)
+        } else {
+            continue;
+        };
+        let Some(name) = f.path.file_name() else {
+            continue;
+        };
+        if created_dirs.insert(dir.clone()) {
+            cs.push(Change::CreateDirAll { path: dir.clone() });
+        }
+        cs.push(Change::WriteFile {
+            path: dir.join(name),
+            bytes: to_opencode_markdown(f.contents, is_agent).into_bytes(),
+            overwrite: f.overwrite,
+        });
+    }
+    cs
+}
+
+/// Keep only `description` from Claude frontmatter; agents become OpenCode subagents.
+fn to_opencode_markdown(contents: &str, is_agent: bool) -> String {
+    let (Some(frontmatter), body) = split_frontmatter(contents) else {
+        return contents.to_string();
+    };
+
+    let mut out = String::from("---\n");
+    for line in frontmatter.lines() {
+        if line.starts_with("description:") {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    if is_agent {
+        out.push_str("mode: subagent\n");
+    }
+    out.push_str("---\n\n");
+    out.push_str(body);
+    out
+}
+
+/// Split a Markdown document into its `---` frontmatter (without fences) and body.
+pub fn split_frontmatter(contents: &str) -> (Option<&str>, &str) {
+    let Some(rest) = contents.strip_prefix("---\n") else {
+        return (None, contents);
+    };
+    let Some(end) = rest.find("\n---") else {
+        return (None, contents);
+    };
+    let (frontmatter, body) = rest.split_at(end);
+    let body = body.trim_start_matches("\n---").trim_start_matches('\n');
+    (Some(frontmatter), body)
+}
+
+/// Read a top-level scalar from frontmatter (surrounding quotes removed).
+pub fn frontmatter_value(frontmatter: &str, key: &str) -> Option<String> {
+    frontmatter.lines().find_map(|line| {
+        let v = line.strip_prefix(key)?.strip_prefix(':')?.trim();
+        let v = v.trim_matches('\'').trim_matches('"');
+        (!v.is_empty()).then(|| v.to_string())
+    })
+}
+
+/// Render `value` as a single-line YAML scalar, double-quoting it when a plain scalar would be
+/// misread (e.g. `Fix: x`, `[file]`, `true`).
+pub fn yaml_scalar(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value.trim() == value
+        && !value.starts_with(|c: char| "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        && !value.ends_with(':')
+        && !value.contains(": ")
+        && !value.contains(" #")
+        && !value.chars().any(char::is_control)
+        && !matches!(
+            value.to_ascii_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~"
+        )
+        && value.parse::<f64>().is_err();
+    if plain {
+        return value.to_string();
+    }
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Workflow and git command templates as `(name, contents)`, shared by project-level targets.
+pub fn workflow_templates(lang: TemplateLang) -> Vec<(&'static str, &'static str)> {
+    const NAMES: &[&str] = &[
+        "workflow",
+        "git-commit",
+        "git-worktree",
+        "git-rollback",
+        "git-cleanBranches",
+    ];
+    // Relative root: only the file names matter here.
+    codex_files(PathBuf::new(), lang)
+        .into_iter()
+        .filter_map(|f| {
+            let stem = f.path.file_stem()?.to_str()?;
+            let name = NAMES.iter().copied().find(|n| *n == stem)?;
+            Some((name, f.contents))
+        })
+        .collect()
+}
+
 fn plan_files(files: Vec<TemplateFile>) -> ChangeSet {
     let mut cs = ChangeSet::new();
     let mut created_dirs: HashSet<PathBuf> = HashSet::new();
@@ -231,10 +419,6 @@ fn claude_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
         style_nekomata,
         style_ojousama,
         style_rem,
-        agent_get_current_datetime,
-        agent_init_architect,
-        agent_planner,
-        agent_uiux,
     ) = match lang {
         TemplateLang::ZhCn => (
             include_str!("../assets/claude/commands/prismctl/zh-CN/workflow.md"),
@@ -251,10 +435,6 @@ fn claude_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
             include_str!("../assets/claude/output-styles/zh-CN/prismctl-nekomata-engineer.md"),
             include_str!("../assets/claude/output-styles/zh-CN/prismctl-ojousama-engineer.md"),
             include_str!("../assets/claude/output-styles/zh-CN/prismctl-rem-engineer.md"),
-            include_str!("../assets/claude/agents/prismctl/zh-CN/common/get-current-datetime.md"),
-            include_str!("../assets/claude/agents/prismctl/zh-CN/common/init-architect.md"),
-            include_str!("../assets/claude/agents/prismctl/zh-CN/plan/planner.md"),
-            include_str!("../assets/claude/agents/prismctl/zh-CN/plan/ui-ux-designer.md"),
         ),
         TemplateLang::En => (
             include_str!("../assets/claude/commands/prismctl/en/workflow.md"),
@@ -271,14 +451,10 @@ fn claude_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
             include_str!("../assets/claude/output-styles/en/prismctl-nekomata-engineer.md"),
             include_str!("../assets/claude/output-styles/en/prismctl-ojousama-engineer.md"),
             include_str!("../assets/claude/output-styles/en/prismctl-rem-engineer.md"),
-            include_str!("../assets/claude/agents/prismctl/en/common/get-current-datetime.md"),
-            include_str!("../assets/claude/agents/prismctl/en/common/init-architect.md"),
-            include_str!("../assets/claude/agents/prismctl/en/plan/planner.md"),
-            include_str!("../assets/claude/agents/prismctl/en/plan/ui-ux-designer.md"),
         ),
     };
 
-    vec![
+    let mut files = vec![
         TemplateFile {
             path: commands.join("workflow.md"),
             contents: workflow,
@@ -349,27 +525,22 @@ fn claude_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
             contents: style_rem,
             overwrite: true,
         },
-        TemplateFile {
-            path: agents.join("common").join("get-current-datetime.md"),
-            contents: agent_get_current_datetime,
-            overwrite: true,
-        },
-        TemplateFile {
-            path: agents.join("common").join("init-architect.md"),
-            contents: agent_init_architect,
-            overwrite: true,
-        },
-        TemplateFile {
-            path: agents.join("plan").join("planner.md"),
-            contents: agent_planner,
-            overwrite: true,
-        },
-        TemplateFile {
-            path: agents.join("plan").join("ui-ux-designer.md"),
-            contents: agent_uiux,
-            overwrite: true,
-        },
-    ]
+    ];
+    for (group, name) in [
+        ("common", "get-current-datetime"),
+        ("common", "init-architect"),
+        ("plan", "planner"),
+        ("plan", "ui-ux-designer"),
+    ] {
+        if let Some(contents) = claude_agent_template(name, lang) {
+            files.push(TemplateFile {
+                path: agents.join(group).join(format!("{name}.md")),
+                contents,
+                overwrite: true,
+            });
+        }
+    }
+    files
 }
 
 fn gemini_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
@@ -385,6 +556,19 @@ fn gemini_files(root: PathBuf, lang: TemplateLang) -> Vec<TemplateFile> {
     }]
 }
 
+pub fn render_qwen_context(existing: &str, lang: TemplateLang) -> String {
+    let block = match lang {
+        TemplateLang::ZhCn => include_str!("../assets/qwen/zh-CN/QWEN.md"),
+        TemplateLang::En => include_str!("../assets/qwen/en/QWEN.md"),
+    };
+    upsert_managed_block(
+        existing,
+        "<!-- prismctl:start -->",
+        "<!-- prismctl:end -->",
+        block,
+    )
+}
+
 pub fn render_gemini_context(existing: &str, lang: TemplateLang) -> String {
     let block = match lang {
         TemplateLang::ZhCn => include_str!("../assets/gemini/zh-CN/GEMINI.md"),
@@ -463,4 +647,42 @@ mod tests {
         // Best-effort cleanup.
         let _ = fs::remove_dir_all(&sandbox);
     }
+
+    #[test]
+    fn frontmatter_helpers_read_description() {
+        let (fm, body) = split_frontmatter("---\ndescription: 'A: b'\n---\n\nBody\n");
+        assert_eq!(
+            fm.and_then(|f| frontmatter_value(f, "description")),
+            Some("A: b".to_string())
+        );
+        assert_eq!(body, "Body\n");
+        assert_eq!(split_frontmatter("# No frontmatter").0, None);
+        assert_eq!(workflow_templates(TemplateLang::En).len(), 5);
+    }
+
+    #[test]
+    fn opencode_templates_convert_frontmatter() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-home"));
+        let cs = plan_templates_opencode(&home, TemplateLang::En);
+
+        let mut saw_agent = false;
+        for c in cs.iter() {
+            let Change::WriteFile { path, bytes, .. } = c else {
+                continue;
+            };
+            let text = String::from_utf8(bytes.clone()).expect("utf8");
+            if !text.starts_with("---") {
+                // Templates without frontmatter are copied as-is.
+                continue;
+            }
+            assert!(text.starts_with("---\ndescription:"), "{}", path.display());
+            assert!(!text.contains("allowed-tools:"));
+            if path.ends_with("agent/prismctl/planner.md") {
+                saw_agent = true;
+                assert!(text.contains("mode: subagent\n"));
+                assert!(!text.contains("color:"));
+            }
+        }
+        assert!(saw_agent);
+    }
 }
@@ -4,6 +4,8 @@ pub enum TomlScalar {
     Str(String),
     Bool(bool),
     Int(i64),
+    /// An array of strings, rendered inline (`["a", "b"]`).
+    StrArray(Vec<String>),
 }
 
 impl TomlScalar {
@@ -12,12 +14,20 @@ impl TomlScalar {
             TomlScalar::Str(s) => render_toml_string(s),
             TomlScalar::Bool(b) => b.to_string(),
             TomlScalar::Int(i) => i.to_string(),
+            TomlScalar::StrArray(items) => {
+                let items: Vec<String> = items.iter().map(|s| render_toml_string(s)).collect();
+                format!("[{}]", items.join(", "))
+            }
         }
     }
 }
 
 fn render_toml_string(value: &str) -> String {
-    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t");
     format!("\"{}\"", escaped)
 }
 
@@ -26,12 +36,54 @@ fn is_table_header(line: &str) -> bool {
     (t.starts_with('[') && t.ends_with(']')) || (t.starts_with("[[") && t.ends_with("]]"))
 }
 
+/// Number of lines after `lines[idx]` that belong to a multi-line array value started there.
+fn continuation_len(lines: &[String], idx: usize) -> usize {
+    let Some((_, v)) = lines[idx].split_once('=') else {
+        return 0;
+    };
+    let mut depth = bracket_depth(v);
+    let mut n = 0;
+    while depth > 0 && idx + n + 1 < lines.len() {
+        n += 1;
+        depth += bracket_depth(&lines[idx + n]);
+    }
+    n
+}
+
+/// Net `[`/`]` nesting of a value fragment, ignoring brackets inside strings and comments.
+fn bracket_depth(fragment: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for c in fragment.chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && q == '"' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '#' => break,
+                _ => {}
+            },
+        }
+    }
+    depth
+}
+
 pub fn upsert_root_key(content: &str, key: &str, value: TomlScalar) -> String {
     let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
     let mut replaced = false;
 
-    for line in lines.iter_mut() {
-        let trimmed = line.trim();
+    for i in 0..lines.len() {
+        let trimmed = lines[i].trim();
         if trimmed.is_empty() || trimmed.starts_with('#') {
             continue;
         }
@@ -43,7 +95,9 @@ pub fn upsert_root_key(content: &str, key: &str, value: TomlScalar) -> String {
             continue;
         };
         if k.trim() == key {
-            *line = format!("{} = {}", key, value.render());
+            let extra = continuation_len(&lines, i);
+            lines.drain(i + 1..=i + extra);
+            lines[i] = format!("{} = {}", key, value.render());
             replaced = true;
             break;
         }
@@ -90,23 +144,27 @@ pub fn upsert_table_kv(content: &str, header: &str, kv: &[(String, TomlScalar)])
         // Track which keys were found.
         let mut found = vec![false; kv.len()];
 
-        for line in lines[i + 1..end].iter_mut() {
-            let trimmed = line.trim();
+        let mut j = i + 1;
+        while j < end {
+            let trimmed = lines[j].trim();
             if trimmed.is_empty() || trimmed.starts_with('#') {
+                j += 1;
                 continue;
             }
             let Some((k, _v)) = trimmed.split_once('=') else {
+                j += 1;
                 continue;
             };
             let key = k.trim();
 
-            for (idx, (want_k, want_v)) in kv.iter().enumerate() {
-                if want_k == key {
-                    *line = format!("{} = {}", want_k, want_v.render());
-                    found[idx] = true;
-                    break;
-                }
+            if let Some(idx) = kv.iter().position(|(want_k, _)| want_k == key) {
+                let extra = continuation_len(&lines, j);
+                lines.drain(j + 1..=j + extra);
+                end -= extra;
+                lines[j] = format!("{} = {}", kv[idx].0, kv[idx].1.render());
+                found[idx] = true;
             }
+            j += 1;
         }
 
         // Append missing keys at end of table.
@@ -134,6 +192,272 @@ pub fn upsert_table_kv(content: &str, header: &str, kv: &[(String, TomlScalar)])
     out
 }
 
+/// Remove a root-level `key = ...` line. Returns `None` when the key is not set.
+pub fn remove_root_key(content: &str, key: &str) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let idx = lines
+        .iter()
+        .take_while(|l| !is_table_header(l.trim()))
+        .position(|l| {
+            let t = l.trim();
+            !t.starts_with('#') && t.split_once('=').is_some_and(|(k, _)| k.trim() == key)
+        })?;
+    lines.remove(idx);
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Some(out)
+}
+
+/// Headers of all `[table]` sections in file order (quotes removed, e.g. `model_providers.x`).
+pub fn table_headers(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| is_table_header(l) && !l.starts_with("[["))
+        .map(|l| {
+            l.trim_start_matches('[')
+                .trim_end_matches(']')
+                .trim()
+                .replace('"', "")
+        })
+        .collect()
+}
+
+/// Remove a table and its sub-tables (e.g. `mcp_servers.x` and `mcp_servers.x.env`).
+///
+/// Returns `None` when the table does not exist.
+pub fn remove_table(content: &str, header: &str) -> Option<String> {
+    let child_prefix = format!("{header}.");
+    let mut out: Vec<&str> = Vec::new();
+    let mut removing = false;
+    let mut found = false;
+    for raw in content.lines() {
+        let line = raw.trim();
+        if is_table_header(line) {
+            let name = line
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .trim()
+                .replace('"', "");
+            let was_removing = removing;
+            removing = name == header || name.starts_with(&child_prefix);
+            if was_removing && !removing && out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push("");
+            }
+            if removing {
+                found = true;
+                while out.last().is_some_and(|l| l.trim().is_empty()) {
+                    out.pop();
+                }
+                continue;
+            }
+        }
+        if !removing {
+            out.push(raw);
+        }
+    }
+    if !found {
+        return None;
+    }
+    while out.last().is_some_and(|l| l.trim().is_empty()) {
+        out.pop();
+    }
+    let mut next = out.join("\n");
+    next.push('\n');
+    Some(next)
+}
+
+/// A TOML table header with its string key/value pairs, in file order.
+pub type StringTable = (String, Vec<(String, String)>);
+
+/// Read `[table]` sections whose values are plain strings (e.g. `[providers.my-gateway]`).
+///
+/// Only what Prismctl's own config files need is supported: basic/literal strings, comments and
+/// quoted header segments. Returns `(header, [(key, value)])` in file order.
+pub fn read_string_tables(content: &str) -> Result<Vec<StringTable>, String> {
+    let mut tables: Vec<StringTable> = Vec::new();
+    for (idx, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if is_table_header(line) {
+            let header = line.trim_start_matches('[').trim_end_matches(']').trim();
+            tables.push((header.replace('"', ""), Vec::new()));
+            continue;
+        }
+
+        let Some((k, v)) = line.split_once('=') else {
+            return Err(format!("TOML 解析失败（第 {} 行）: {}", idx + 1, raw));
+        };
+        let value = parse_toml_string(v.trim())
+            .ok_or_else(|| format!("TOML 解析失败（第 {} 行）: 仅支持字符串值", idx + 1))?;
+        let Some((_, kv)) = tables.last_mut() else {
+            return Err(format!(
+                "TOML 解析失败（第 {} 行）: 键必须位于表内",
+                idx + 1
+            ));
+        };
+        kv.push((k.trim().trim_matches('"').to_string(), value));
+    }
+    Ok(tables)
+}
+
+/// String values of one table (e.g. `model_providers.prismctl`, or `""` for the root table);
+/// other value types are skipped.
+///
+/// Unlike [`read_string_tables`] this never fails, so it is safe on tool-owned files.
+pub fn read_table_strings(content: &str, header: &str) -> Vec<(String, String)> {
+    let header_line = format!("[{}]", header);
+    let mut inside = header.is_empty();
+    let mut out = Vec::new();
+    for raw in content.lines() {
+        let line = raw.trim();
+        if is_table_header(line) {
+            inside = line == header_line;
+            continue;
+        }
+        if !inside || line.starts_with('#') {
+            continue;
+        }
+        let Some((k, v)) = line.split_once('=') else {
+            continue;
+        };
+        if let Some(value) = parse_toml_string(v.trim()) {
+            out.push((k.trim().to_string(), value));
+        }
+    }
+    out
+}
+
+/// Raw value text of every key in one table (`""` for the root table), in file order.
+///
+/// Trailing comments are dropped. Multi-line string arrays are normalized onto one line
+/// (`["a", "b"]`); other multi-line values keep their raw lines.
+pub fn read_table_raw(content: &str, header: &str) -> Vec<(String, String)> {
+    let lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+    let header_line = format!("[{}]", header);
+    let mut inside = header.is_empty();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        if is_table_header(line) {
+            inside = line == header_line;
+            i += 1;
+            continue;
+        }
+        let extra = continuation_len(&lines, i);
+        if inside && !line.starts_with('#') {
+            if let Some((k, v)) = line.split_once('=') {
+                let mut value = strip_comment(v).trim().to_string();
+                if extra > 0 {
+                    let rest = &lines[i + 1..=i + extra];
+                    let joined = rest.iter().fold(value.clone(), |mut acc, l| {
+                        acc.push(' ');
+                        acc.push_str(strip_comment(l).trim());
+                        acc
+                    });
+                    value = match parse_toml_string_array(&joined) {
+                        Some(items) => TomlScalar::StrArray(items).render(),
+                        None => rest.iter().fold(v.trim().to_string(), |mut acc, l| {
+                            acc.push('\n');
+                            acc.push_str(l.trim());
+                            acc
+                        }),
+                    };
+                }
+                out.push((k.trim().to_string(), value));
+            }
+        }
+        i += extra + 1;
+    }
+    out
+}
+
+/// `fragment` up to a `#` comment (ignoring `#` inside strings).
+fn strip_comment(fragment: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in fragment.char_indices() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && q == '"' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '#' => return &fragment[..i],
+                _ => {}
+            },
+        }
+    }
+    fragment
+}
+
+/// Items of an array of strings such as `["a", 'b',]`; `None` for anything else.
+fn parse_toml_string_array(value: &str) -> Option<Vec<String>> {
+    let mut rest = value.trim().strip_prefix('[')?.trim_start();
+    let mut items = Vec::new();
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            return after.trim().is_empty().then_some(items);
+        }
+        let (item, after) = split_toml_string(rest)?;
+        items.push(item);
+        rest = after.trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after.trim_start();
+        } else if !rest.starts_with(']') {
+            return None;
+        }
+    }
+}
+
+fn parse_toml_string(value: &str) -> Option<String> {
+    split_toml_string(value).map(|(s, _)| s)
+}
+
+/// A leading basic or literal string and the text after its closing quote.
+fn split_toml_string(value: &str) -> Option<(String, &str)> {
+    if let Some(rest) = value.strip_prefix('\'') {
+        let end = rest.find('\'')?;
+        return Some((rest[..end].to_string(), &rest[end + 1..]));
+    }
+    let rest = value.strip_prefix('"')?;
+    let mut out = String::new();
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &rest[i + 1..])),
+            '\\' => match chars.next()?.1 {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                'r' => out.push('\r'),
+                'u' => out.push(parse_unicode_escape(&mut chars, 4)?),
+                'U' => out.push(parse_unicode_escape(&mut chars, 8)?),
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// The `XXXX`/`XXXXXXXX` part of a `\u`/`\U` escape.
+fn parse_unicode_escape(chars: &mut std::str::CharIndices<'_>, len: usize) -> Option<char> {
+    let hex: String = chars.take(len).map(|(_, c)| c).collect();
+    if hex.len() != len {
+        return None;
+    }
+    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +491,99 @@ mod tests {
         assert!(out.contains("base_url = \"b\""));
         assert!(!out.contains("base_url = \"a\""));
     }
+
+    #[test]
+    fn upsert_replaces_multi_line_arrays() {
+        let input = "[sandbox_workspace_write]\nwritable_roots = [\n  \"/a\",\n  \"/b]\",\n]\nnetwork_access = false\n";
+        let out = upsert_table_kv(
+            input,
+            "sandbox_workspace_write",
+            &[(
+                "writable_roots".into(),
+                TomlScalar::StrArray(vec!["/c".into()]),
+            )],
+        );
+        assert_eq!(
+            out,
+            "[sandbox_workspace_write]\nwritable_roots = [\"/c\"]\nnetwork_access = false\n"
+        );
+        assert_eq!(
+            read_table_raw(input, "sandbox_workspace_write"),
+            vec![
+                (
+                    "writable_roots".to_string(),
+                    "[\"/a\", \"/b]\"]".to_string()
+                ),
+                ("network_access".to_string(), "false".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_table_raw_normalizes_multi_line_arrays() {
+        let input = "[sandbox_workspace_write]\nwritable_roots = [ # extra roots\n  \"/a\",  # cache\n  '/b#c',\n]\nnetwork_access = true # allow\nmatrix = [\n  [1, 2],\n]\n";
+        assert_eq!(
+            read_table_raw(input, "sandbox_workspace_write"),
+            vec![
+                (
+                    "writable_roots".to_string(),
+                    "[\"/a\", \"/b#c\"]".to_string()
+                ),
+                ("network_access".to_string(), "true".to_string()),
+                ("matrix".to_string(), "[\n[1, 2],\n]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_root_key_ignores_table_keys() {
+        let input = "profile = \"a\"\nmodel = \"x\"\n[profiles.a]\nprofile = \"b\"\n";
+        let out = remove_root_key(input, "profile").expect("removed");
+        assert_eq!(out, "model = \"x\"\n[profiles.a]\nprofile = \"b\"\n");
+        assert!(remove_root_key(&out, "profile").is_none());
+    }
+
+    #[test]
+    fn remove_table_drops_sub_tables_and_keeps_others() {
+        let input = "model = \"x\"\n\n[mcp_servers.a]\ncommand = \"npx\"\n\n[mcp_servers.a.env]\nK = \"v\"\n\n[mcp_servers.ab]\ncommand = \"uvx\"\n";
+        let out = remove_table(input, "mcp_servers.a").expect("removed");
+        assert_eq!(
+            out,
+            "model = \"x\"\n\n[mcp_servers.ab]\ncommand = \"uvx\"\n"
+        );
+        assert_eq!(table_headers(&out), vec!["mcp_servers.ab".to_string()]);
+        assert!(remove_table(&out, "mcp_servers.a").is_none());
+    }
+
+    #[test]
+    fn read_table_strings_skips_other_tables_and_types() {
+        let input = "model = \"x\"\n[model_providers.prismctl]\nbase_url = \"https://a\"\nrequires_openai_auth = false\nargs = [\n  \"a\",\n]\n[other]\nbase_url = \"b\"\n";
+        assert_eq!(
+            read_table_strings(input, "model_providers.prismctl"),
+            vec![("base_url".to_string(), "https://a".to_string())]
+        );
+        assert_eq!(
+            read_table_strings(input, ""),
+            vec![("model".to_string(), "x".to_string())]
+        );
+    }
+
+    #[test]
+    fn read_string_tables_parses_quoted_headers_and_comments() {
+        let input = "# presets\n[providers.\"my-gw\"]\nname = \"My \\\"GW\\\"\" # note\nbase_url = 'https://gw/v1'\n";
+        let tables = read_string_tables(input).expect("parse");
+        assert_eq!(tables[0].0, "providers.my-gw");
+        assert_eq!(tables[0].1[0], ("name".into(), "My \"GW\"".into()));
+        assert_eq!(tables[0].1[1].1, "https://gw/v1");
+        assert!(read_string_tables("x = 1\n").is_err());
+    }
+
+    #[test]
+    fn strings_decode_and_encode_escapes() {
+        let tables = read_string_tables("[p]\nnote = \"a\\nb\\tc\\\\d\\u00e9\"\n").expect("parse");
+        assert_eq!(tables[0].1[0].1, "a\nb\tc\\dé");
+        let rendered = TomlScalar::Str("a\nb\tc".into()).render();
+        assert_eq!(rendered, "\"a\\nb\\tc\"");
+        assert_eq!(parse_toml_string(&rendered).as_deref(), Some("a\nb\tc"));
+    }
 }
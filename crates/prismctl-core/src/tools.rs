@@ -0,0 +1,387 @@
+use crate::changeset::ChangeSet;
+use crate::paths::{PrismctlHome, Tool};
+use crate::templates::{self, TemplateLang};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How a tool's MCP servers are managed.
+pub enum McpBackend {
+    /// Delegated to the tool's own `mcp add/remove` commands.
+    ToolCli,
+    /// Written directly into the tool's config file.
+    ConfigFile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A Homebrew package used to install a tool.
+pub struct BrewPackage {
+    pub name: &'static str,
+    pub cask: bool,
+}
+
+/// Describes everything Prismctl needs to know about one external tool.
+///
+/// Adding a tool means implementing this trait and listing it in [`registry`].
+pub trait ToolAdapter: Sync {
+    /// Stable id used by `--tool` (e.g. `codex`).
+    fn id(&self) -> &'static str;
+
+    /// Extra accepted `--tool` values (e.g. `claude-code`).
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Human-readable name.
+    fn display_name(&self) -> &'static str;
+
+    /// The [`Tool`] this adapter manages.
+    fn tool(&self) -> Tool;
+
+    /// Tool root directory under the resolved HOME.
+    fn root(&self, home: &PrismctlHome) -> PathBuf {
+        home.tool_root(self.tool())
+    }
+
+    /// User-scoped files checked by `doctor`, derived from [`ToolAdapter::root`].
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf>;
+
+    /// Project-scoped files checked by `doctor`, relative to the project root.
+    fn project_files(&self) -> &'static [&'static str];
+
+    /// npm package used by `install`/`upgrade`.
+    fn npm_package(&self) -> &'static str;
+
+    /// Homebrew package, if the tool is published there.
+    fn brew_package(&self) -> Option<BrewPackage>;
+
+    /// Plan built-in templates for `init`/`update`.
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet;
+
+    /// How MCP servers are configured for this tool; `None` when Prismctl does not manage them.
+    fn mcp_backend(&self) -> Option<McpBackend>;
+}
+
+struct CodexAdapter;
+
+impl ToolAdapter for CodexAdapter {
+    fn id(&self) -> &'static str {
+        "codex"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Codex"
+    }
+
+    fn tool(&self) -> Tool {
+        Tool::Codex
+    }
+
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf> {
+        let root = self.root(home);
+        vec![
+            root.join("config.toml"),
+            root.join("auth.json"),
+            root.join("AGENTS.md"),
+        ]
+    }
+
+    fn project_files(&self) -> &'static [&'static str] {
+        &["AGENTS.md"]
+    }
+
+    fn npm_package(&self) -> &'static str {
+        "@openai/codex"
+    }
+
+    fn brew_package(&self) -> Option<BrewPackage> {
+        Some(BrewPackage {
+            name: "codex",
+            cask: true,
+        })
+    }
+
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+        templates::plan_templates_codex(home, lang)
+    }
+
+    fn mcp_backend(&self) -> Option<McpBackend> {
+        Some(McpBackend::ConfigFile)
+    }
+}
+
+struct ClaudeAdapter;
+
+impl ToolAdapter for ClaudeAdapter {
+    fn id(&self) -> &'static str {
+        "claude"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["claude-code"]
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Claude Code"
+    }
+
+    fn tool(&self) -> Tool {
+        Tool::ClaudeCode
+    }
+
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf> {
+        vec![
+            home.claude_settings_path(),
+            home.home_dir().join(".claude.json"),
+        ]
+    }
+
+    fn project_files(&self) -> &'static [&'static str] {
+        &[".mcp.json"]
+    }
+
+    fn npm_package(&self) -> &'static str {
+        "@anthropic-ai/claude-code"
+    }
+
+    fn brew_package(&self) -> Option<BrewPackage> {
+        Some(BrewPackage {
+            name: "claude-code",
+            cask: true,
+        })
+    }
+
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+        templates::plan_templates_claude(home, lang)
+    }
+
+    fn mcp_backend(&self) -> Option<McpBackend> {
+        Some(McpBackend::ToolCli)
+    }
+}
+
+struct GeminiAdapter;
+
+impl ToolAdapter for GeminiAdapter {
+    fn id(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["gemini-cli"]
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Gemini CLI"
+    }
+
+    fn tool(&self) -> Tool {
+        Tool::GeminiCli
+    }
+
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf> {
+        let root = self.root(home);
+        vec![root.join(".env"), root.join("settings.json")]
+    }
+
+    fn project_files(&self) -> &'static [&'static str] {
+        &[".gemini/.env", ".gemini/settings.json", ".gemini/GEMINI.md"]
+    }
+
+    fn npm_package(&self) -> &'static str {
+        "@google/gemini-cli"
+    }
+
+    fn brew_package(&self) -> Option<BrewPackage> {
+        Some(BrewPackage {
+            name: "gemini-cli",
+            cask: false,
+        })
+    }
+
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+        // GEMINI.md is partially user-owned; read it so the managed block can be merged.
+        let existing = fs::read_to_string(self.root(home).join("GEMINI.md")).unwrap_or_default();
+        templates::plan_templates_gemini_with_existing(home, &existing, lang)
+    }
+
+    fn mcp_backend(&self) -> Option<McpBackend> {
+        Some(McpBackend::ToolCli)
+    }
+}
+
+struct OpenCodeAdapter;
+
+impl ToolAdapter for OpenCodeAdapter {
+    fn id(&self) -> &'static str {
+        "opencode"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "OpenCode"
+    }
+
+    fn tool(&self) -> Tool {
+        Tool::OpenCode
+    }
+
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf> {
+        vec![self.root(home).join("opencode.json")]
+    }
+
+    fn project_files(&self) -> &'static [&'static str] {
+        &["opencode.json"]
+    }
+
+    fn npm_package(&self) -> &'static str {
+        "opencode-ai"
+    }
+
+    fn brew_package(&self) -> Option<BrewPackage> {
+        Some(BrewPackage {
+            name: "opencode",
+            cask: false,
+        })
+    }
+
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+        templates::plan_templates_opencode(home, lang)
+    }
+
+    fn mcp_backend(&self) -> Option<McpBackend> {
+        Some(McpBackend::ConfigFile)
+    }
+}
+
+struct QwenAdapter;
+
+impl ToolAdapter for QwenAdapter {
+    fn id(&self) -> &'static str {
+        "qwen"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qwen-code"]
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Qwen Code"
+    }
+
+    fn tool(&self) -> Tool {
+        Tool::QwenCode
+    }
+
+    fn user_files(&self, home: &PrismctlHome) -> Vec<PathBuf> {
+        let root = self.root(home);
+        vec![
+            root.join(".env"),
+            root.join("settings.json"),
+            root.join("QWEN.md"),
+        ]
+    }
+
+    fn project_files(&self) -> &'static [&'static str] {
+        &[".qwen/.env", ".qwen/settings.json", "QWEN.md"]
+    }
+
+    fn npm_package(&self) -> &'static str {
+        "@qwen-code/qwen-code"
+    }
+
+    fn brew_package(&self) -> Option<BrewPackage> {
+        Some(BrewPackage {
+            name: "qwen-code",
+            cask: false,
+        })
+    }
+
+    fn plan_templates(&self, home: &PrismctlHome, lang: TemplateLang) -> ChangeSet {
+        // QWEN.md is partially user-owned, same as GEMINI.md.
+        let existing = fs::read_to_string(self.root(home).join("QWEN.md")).unwrap_or_default();
+        templates::plan_templates_qwen_with_existing(home, &existing, lang)
+    }
+
+    fn mcp_backend(&self) -> Option<McpBackend> {
+        Some(McpBackend::ToolCli)
+    }
+}
+
+static REGISTRY: &[&dyn ToolAdapter] = &[
+    &CodexAdapter,
+    &ClaudeAdapter,
+    &GeminiAdapter,
+    &OpenCodeAdapter,
+    &QwenAdapter,
+];
+
+/// All tools Prismctl can manage, in display order.
+pub fn registry() -> &'static [&'static dyn ToolAdapter] {
+    REGISTRY
+}
+
+/// `--tool` choices for help and errors, e.g. `codex|claude|...|all`.
+pub fn tool_choices() -> String {
+    let mut ids: Vec<&str> = registry().iter().map(|t| t.id()).collect();
+    ids.push("all");
+    ids.join("|")
+}
+
+/// The adapter managing `tool`.
+pub fn adapter_for(tool: Tool) -> &'static dyn ToolAdapter {
+    registry()
+        .iter()
+        .copied()
+        .find(|t| t.tool() == tool)
+        .expect("every Tool has an adapter")
+}
+
+/// Look up a tool by id or alias.
+pub fn find_tool(value: &str) -> Option<&'static dyn ToolAdapter> {
+    registry()
+        .iter()
+        .copied()
+        .find(|t| t.id() == value || t.aliases().contains(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_and_aliases_are_unique() {
+        let mut seen = HashSet::new();
+        for t in registry() {
+            assert!(seen.insert(t.id()), "duplicate id: {}", t.id());
+            for a in t.aliases() {
+                assert!(seen.insert(*a), "duplicate alias: {a}");
+            }
+        }
+        assert!(!seen.contains("all"));
+    }
+
+    #[test]
+    fn find_tool_accepts_aliases() {
+        assert_eq!(find_tool("claude-code").map(|t| t.id()), Some("claude"));
+        assert_eq!(find_tool("gemini").map(|t| t.id()), Some("gemini"));
+        assert!(find_tool("unknown").is_none());
+    }
+
+    #[test]
+    fn roots_match_paths() {
+        let home = PrismctlHome::new(PathBuf::from("/tmp/prismctl-home"));
+        let codex = find_tool("codex").expect("codex");
+        assert_eq!(codex.root(&home), home.tool_root(Tool::Codex));
+        for t in registry() {
+            assert_eq!(adapter_for(t.tool()).id(), t.id());
+            let root = t.root(&home);
+            for f in t.user_files(&home) {
+                assert!(f.starts_with(home.home_dir()), "{}", f.display());
+                assert!(f.starts_with(&root) || f.parent() == Some(home.home_dir()));
+            }
+        }
+        assert!(tool_choices().starts_with("codex|claude|"));
+        assert!(tool_choices().ends_with("|all"));
+    }
+}
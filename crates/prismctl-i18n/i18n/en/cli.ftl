@@ -33,9 +33,15 @@ error-tool-value-unsupported = Unsupported --tool value: { $value }
 error-lang-value-unsupported = Unsupported --lang value: { $value }
 error-install-method-value-unsupported = Unsupported --install-method value: { $value }
 error-codex-provider-set-needs-args = codex provider set requires at least one of: --provider/--api-key/--base-url/--model/--wire-api/--default
-error-claude-env-set-needs-args = claude env set requires at least one of: --auth-token/--base-url/--model/--haiku-model/--sonnet-model/--opus-model
-error-gemini-env-set-needs-args = gemini env set requires: --api-key
+error-claude-env-set-needs-args = claude env set requires at least one of: --provider/--auth-token/--base-url/--model/--haiku-model/--sonnet-model/--opus-model/--small-fast-model/--api-timeout-ms/--max-output-tokens/--disable-telemetry
+error-claude-env-unset-needs-keys = claude env unset requires at least one KEY, e.g. ANTHROPIC_BASE_URL
+error-gemini-env-set-needs-args = gemini env set requires at least one of: --mode/--api-key/--project/--location/--base-url
 error-gemini-settings-set-needs-args = gemini settings set requires: --model
+error-codex-settings-set-needs-args = codex settings set requires at least one of: --approval-policy/--sandbox-mode/--reasoning-effort/--verbosity/--writable-root/--network-access/--exclude-tmpdir-env-var/--exclude-slash-tmp
+error-opencode-provider-set-needs-args = opencode provider set requires at least one of: --provider/--api-key/--base-url/--model
+error-qwen-env-set-needs-args = qwen env set requires at least one of: --provider/--api-key/--base-url/--model
+error-instructions-source-not-found = Instructions source not found: { $path } (pass --source <PATH>)
+error-instructions-drift = { $count } file(s) out of sync with { $source }; run `prismctl project instructions sync --apply`
 
 # skill name validation (prismctl-core)
 error-skill-name-empty = Skill name cannot be empty
@@ -56,7 +62,7 @@ prompt-tool-init = Select tool to init (--tool):
 prompt-tool-update = Select tool to update (--tool):
 prompt-tool-install = Select tool to install (--tool):
 prompt-tool-upgrade = Select tool to upgrade (--tool):
-error-tool-flag-invalid = Missing/invalid --tool <codex|claude|gemini|all>
+error-tool-flag-invalid = Missing/invalid --tool <{ $tools }>
 prompt-skill-subcommand = Select skill subcommand:
 error-missing-subcommand = Missing subcommand
 prompt-skill-name-flag = Enter skill name (--name):
@@ -80,13 +86,117 @@ dry-run-hint-write-project = Note: this is dry-run. Pass --apply to write projec
 dry-run-hint-install-upgrade = Note: this is dry-run. Pass --apply to perform global install/upgrade.
 
 codex-agent-list-title = Available Codex agents (built-in):
-codex-agent-switched = Switched Codex AGENTS.md to: { $name }
-codex-provider-default-set = Set Codex default model_provider to: prismctl
+codex-agent-switched = Switched Codex persona (managed block in AGENTS.md) to: { $name }
+codex-agent-current = Current Codex persona: { $name } ({ $path })
+codex-agent-current-none = No Prismctl persona in { $path }
+codex-provider-default-set = Set Codex default model_provider to: { $id }
 codex-provider-preset = Using provider preset: { $provider }
-codex-auth-key-hidden = Will write Codex auth.json key: PRISMCTL_CODEX_API_KEY (value hidden)
+codex-provider-list-title = Codex model providers ({ $path }):
+codex-provider-list-empty = (no [model_providers.*] tables)
+codex-provider-active-marker = (active)
+codex-provider-list-detail = base_url: { $base_url } | model: { $model } | key: { $key }
+codex-provider-active-builtin = Active model_provider: { $id } (built into Codex)
+codex-provider-active-none = No model_provider set; Codex uses its default (openai)
+codex-profile-list-title = Codex profiles ({ $path }):
+codex-profile-list-empty = (no [profiles.*] tables)
+codex-profile-default-marker = (default)
+codex-profile-list-detail = model: { $model } | model_provider: { $provider } | approval_policy: { $approval } | reasoning: { $effort }
+codex-profile-default-set = Set Codex default profile to: { $name }
+codex-profile-usage-hint = Use it with: codex --profile { $name }
+codex-settings-title = Codex settings ({ $path }):
+codex-settings-none = (no approval/sandbox/reasoning settings; Codex defaults apply)
+codex-settings-full-access-warning = Warning: danger-full-access lets Codex run commands without any sandbox.
+codex-mcp-list-title = Codex MCP servers ({ $path }):
+codex-mcp-builtin-title = Built-in MCP servers (Codex):
+codex-mcp-list-empty = (no MCP servers configured)
+codex-auth-key-hidden = Will write Codex auth.json key: { $key } (value hidden)
+codex-auth-switched = Switched Codex auth mode to: { $mode }
+codex-prompt-list-title = Custom Codex prompts ({ $path }):
+claude-command-list-title = Custom Claude Code commands ({ $path }):
+custom-prompt-list-empty = (none)
+custom-prompt-invalid = invalid frontmatter: { $error }
+custom-prompt-usage-hint = Edit the body in { $path }, then use it with: { $usage }
+claude-permissions-title = Claude Code permissions ({ $path }):
+claude-permissions-mode = defaultMode: { $mode }
+claude-permissions-mode-unset = defaultMode: (not set)
+claude-permissions-list-empty = (no rules)
+claude-permissions-rule-not-found = Rule not found in any list: { $rule }
+claude-permissions-bypass-warning = Warning: bypassPermissions skips every permission prompt; only use it in isolated environments.
+claude-permissions-preset-rules = Preset { $name } merges these rules (defaultMode: { $mode }):
+claude-hooks-title = Claude Code hooks ({ $path }):
+claude-hooks-presets-title = Built-in hook presets:
+claude-hooks-list-empty = (no hooks configured)
+claude-preset-installed-marker = (installed)
+claude-hooks-not-found = No { $event } hook runs this command: { $command }
+claude-hooks-preset-not-installed = Hook preset { $name } is not installed
+claude-statusline-current = statusLine: { $command }
+claude-statusline-unset = statusLine: (not set)
+claude-statusline-presets-title = Built-in status line presets (segments):
+claude-agent-list-title = Claude Code subagents ({ $path }):
+claude-agent-list-empty = (no subagents)
+claude-agent-builtin-title = Built-in subagents (install with --name <NAME>):
+claude-agent-usage-hint = Saved { $path }; Claude Code delegates to { $name } based on its description, or ask for it by name
 claude-auth-token-hidden = Will write Claude settings.json env key: ANTHROPIC_AUTH_TOKEN (value hidden)
+claude-provider-preset = Using Claude provider preset: { $provider } (model keys it doesn't set are cleared)
+claude-env-title = Claude Code env ({ $path }):
+claude-env-empty = (no env keys set)
+claude-env-key-not-set = Not set in env: { $key }
+claude-env-reset-nothing = None of the Prismctl-managed env keys are set
 claude-output-style-set = Set Claude outputStyle to: { $name }
-gemini-env-managed-keys = Managing Gemini env block keys: GEMINI_API_KEY
+gemini-env-managed-keys = Gemini auth mode: { $mode }; managed env block keys: { $keys }
+opencode-provider-model-set = Set OpenCode model to: prismctl/{ $model }
+opencode-api-key-hidden = Will write OpenCode provider.prismctl.options.apiKey (value hidden)
+opencode-mcp-list-title = OpenCode MCP servers ({ $path }):
+opencode-mcp-builtin-title = Built-in MCP servers (OpenCode):
+opencode-mcp-list-empty = (no MCP servers configured)
+qwen-env-managed-keys = Managing Qwen Code env block keys: OPENAI_API_KEY, OPENAI_BASE_URL, OPENAI_MODEL
+qwen-api-key-hidden = Will write Qwen Code .env key: OPENAI_API_KEY (value hidden)
+qwen-mcp-builtin-title = Built-in MCP servers (Qwen Code):
+profile-list-title = Profiles (~/.prismctl/profiles):
+profile-none = (none; create one with `prismctl profile save --name <NAME> --apply`)
+profile-active-marker = (active)
+profile-in-sync = Profile { $name } matches the current configuration.
+profile-diff-title = Profile { $name } differs from the current configuration (current -> profile):
+profile-unset = (unset)
+profile-empty = Profile { $name } has no [codex]/[claude]/[gemini] values; nothing to apply.
+profile-nothing-to-save = No Codex/Claude/Gemini configuration found to save.
+profile-secrets-hint = Note: the profile file stores credentials in plain text; keep it private.
+provider-test-line = { $tool }: { $outcome } (HTTP { $status }, { $latency } ms) -> { $url }
+provider-test-detail = Detail: { $detail }
+provider-test-hint-unauthorized = Hint: the API key/token was rejected; check the configured key.
+provider-test-hint-forbidden = Hint: the key is valid but not allowed (plan, region or disabled API).
+provider-test-hint-not-found = Hint: check the base URL (e.g. a missing /v1 suffix) or the model id.
+provider-test-hint-bad-request = Hint: the endpoint rejected the request; the model id may be unknown.
+provider-test-hint-rate-limited = Hint: rate limited or out of quota; the credentials themselves work.
+provider-test-hint-server-error = Hint: the endpoint returned a server error; retry later.
+provider-test-hint-unexpected-status = Hint: unexpected HTTP status; check the base URL.
+provider-test-hint-timeout = Hint: no response before the timeout (see --timeout); check network/proxy.
+provider-test-hint-dns = Hint: the host name could not be resolved; check the base URL.
+provider-test-hint-connection-refused = Hint: connection refused; is the service (e.g. Ollama) running?
+provider-test-hint-tls = Hint: TLS handshake failed; check the scheme (http/https) and certificates.
+provider-test-hint-network = Hint: network error; check connectivity and proxy settings.
+provider-test-hint-missing-credentials = Hint: no API key configured; run `prismctl { $command }` first.
+provider-test-hint-unsupported = Hint: this endpoint type (Vertex AI) does not support listing models.
+provider-models-title = Models from { $url }:
+provider-models-empty = (the endpoint returned no models)
+provider-models-unavailable = Could not list models from { $url }: { $outcome }
+provider-models-fallback = Falling back to the default model: { $model }
+error-provider-test-failed = { $count } provider check(s) failed
+error-provider-timeout-invalid = Invalid --timeout value: { $value } (expected seconds > 0)
+error-provider-models-no-fallback = Could not list models and no default model is known (pass --provider or configure a model first)
+error-provider-models-tool = provider models needs exactly one tool: --tool <codex|claude|gemini>
+error-custom-prompt-exists = Already exists: { $path } (use `edit` to change its frontmatter)
+error-custom-prompt-not-found = Not found: { $path }
+error-custom-prompt-edit-needs-args = Nothing to edit: pass --description, --argument-hint or --allowed-tools
+error-claude-permissions-needs-rules = Pass at least one rule, e.g. "Bash(git diff:*)"
+error-claude-hooks-target = Pass either --preset <NAME> or --event <EVENT> --command <CMD>
+error-claude-hooks-timeout = Invalid --timeout: { $value } (expected a positive number of seconds)
+error-claude-statusline-padding = Invalid --padding: { $value } (expected a non-negative integer)
+error-claude-agent-exists = Subagent already exists: { $path }
+error-claude-agent-not-found = Subagent not found: { $name }
+instructions-source = Instructions source: { $path }
+instructions-in-sync = Project instructions are in sync.
+instructions-drifted = Out of sync: { $path }
 
 skill-builtin-title = Built-in skills:
 skill-installed-title = Installed skills:
@@ -99,6 +209,27 @@ danger-confirm-need-yes = Confirmation required: pass \"--yes\"
 danger-skill-remove-type = Operation: remove skill
 danger-skill-remove-scope = Scope: recursively delete ~/.claude/skills/{ $name } (or sandbox path under --home)
 danger-skill-remove-risk = Risk: may delete user custom scripts/config; irreversible unless you have backups
+danger-claude-env-reset-type = Operation: reset Claude Code env
+danger-claude-env-reset-scope = Scope: remove { $keys } from env in { $path }
+danger-claude-env-reset-risk = Risk: drops the configured token, gateway and model settings; Claude Code falls back to the official API and defaults
+danger-profile-overwrite-type = Operation: overwrite profile
+danger-profile-overwrite-scope = Scope: replace ~/.prismctl/profiles/{ $name }.toml (or sandbox path under --home)
+danger-profile-overwrite-risk = Risk: the saved credentials in this profile are lost
+danger-codex-provider-remove-type = Operation: remove Codex model provider
+danger-codex-provider-remove-scope = Scope: [model_providers.{ $id }] in ~/.codex/config.toml and its key in auth.json
+danger-codex-provider-remove-risk = Risk: the provider settings and its stored API key are deleted
+danger-codex-profile-remove-type = Operation: remove Codex profile
+danger-codex-profile-remove-scope = Scope: [profiles.{ $name }] in ~/.codex/config.toml (and the root profile key if it points to it)
+danger-codex-profile-remove-risk = Risk: the profile settings are deleted
+danger-codex-mcp-remove-type = Operation: remove Codex MCP server
+danger-codex-mcp-remove-scope = Scope: deletes [mcp_servers.{ $name }] (and its sub-tables) from ~/.codex/config.toml
+danger-codex-mcp-remove-risk = Risk: the server entry (including custom env/headers) is lost unless you have backups
+danger-custom-prompt-remove-type = Operation: remove { $kind }
+danger-custom-prompt-remove-scope = Scope: deletes { $path }
+danger-custom-prompt-remove-risk = Risk: the file content is lost unless you have backups
+danger-claude-agent-overwrite-type = Operation: overwrite Claude Code subagent
+danger-claude-agent-overwrite-scope = Scope: replaces { $path } with the built-in template
+danger-claude-agent-overwrite-risk = Risk: local edits to this file are lost unless you have backups
 
 danger-install-type = Operation: { $op }
 danger-install-scope = Scope: will call brew or npm and modify system-level toolchain/global deps
@@ -106,10 +237,6 @@ danger-install-risk = Risk: may override existing versions / affect PATH; failur
 danger-install-op-install = global install
 danger-install-op-upgrade = global upgrade
 
-danger-codex-agent-type = Operation: overwrite Codex system prompt file (AGENTS.md)
-danger-codex-agent-scope = Scope: changes Codex global system prompt/output style
-danger-codex-agent-risk = Risk: may overwrite your custom prompt; a backup is planned if an old file exists
-
 danger-claude-mcp-type = Operation: modify Claude Code MCP configuration
 danger-claude-mcp-scope = Scope: will call `claude mcp` to write MCP server configuration (may affect ~/.claude.json or project .mcp.json)
 danger-claude-mcp-risk = Risk: may overwrite same-name MCP server config; consider running dry-run first
@@ -117,3 +244,11 @@ danger-claude-mcp-risk = Risk: may overwrite same-name MCP server config; consid
 danger-gemini-mcp-type = Operation: modify Gemini CLI MCP configuration
 danger-gemini-mcp-scope = Scope: will call `gemini mcp` to write MCP server configuration (may affect ~/.gemini/settings.json or project .gemini/settings.json) and enable/disable state file
 danger-gemini-mcp-risk = Risk: may overwrite same-name MCP server config; consider running dry-run first
+
+danger-opencode-mcp-remove-type = Operation: remove OpenCode MCP server
+danger-opencode-mcp-remove-scope = Scope: deletes mcp.{ $name } from opencode.json
+danger-opencode-mcp-remove-risk = Risk: the server entry (including custom headers/env) is lost unless you have backups
+
+danger-qwen-mcp-type = Operation: modify Qwen Code MCP configuration
+danger-qwen-mcp-scope = Scope: will call `qwen mcp` to write MCP server configuration (may affect ~/.qwen/settings.json or project .qwen/settings.json)
+danger-qwen-mcp-risk = Risk: may overwrite same-name MCP server config; consider running dry-run first
@@ -8,7 +8,7 @@ menu-configure-codex = Configure Codex
 menu-configure-gemini = Configure Gemini CLI
 menu-manage-output-style = Manage Output Style (Claude outputStyle)
 menu-manage-skills = Manage Skills
-menu-view-config = View Current Config (doctor + skills)
+menu-view-config = View Current Config (doctor + Codex settings + skills)
 menu-language = Language
 menu-help = Help
 menu-exit = Exit
@@ -37,7 +37,7 @@ menu-codex-agent-project-path-prompt = Target project path for project scope (em
 menu-gemini-title = Gemini CLI Menu:
 menu-gemini-wizard = Guided setup (wizard)
 menu-gemini-mcp = Configure MCP
-menu-gemini-env = Configure API Key (.env)
+menu-gemini-env = Configure authentication (.env)
 menu-gemini-settings = Configure settings.json
 menu-gemini-env-scope-title = Select .env scope:
 menu-gemini-env-scope-user = user (writes ~/.gemini/.env)
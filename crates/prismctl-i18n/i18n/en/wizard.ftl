@@ -22,6 +22,15 @@ wizard-quick-init-configure-gemini = Configure Gemini CLI?
 
 # Gemini wizard
 wizard-gemini-title = Gemini CLI Setup Wizard
+wizard-gemini-auth-mode-title = Authentication mode (.env):
+wizard-gemini-auth-mode-api-key = Gemini API key (GEMINI_API_KEY)
+wizard-gemini-auth-mode-vertex-ai = Vertex AI (Google Cloud project / express API key)
+wizard-gemini-auth-mode-base-url = API key + custom base URL (GOOGLE_GEMINI_BASE_URL)
+wizard-gemini-auth-mode-skip = Skip (do not change .env)
+wizard-gemini-prompt-project = Google Cloud project ID (empty = skip):
+wizard-gemini-prompt-location = Google Cloud location (e.g. us-central1; empty = skip):
+wizard-gemini-prompt-vertex-api-key = Vertex AI express API key (GOOGLE_API_KEY; empty = use project/location):
+wizard-gemini-prompt-base-url = Base URL (GOOGLE_GEMINI_BASE_URL):
 wizard-gemini-prompt-api-key = API key (empty = use env default, "-" = skip):
 wizard-gemini-prompt-model = model.name (writes settings.json; empty = do not set):
 wizard-no-changes-cancelled = No changes provided. Cancelled.
@@ -46,12 +55,21 @@ wizard-codex-prompt-wire-api = Wire API (empty = do not set):
 wizard-codex-prompt-model = Model (empty = do not set):
 wizard-codex-prompt-api-key = API key (empty = use env default, "-" = skip):
 wizard-codex-set-default = Set as default provider (recommended)?
+wizard-codex-create-profile = Also save it as a Codex profile (codex --profile <name>)?
+wizard-codex-profile-name = Profile name:
+wizard-codex-approval-policy = Approval policy:
+wizard-codex-reasoning-effort = Reasoning effort:
+wizard-codex-profile-default = Make it the default profile?
+wizard-codex-configure-settings = Configure Codex behavior (approval, sandbox, reasoning)?
+wizard-codex-sandbox-mode = Sandbox mode:
+wizard-codex-network-access = Allow network access in the workspace-write sandbox?
+wizard-codex-verbosity = Model verbosity:
 wizard-codex-switch-agent-title = Switch Codex agent (optional)?
 wizard-codex-switch-agent = Switch agent?
 wizard-codex-agent-title = Select Codex agent:
 wizard-codex-agent-custom = custom (manual input)
 wizard-codex-agent-name = Agent name:
-wizard-codex-confirm-overwrite-agents = Overwrite Codex AGENTS.md?
+wizard-codex-confirm-apply-persona = Write this persona into AGENTS.md (the rest of the file is kept)?
 
 # Claude wizard
 wizard-claude-title = Claude Code Setup Wizard
@@ -64,6 +82,12 @@ wizard-claude-prompt-model = Model (empty = do not set):
 wizard-claude-prompt-haiku-model = Default haiku model (empty = do not set):
 wizard-claude-prompt-sonnet-model = Default sonnet model (empty = do not set):
 wizard-claude-prompt-opus-model = Default opus model (empty = do not set):
+wizard-model-custom = custom (manual input)
+wizard-model-skip = skip (do not set)
+wizard-model-name = Model id:
+wizard-models-fetching = Fetching available models from { $url } ...
+wizard-models-unavailable = Could not list models ({ $outcome }); showing the default model instead.
+wizard-models-fetch-confirm = Query { $url } for the list of available models?
 
 wizard-claude-env-skip = No Claude env changes provided. Skipping.
 
@@ -72,6 +96,9 @@ wizard-claude-output-style-confirm = Set outputStyle?
 wizard-claude-output-style-select = Select outputStyle:
 wizard-claude-output-style-custom = custom (manual input)
 wizard-claude-output-style-name = outputStyle name:
+wizard-claude-statusline-title = Set the Claude Code status line (optional)?
+wizard-claude-statusline-confirm = Install a status line preset?
+wizard-claude-statusline-select = Select a status line preset:
 
 wizard-claude-skills-title = Install Claude skills (optional)?
 wizard-claude-skills-confirm = Install skills?
@@ -33,9 +33,15 @@ error-tool-value-unsupported = 不支持的 --tool 值: { $value }
 error-lang-value-unsupported = 不支持的 --lang 值: { $value }
 error-install-method-value-unsupported = 不支持的 --install-method 值: { $value }
 error-codex-provider-set-needs-args = codex provider set 需要至少传入一个参数：--provider/--api-key/--base-url/--model/--wire-api/--default
-error-claude-env-set-needs-args = claude env set 需要至少传入一个参数：--auth-token/--base-url/--model/--haiku-model/--sonnet-model/--opus-model
-error-gemini-env-set-needs-args = gemini env set 需要传入：--api-key
+error-claude-env-set-needs-args = claude env set 需要至少传入一个参数：--provider/--auth-token/--base-url/--model/--haiku-model/--sonnet-model/--opus-model/--small-fast-model/--api-timeout-ms/--max-output-tokens/--disable-telemetry
+error-claude-env-unset-needs-keys = claude env unset 需要至少一个 KEY，例如 ANTHROPIC_BASE_URL
+error-gemini-env-set-needs-args = gemini env set 需要至少传入一个参数：--mode/--api-key/--project/--location/--base-url
 error-gemini-settings-set-needs-args = gemini settings set 需要传入：--model
+error-codex-settings-set-needs-args = codex settings set 需要至少传入一个参数：--approval-policy/--sandbox-mode/--reasoning-effort/--verbosity/--writable-root/--network-access/--exclude-tmpdir-env-var/--exclude-slash-tmp
+error-opencode-provider-set-needs-args = opencode provider set 至少需要以下参数之一：--provider/--api-key/--base-url/--model
+error-qwen-env-set-needs-args = qwen env set 至少需要以下参数之一：--provider/--api-key/--base-url/--model
+error-instructions-source-not-found = 未找到指令源文件：{ $path }（可通过 --source <PATH> 指定）
+error-instructions-drift = { $count } 个文件与 { $source } 不一致，请执行 `prismctl project instructions sync --apply`
 
 # skill name validation (prismctl-core)
 error-skill-name-empty = skill 名称不能为空
@@ -56,7 +62,7 @@ prompt-tool-init = 选择要初始化的工具（--tool）：
 prompt-tool-update = 选择要更新的工具（--tool）：
 prompt-tool-install = 选择要安装的工具（--tool）：
 prompt-tool-upgrade = 选择要升级的工具（--tool）：
-error-tool-flag-invalid = 缺少/不合法的 --tool <codex|claude|gemini|all>
+error-tool-flag-invalid = 缺少/不合法的 --tool <{ $tools }>
 prompt-skill-subcommand = 选择 skill 子命令：
 error-missing-subcommand = 缺少子命令
 prompt-skill-name-flag = 请输入 skill 名称（--name）：
@@ -80,13 +86,117 @@ dry-run-hint-write-project = 提示：这是 dry-run。传入 --apply 才会真
 dry-run-hint-install-upgrade = 提示：这是 dry-run。传入 --apply 才会真正执行全局安装/升级。
 
 codex-agent-list-title = 可用 Codex agent（内置）：
-codex-agent-switched = 将 Codex 的 AGENTS.md 切换为: { $name }
-codex-provider-default-set = 将 Codex 的默认 model_provider 设置为: prismctl
+codex-agent-switched = 将 Codex persona（AGENTS.md 中的托管块）切换为: { $name }
+codex-agent-current = 当前 Codex persona: { $name }（{ $path }）
+codex-agent-current-none = { $path } 中没有 Prismctl persona
+codex-provider-default-set = 将 Codex 的默认 model_provider 设置为: { $id }
 codex-provider-preset = 使用 provider 预设: { $provider }
-codex-auth-key-hidden = 将写入 Codex auth.json 键: PRISMCTL_CODEX_API_KEY（值已隐藏）
+codex-provider-list-title = Codex model providers（{ $path }）：
+codex-provider-list-empty = （没有 [model_providers.*] 表）
+codex-provider-active-marker = （当前）
+codex-provider-list-detail = base_url: { $base_url } | model: { $model } | key: { $key }
+codex-provider-active-builtin = 当前 model_provider: { $id }（Codex 内置）
+codex-provider-active-none = 未设置 model_provider；Codex 使用默认值（openai）
+codex-profile-list-title = Codex profiles（{ $path }）：
+codex-profile-list-empty = （没有 [profiles.*] 表）
+codex-profile-default-marker = （默认）
+codex-profile-list-detail = model: { $model } | model_provider: { $provider } | approval_policy: { $approval } | reasoning: { $effort }
+codex-profile-default-set = 将 Codex 的默认 profile 设置为: { $name }
+codex-profile-usage-hint = 使用方式: codex --profile { $name }
+codex-settings-title = Codex 设置（{ $path }）：
+codex-settings-none = （未设置审批/沙箱/推理相关配置，使用 Codex 默认值）
+codex-settings-full-access-warning = 警告：danger-full-access 会让 Codex 在没有任何沙箱的情况下执行命令。
+codex-mcp-list-title = Codex MCP servers（{ $path }）：
+codex-mcp-builtin-title = 内置 MCP servers（Codex）：
+codex-mcp-list-empty = （未配置 MCP server）
+codex-auth-key-hidden = 将写入 Codex auth.json 键: { $key }（值已隐藏）
+codex-auth-switched = 已切换 Codex 认证方式为：{ $mode }
+codex-prompt-list-title = 自定义 Codex prompts（{ $path }）：
+claude-command-list-title = 自定义 Claude Code commands（{ $path }）：
+custom-prompt-list-empty = (none)
+custom-prompt-invalid = frontmatter 无效：{ $error }
+custom-prompt-usage-hint = 请在 { $path } 中编辑正文，然后通过 { $usage } 使用
+claude-permissions-title = Claude Code 权限（{ $path }）：
+claude-permissions-mode = defaultMode：{ $mode }
+claude-permissions-mode-unset = defaultMode：（未设置）
+claude-permissions-list-empty = （没有规则）
+claude-permissions-rule-not-found = 任何列表中都没有该规则：{ $rule }
+claude-permissions-bypass-warning = 警告：bypassPermissions 会跳过所有权限确认，仅应在隔离环境中使用。
+claude-permissions-preset-rules = 预设 { $name } 将合并以下规则（defaultMode：{ $mode }）：
+claude-hooks-title = Claude Code hooks（{ $path }）：
+claude-hooks-presets-title = 内置 hook 预设：
+claude-hooks-list-empty = （未配置 hook）
+claude-preset-installed-marker = （已安装）
+claude-hooks-not-found = { $event } 下没有执行该命令的 hook：{ $command }
+claude-hooks-preset-not-installed = hook 预设 { $name } 未安装
+claude-statusline-current = statusLine：{ $command }
+claude-statusline-unset = statusLine：（未设置）
+claude-statusline-presets-title = 内置 status line 预设（显示内容）：
+claude-agent-list-title = Claude Code subagents（{ $path }）：
+claude-agent-list-empty = （没有 subagent）
+claude-agent-builtin-title = 内置 subagents（使用 --name <NAME> 安装）：
+claude-agent-usage-hint = 已写入 { $path }；Claude Code 会根据 description 自动委派给 { $name }，也可以按名称显式调用
 claude-auth-token-hidden = 将写入 Claude settings.json env 键: ANTHROPIC_AUTH_TOKEN（值已隐藏）
+claude-provider-preset = 使用 Claude provider 预设: { $provider }（预设未设置的模型变量会被清除）
+claude-env-title = Claude Code env（{ $path }）：
+claude-env-empty = （未设置任何环境变量）
+claude-env-key-not-set = env 中未设置：{ $key }
+claude-env-reset-nothing = 未设置任何由 Prismctl 管理的 env 键
 claude-output-style-set = 将 Claude 的 outputStyle 设置为: { $name }
-gemini-env-managed-keys = 将管理 Gemini 环境变量块（keys）：GEMINI_API_KEY
+gemini-env-managed-keys = Gemini 认证方式：{ $mode }；将管理环境变量块（keys）：{ $keys }
+opencode-provider-model-set = 已将 OpenCode model 设置为：prismctl/{ $model }
+opencode-api-key-hidden = 将写入 OpenCode opencode.json 键: provider.prismctl.options.apiKey（值已隐藏）
+opencode-mcp-list-title = OpenCode MCP servers（{ $path }）：
+opencode-mcp-builtin-title = 内置 MCP servers（OpenCode）：
+opencode-mcp-list-empty = （未配置 MCP server）
+qwen-env-managed-keys = 将管理 Qwen Code 环境变量块（keys）：OPENAI_API_KEY, OPENAI_BASE_URL, OPENAI_MODEL
+qwen-api-key-hidden = 将写入 Qwen Code .env 键: OPENAI_API_KEY（值已隐藏）
+qwen-mcp-builtin-title = 内置 MCP servers（Qwen Code）：
+profile-list-title = Profiles（~/.prismctl/profiles）：
+profile-none = （暂无；可用 `prismctl profile save --name <NAME> --apply` 创建）
+profile-active-marker = （当前生效）
+profile-in-sync = Profile { $name } 与当前配置一致。
+profile-diff-title = Profile { $name } 与当前配置的差异（当前 -> profile）：
+profile-unset = （未设置）
+profile-empty = Profile { $name } 没有 [codex]/[claude]/[gemini] 配置，无需应用。
+profile-nothing-to-save = 未找到可保存的 Codex/Claude/Gemini 配置。
+profile-secrets-hint = 注意：profile 文件以明文保存凭据，请妥善保管。
+provider-test-line = { $tool }：{ $outcome }（HTTP { $status }，{ $latency } ms）-> { $url }
+provider-test-detail = 详情：{ $detail }
+provider-test-hint-unauthorized = 提示：API key/token 被拒绝，请检查配置的密钥。
+provider-test-hint-forbidden = 提示：密钥有效但无权限（套餐、地区或 API 未启用）。
+provider-test-hint-not-found = 提示：请检查 base URL（例如缺少 /v1 后缀）或模型 ID。
+provider-test-hint-bad-request = 提示：服务端拒绝了请求，模型 ID 可能不存在。
+provider-test-hint-rate-limited = 提示：触发限流或额度不足；凭据本身可用。
+provider-test-hint-server-error = 提示：服务端返回错误，请稍后重试。
+provider-test-hint-unexpected-status = 提示：非预期的 HTTP 状态码，请检查 base URL。
+provider-test-hint-timeout = 提示：在超时前未收到响应（见 --timeout），请检查网络/代理。
+provider-test-hint-dns = 提示：无法解析主机名，请检查 base URL。
+provider-test-hint-connection-refused = 提示：连接被拒绝；服务（例如 Ollama）是否已启动？
+provider-test-hint-tls = 提示：TLS 握手失败，请检查协议（http/https）与证书。
+provider-test-hint-network = 提示：网络错误，请检查连接与代理设置。
+provider-test-hint-missing-credentials = 提示：未配置 API key；请先执行 `prismctl { $command }`。
+provider-test-hint-unsupported = 提示：该类型的端点（Vertex AI）不支持列出模型。
+provider-models-title = 来自 { $url } 的模型：
+provider-models-empty = （端点未返回任何模型）
+provider-models-unavailable = 无法从 { $url } 列出模型：{ $outcome }
+provider-models-fallback = 回退到默认模型：{ $model }
+error-provider-test-failed = { $count } 个 provider 检查未通过
+error-provider-timeout-invalid = 无效的 --timeout: { $value }（需为大于 0 的秒数）
+error-provider-models-no-fallback = 无法列出模型，且没有已知的默认模型（请传入 --provider 或先配置模型）
+error-provider-models-tool = provider models 需要指定单个工具：--tool <codex|claude|gemini>
+error-custom-prompt-exists = 已存在：{ $path }（如需修改 frontmatter 请使用 `edit`）
+error-custom-prompt-not-found = 不存在：{ $path }
+error-custom-prompt-edit-needs-args = 没有要修改的内容：请传入 --description、--argument-hint 或 --allowed-tools
+error-claude-permissions-needs-rules = 请至少传入一条规则，例如 "Bash(git diff:*)"
+error-claude-hooks-target = 请传入 --preset <NAME>，或 --event <EVENT> --command <CMD>
+error-claude-hooks-timeout = 无效的 --timeout：{ $value }（应为正整数秒数）
+error-claude-statusline-padding = 无效的 --padding：{ $value }（应为非负整数）
+error-claude-agent-exists = subagent 已存在：{ $path }
+error-claude-agent-not-found = 未找到 subagent：{ $name }
+instructions-source = 指令源文件：{ $path }
+instructions-in-sync = 项目指令已同步，无漂移。
+instructions-drifted = 不一致：{ $path }
 
 skill-builtin-title = 内置 skills：
 skill-installed-title = 已安装 skills：
@@ -99,6 +209,27 @@ danger-confirm-need-yes = 请确认是否继续？需要显式传入 "--yes"（
 danger-skill-remove-type = 操作类型：删除 skill
 danger-skill-remove-scope = 影响范围：将递归删除 ~/.claude/skills/{ $name } 目录（在 --home 沙箱下则删除沙箱内对应目录）
 danger-skill-remove-risk = 风险评估：可能删除用户自定义脚本/配置，操作不可逆（除非你有备份）
+danger-claude-env-reset-type = 操作类型：重置 Claude Code env
+danger-claude-env-reset-scope = 影响范围：从 { $path } 的 env 中删除 { $keys }
+danger-claude-env-reset-risk = 风险评估：会删除已配置的 token、网关与模型设置，Claude Code 将回退到官方 API 与默认值
+danger-profile-overwrite-type = 操作类型：覆盖 profile
+danger-profile-overwrite-scope = 影响范围：替换 ~/.prismctl/profiles/{ $name }.toml（或 --home 下的沙箱路径）
+danger-profile-overwrite-risk = 风险评估：该 profile 中已保存的凭据将丢失
+danger-codex-provider-remove-type = 操作类型：删除 Codex model provider
+danger-codex-provider-remove-scope = 影响范围：~/.codex/config.toml 中的 [model_providers.{ $id }] 及 auth.json 中对应的 key
+danger-codex-provider-remove-risk = 风险评估：该 provider 的配置及已保存的 API key 将被删除
+danger-codex-profile-remove-type = 操作类型：删除 Codex profile
+danger-codex-profile-remove-scope = 影响范围：~/.codex/config.toml 中的 [profiles.{ $name }]（若根级 profile 指向它也一并移除）
+danger-codex-profile-remove-risk = 风险评估：该 profile 的配置将被删除
+danger-codex-mcp-remove-type = 操作类型：删除 Codex MCP server
+danger-codex-mcp-remove-scope = 影响范围：从 ~/.codex/config.toml 删除 [mcp_servers.{ $name }]（含子表）
+danger-codex-mcp-remove-risk = 风险评估：该 server 配置（含自定义 env/headers）将丢失（除非有备份）
+danger-custom-prompt-remove-type = 操作类型：删除 { $kind }
+danger-custom-prompt-remove-scope = 影响范围：删除 { $path }
+danger-custom-prompt-remove-risk = 风险评估：文件内容将丢失（除非有备份）
+danger-claude-agent-overwrite-type = 操作类型：覆盖 Claude Code subagent
+danger-claude-agent-overwrite-scope = 影响范围：用内置模板替换 { $path }
+danger-claude-agent-overwrite-risk = 风险评估：对该文件的本地修改将丢失（除非有备份）
 
 danger-install-type = 操作类型：{ $op }
 danger-install-scope = 影响范围：将调用 brew 或 npm 修改系统级工具链与全局依赖
@@ -106,10 +237,6 @@ danger-install-risk = 风险评估：可能覆盖现有版本、影响 PATH/环
 danger-install-op-install = 全局安装
 danger-install-op-upgrade = 全局升级
 
-danger-codex-agent-type = 操作类型：覆盖 Codex 系统提示文件（AGENTS.md）
-danger-codex-agent-scope = 影响范围：将改变 Codex 的全局系统提示/输出风格
-danger-codex-agent-risk = 风险评估：可能覆盖你当前自定义提示；已自动计划备份（如存在旧文件）
-
 danger-claude-mcp-type = 操作类型：修改 Claude Code MCP 配置
 danger-claude-mcp-scope = 影响范围：将调用 claude mcp 写入 MCP server 配置（可能影响 ~/.claude.json 或项目 .mcp.json）
 danger-claude-mcp-risk = 风险评估：可能覆盖同名 MCP server 配置；建议先 dry-run 预览
@@ -117,3 +244,11 @@ danger-claude-mcp-risk = 风险评估：可能覆盖同名 MCP server 配置；
 danger-gemini-mcp-type = 操作类型：修改 Gemini CLI MCP 配置
 danger-gemini-mcp-scope = 影响范围：将调用 gemini mcp 写入 MCP server 配置（可能影响 ~/.gemini/settings.json 或项目 .gemini/settings.json），以及 enable/disable 状态文件
 danger-gemini-mcp-risk = 风险评估：可能覆盖同名 MCP server 配置；建议先 dry-run 预览
+
+danger-opencode-mcp-remove-type = 操作类型：删除 OpenCode MCP server
+danger-opencode-mcp-remove-scope = 影响范围：从 opencode.json 中删除 mcp.{ $name }
+danger-opencode-mcp-remove-risk = 风险评估：该 server 配置（含自定义 headers/env）将丢失，除非你有备份
+
+danger-qwen-mcp-type = 操作类型：修改 Qwen Code MCP 配置
+danger-qwen-mcp-scope = 影响范围：将调用 qwen mcp 写入 MCP server 配置（可能影响 ~/.qwen/settings.json 或项目 .qwen/settings.json）
+danger-qwen-mcp-risk = 风险评估：可能覆盖同名 MCP server 配置；建议先 dry-run 预览
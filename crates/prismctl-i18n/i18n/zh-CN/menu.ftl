@@ -8,7 +8,7 @@ menu-configure-codex = 配置 Codex
 menu-configure-gemini = 配置 Gemini CLI
 menu-manage-output-style = 管理输出样式（Claude outputStyle）
 menu-manage-skills = 管理技能（Skills）
-menu-view-config = 查看当前配置（doctor + skills）
+menu-view-config = 查看当前配置（doctor + Codex 设置 + skills）
 menu-language = 切换语言
 menu-help = 帮助（help）
 menu-exit = 退出（quit）
@@ -37,7 +37,7 @@ menu-codex-agent-project-path-prompt = project scope 目标项目路径（留空
 menu-gemini-title = Gemini CLI 菜单：
 menu-gemini-wizard = 一键配置（向导）
 menu-gemini-mcp = 配置 MCP
-menu-gemini-env = 配置 API Key（.env）
+menu-gemini-env = 配置认证方式（.env）
 menu-gemini-settings = 配置 settings.json
 menu-gemini-env-scope-title = 选择 .env scope：
 menu-gemini-env-scope-user = user（写入 ~/.gemini/.env）
@@ -22,6 +22,15 @@ wizard-quick-init-configure-gemini = 配置 Gemini CLI？
 
 # Gemini 向导
 wizard-gemini-title = Gemini CLI 配置向导
+wizard-gemini-auth-mode-title = 认证方式（.env）：
+wizard-gemini-auth-mode-api-key = Gemini API key（GEMINI_API_KEY）
+wizard-gemini-auth-mode-vertex-ai = Vertex AI（Google Cloud 项目 / express API key）
+wizard-gemini-auth-mode-base-url = API key + 自定义 base URL（GOOGLE_GEMINI_BASE_URL）
+wizard-gemini-auth-mode-skip = 跳过（不修改 .env）
+wizard-gemini-prompt-project = Google Cloud 项目 ID（留空跳过）：
+wizard-gemini-prompt-location = Google Cloud 区域（如 us-central1；留空跳过）：
+wizard-gemini-prompt-vertex-api-key = Vertex AI express API key（GOOGLE_API_KEY；留空则使用项目/区域）：
+wizard-gemini-prompt-base-url = Base URL（GOOGLE_GEMINI_BASE_URL）：
 wizard-gemini-prompt-api-key = api key（留空=使用环境变量默认值；输入 "-"=跳过不写入）：
 wizard-gemini-prompt-model = model.name（写入 settings.json；留空不设置）：
 wizard-no-changes-cancelled = 未提供任何修改，已取消。
@@ -46,12 +55,21 @@ wizard-codex-prompt-wire-api = wire api（留空不设置）：
 wizard-codex-prompt-model = model（留空不设置）：
 wizard-codex-prompt-api-key = api key（留空=使用环境变量默认值；输入 "-"=跳过不写入）：
 wizard-codex-set-default = 设为默认 provider（建议）？
+wizard-codex-create-profile = 同时保存为 Codex profile（codex --profile <name>）？
+wizard-codex-profile-name = Profile 名称：
+wizard-codex-approval-policy = 审批策略（approval_policy）：
+wizard-codex-reasoning-effort = 推理强度（model_reasoning_effort）：
+wizard-codex-profile-default = 设为默认 profile？
+wizard-codex-configure-settings = 配置 Codex 行为（审批、沙箱、推理）？
+wizard-codex-sandbox-mode = 沙箱模式（sandbox_mode）：
+wizard-codex-network-access = workspace-write 沙箱中允许网络访问？
+wizard-codex-verbosity = 输出详细程度（model_verbosity）：
 wizard-codex-switch-agent-title = 是否切换 Codex agent（可选）？
 wizard-codex-switch-agent = 切换 agent？
 wizard-codex-agent-title = 选择 Codex agent：
 wizard-codex-agent-custom = custom（手动输入）
 wizard-codex-agent-name = agent 名称：
-wizard-codex-confirm-overwrite-agents = 确认覆盖 Codex 的 AGENTS.md？
+wizard-codex-confirm-apply-persona = 将该 persona 写入 AGENTS.md（保留文件其余内容）？
 
 # Claude 向导
 wizard-claude-title = Claude Code 配置向导
@@ -64,6 +82,12 @@ wizard-claude-prompt-model = model（留空不设置）：
 wizard-claude-prompt-haiku-model = default haiku model（留空不设置）：
 wizard-claude-prompt-sonnet-model = default sonnet model（留空不设置）：
 wizard-claude-prompt-opus-model = default opus model（留空不设置）：
+wizard-model-custom = custom（手动输入）
+wizard-model-skip = 跳过（不设置）
+wizard-model-name = 模型 ID：
+wizard-models-fetching = 正在从 { $url } 获取可用模型...
+wizard-models-unavailable = 无法列出模型（{ $outcome }），改为显示默认模型。
+wizard-models-fetch-confirm = 是否从 { $url } 查询可用模型列表？
 
 wizard-claude-env-skip = 未提供任何 Claude env 配置，已跳过。
 
@@ -72,6 +96,9 @@ wizard-claude-output-style-confirm = 设置 outputStyle？
 wizard-claude-output-style-select = 选择 outputStyle：
 wizard-claude-output-style-custom = custom（手动输入）
 wizard-claude-output-style-name = outputStyle 名称:
+wizard-claude-statusline-title = 是否设置 Claude Code 状态栏（可选）？
+wizard-claude-statusline-confirm = 安装状态栏预设？
+wizard-claude-statusline-select = 选择状态栏预设：
 
 wizard-claude-skills-title = 是否安装 Claude skills（可选）？
 wizard-claude-skills-confirm = 安装 skills？